@@ -0,0 +1,455 @@
+//! Arbitrary-width integers
+//!
+//! This module provides [`UInt`] and [`Int`], integers of any width from
+//! `1` to `128` bits, chosen with a const generic, such as `UInt<5>` or `Int<13>`.
+//!
+//! Each integer is stored in the smallest primitive that fits it, given by [`Backing`],
+//! and is always kept within it's range. Widths without a [`Backing`], such as `0`, are
+//! rejected at compile time.
+//!
+//! They extend into any integer at least as wide, and truncate into any integer at most as
+//! wide, the same way primitives do. As the widths are generic, these conversions are only
+//! implemented for valid widths through [`Narrower`], so that, for example, truncating an
+//! `UInt<5>` into an `UInt<7>` doesn't type check.
+
+// Modules
+mod conv;
+mod fmt;
+
+// Imports
+use crate::{
+	raw::{self, Raw},
+	sign::impl_signed_const,
+	Const, Signed,
+};
+use core::{fmt as core_fmt, hash::Hash};
+
+/// Width of an arbitrary-width integer
+///
+/// This type is only used to select the [`Backing`] of a width.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Bits<const N: u32>;
+
+/// Primitives backing an arbitrary-width integer
+///
+/// This trait is implemented by [`Bits<N>`] for each supported width,
+/// from `1` to `128`.
+pub trait Backing {
+	/// Unsigned primitive storing an [`UInt`] of this width
+	type Unsigned: Raw
+		+ Eq
+		+ Ord
+		+ Hash
+		+ Default
+		+ core_fmt::Debug
+		+ core_fmt::Display
+		+ core_fmt::LowerHex
+		+ core_fmt::UpperHex
+		+ core_fmt::Octal
+		+ core_fmt::Binary;
+
+	/// Signed primitive storing an [`Int`] of this width
+	type Signed: Raw + Eq + Ord + Hash + Default + core_fmt::Debug + core_fmt::Display;
+
+	/// Smallest value of an [`UInt`] of this width
+	const MIN_UNSIGNED: Self::Unsigned;
+
+	/// Largest value of an [`UInt`] of this width
+	const MAX_UNSIGNED: Self::Unsigned;
+
+	/// Smallest value of an [`Int`] of this width
+	const MIN_SIGNED: Self::Signed;
+
+	/// Largest value of an [`Int`] of this width
+	const MAX_SIGNED: Self::Signed;
+}
+
+/// Macro to help implement [`Backing`]
+macro_rules! impl_backing {
+	($Unsigned:ty, $Signed:ty => $( $N:literal ),+ $(,)?) => {
+		$(
+			impl Backing for Bits<$N> {
+				type Unsigned = $Unsigned;
+				type Signed = $Signed;
+
+				const MIN_UNSIGNED: $Unsigned = 0;
+
+				// Note: Casting from a larger to smaller integer will truncate.
+				#[allow(clippy::as_conversions)]
+				const MAX_UNSIGNED: $Unsigned = (u128::MAX >> (128 - $N)) as $Unsigned;
+				#[allow(clippy::as_conversions)]
+				const MIN_SIGNED: $Signed = (i128::MIN >> (128 - $N)) as $Signed;
+				#[allow(clippy::as_conversions)]
+				const MAX_SIGNED: $Signed = (i128::MAX >> (128 - $N)) as $Signed;
+			}
+		)+
+	};
+}
+
+#[rustfmt::skip]
+impl_backing! { u8, i8 => 1, 2, 3, 4, 5, 6, 7, 8 }
+#[rustfmt::skip]
+impl_backing! { u16, i16 => 9, 10, 11, 12, 13, 14, 15, 16 }
+#[rustfmt::skip]
+impl_backing! { u32, i32 =>
+	17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+}
+#[rustfmt::skip]
+impl_backing! { u64, i64 =>
+	33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48,
+	49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64,
+}
+#[rustfmt::skip]
+impl_backing! { u128, i128 =>
+	 65,  66,  67,  68,  69,  70,  71,  72,  73,  74,  75,  76,  77,  78,  79,  80,
+	 81,  82,  83,  84,  85,  86,  87,  88,  89,  90,  91,  92,  93,  94,  95,  96,
+	 97,  98,  99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 111, 112,
+	113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 123, 124, 125, 126, 127, 128,
+}
+
+/// Widths narrower than `T`
+///
+/// This trait is implemented by [`Bits<N>`] for each [`Bits<M>`] with `N < M`, from `1`
+/// to `129`, so conversions between integers may only be implemented for valid widths.
+///
+/// Note: `129` is included so that widths up to `128` can be checked to be at most `128`.
+pub trait Narrower<T> {}
+
+/// Macro to help implement [`Narrower`]
+///
+/// Each width is narrower than all widths after it.
+macro_rules! impl_narrower {
+	($N:literal $(, $M:literal )* $(,)?) => {
+		$( impl Narrower<Bits<$M>> for Bits<$N> {} )*
+		impl_narrower! { $( $M ),* }
+	};
+
+	() => {};
+}
+
+#[rustfmt::skip]
+impl_narrower! {
+	  1,   2,   3,   4,   5,   6,   7,   8,   9,  10,  11,  12,  13,  14,  15,  16,
+	 17,  18,  19,  20,  21,  22,  23,  24,  25,  26,  27,  28,  29,  30,  31,  32,
+	 33,  34,  35,  36,  37,  38,  39,  40,  41,  42,  43,  44,  45,  46,  47,  48,
+	 49,  50,  51,  52,  53,  54,  55,  56,  57,  58,  59,  60,  61,  62,  63,  64,
+	 65,  66,  67,  68,  69,  70,  71,  72,  73,  74,  75,  76,  77,  78,  79,  80,
+	 81,  82,  83,  84,  85,  86,  87,  88,  89,  90,  91,  92,  93,  94,  95,  96,
+	 97,  98,  99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 111, 112,
+	113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 123, 124, 125, 126, 127, 128,
+	129,
+}
+
+/// Unsigned integer of `BITS` bits
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct UInt<const BITS: u32>(<Bits<BITS> as Backing>::Unsigned)
+where
+	Bits<BITS>: Backing;
+
+/// Signed integer of `BITS` bits
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct Int<const BITS: u32>(<Bits<BITS> as Backing>::Signed)
+where
+	Bits<BITS>: Backing;
+
+impl<const BITS: u32> UInt<BITS>
+where
+	Bits<BITS>: Backing,
+{
+	/// The size of this integer in bits
+	pub const BITS: u32 = BITS;
+
+	/// The smallest value of this integer
+	pub const MIN: Self = Self(<Bits<BITS> as Backing>::MIN_UNSIGNED);
+
+	/// The largest value of this integer
+	pub const MAX: Self = Self(<Bits<BITS> as Backing>::MAX_UNSIGNED);
+
+	/// Creates this integer from `value`, if it fits
+	#[must_use]
+	#[inline]
+	pub fn new(value: <Bits<BITS> as Backing>::Unsigned) -> Option<Self> {
+		(value <= Self::MAX.0).then(|| Self(value))
+	}
+
+	/// Returns the value of this integer
+	#[must_use]
+	#[inline]
+	pub const fn get(self) -> <Bits<BITS> as Backing>::Unsigned {
+		self.0
+	}
+}
+
+impl<const BITS: u32> Int<BITS>
+where
+	Bits<BITS>: Backing,
+{
+	/// The size of this integer in bits
+	pub const BITS: u32 = BITS;
+
+	/// The smallest value of this integer
+	pub const MIN: Self = Self(<Bits<BITS> as Backing>::MIN_SIGNED);
+
+	/// The largest value of this integer
+	pub const MAX: Self = Self(<Bits<BITS> as Backing>::MAX_SIGNED);
+
+	/// Creates this integer from `value`, if it fits
+	#[must_use]
+	#[inline]
+	pub fn new(value: <Bits<BITS> as Backing>::Signed) -> Option<Self> {
+		(Self::MIN.0 <= value && value <= Self::MAX.0).then(|| Self(value))
+	}
+
+	/// Returns the value of this integer
+	#[must_use]
+	#[inline]
+	pub const fn get(self) -> <Bits<BITS> as Backing>::Signed {
+		self.0
+	}
+}
+
+/// Backing integer stored in the lower bits of an `u128`
+///
+/// Note: The backing integers can't be converted through [`Raw`] in a `const fn`, as it's a trait,
+///       so we instead reinterpret their bytes through this union.
+#[repr(C)]
+union Pun<T: Raw> {
+	/// Backing integer
+	value: T,
+
+	/// Raw bits, with the backing integer in it's lower bytes
+	raw: u128,
+}
+
+impl<T: Raw> Pun<T> {
+	/// Shift of the backing integer's bytes in `raw`
+	///
+	/// Note: On big endian targets, the bytes of the backing integer are the most significant ones.
+	const SHIFT: u32 = if cfg!(target_endian = "big") { u128::BITS - T::BITS } else { 0 };
+
+	/// Returns the bits of `value`, zero extended
+	const fn to_raw(value: T) -> u128 {
+		let mut pun = Self { raw: 0 };
+		pun.value = value;
+
+		// SAFETY: All bytes of `raw` are initialized, either by `0` or by `value`
+		unsafe { pun.raw >> Self::SHIFT }
+	}
+
+	/// Creates a backing integer from the lower bits of `raw`
+	const fn from_raw(raw: u128) -> T {
+		let pun = Self { raw: raw << Self::SHIFT };
+
+		// SAFETY: The backing integers are all primitives, for which any bits are valid
+		unsafe { pun.value }
+	}
+}
+
+impl<const BITS: u32> Const<UInt<BITS>>
+where
+	Bits<BITS>: Backing,
+{
+	/// Returns the raw bits of `value`
+	pub(crate) const fn to_raw(value: UInt<BITS>) -> u128 {
+		Pun::to_raw(value.0)
+	}
+
+	/// Creates an integer from the lower bits of `raw`
+	pub(crate) const fn from_raw(raw: u128) -> UInt<BITS> {
+		UInt(Pun::from_raw(raw & raw::mask(BITS)))
+	}
+}
+
+impl<const BITS: u32> Const<Int<BITS>>
+where
+	Bits<BITS>: Backing,
+{
+	/// Returns the raw bits of `value`
+	pub(crate) const fn to_raw(value: Int<BITS>) -> u128 {
+		Pun::to_raw(value.0) & raw::mask(BITS)
+	}
+
+	/// Creates an integer from the lower bits of `raw`
+	pub(crate) const fn from_raw(raw: u128) -> Int<BITS> {
+		// Note: We keep the inner integer sign extended, so it holds the same value
+		Int(Pun::from_raw(raw::sign_extend(raw, BITS)))
+	}
+}
+
+impl_signed_const! { [N] UInt<N> : -Int<N> : +UInt<N> }
+impl_signed_const! { [N] Int<N> : -Int<N> : +UInt<N> }
+
+impl<const BITS: u32> Raw for UInt<BITS>
+where
+	Bits<BITS>: Backing,
+{
+	const BITS: u32 = BITS;
+	const SIGNED: bool = false;
+
+	#[inline]
+	fn to_raw(self) -> u128 {
+		Const::<UInt<BITS>>::to_raw(self)
+	}
+
+	#[inline]
+	fn from_raw(raw: u128) -> Self {
+		Const::<UInt<BITS>>::from_raw(raw)
+	}
+}
+
+impl<const BITS: u32> Raw for Int<BITS>
+where
+	Bits<BITS>: Backing,
+{
+	const BITS: u32 = BITS;
+	const SIGNED: bool = true;
+
+	#[inline]
+	fn to_raw(self) -> u128 {
+		Const::<Int<BITS>>::to_raw(self)
+	}
+
+	#[inline]
+	fn from_raw(raw: u128) -> Self {
+		Const::<Int<BITS>>::from_raw(raw)
+	}
+}
+
+impl<const BITS: u32> Signed for Int<BITS>
+where
+	Bits<BITS>: Backing,
+{
+	type Signed = Int<BITS>;
+	type Unsigned = UInt<BITS>;
+
+	#[inline]
+	fn as_unsigned(self) -> Self::Unsigned {
+		Const::<Int<BITS>>::as_unsigned(self)
+	}
+
+	#[inline]
+	fn as_signed(self) -> Self::Signed {
+		Const::<Int<BITS>>::as_signed(self)
+	}
+
+	#[inline]
+	fn abs_unsigned(self) -> Self::Unsigned {
+		Const::<Int<BITS>>::abs_unsigned(self)
+	}
+}
+
+impl<const BITS: u32> Signed for UInt<BITS>
+where
+	Bits<BITS>: Backing,
+{
+	type Signed = Int<BITS>;
+	type Unsigned = UInt<BITS>;
+
+	#[inline]
+	fn as_unsigned(self) -> Self::Unsigned {
+		Const::<UInt<BITS>>::as_unsigned(self)
+	}
+
+	#[inline]
+	fn as_signed(self) -> Self::Signed {
+		Const::<UInt<BITS>>::as_signed(self)
+	}
+
+	#[inline]
+	fn abs_unsigned(self) -> Self::Unsigned {
+		Const::<UInt<BITS>>::abs_unsigned(self)
+	}
+}
+
+// Check that the backing integers are the smallest ones
+static_assertions::assert_type_eq_all! { <Bits<  1> as Backing>::Unsigned, u8   }
+static_assertions::assert_type_eq_all! { <Bits<  8> as Backing>::Unsigned, u8   }
+static_assertions::assert_type_eq_all! { <Bits<  9> as Backing>::Unsigned, u16  }
+static_assertions::assert_type_eq_all! { <Bits< 16> as Backing>::Unsigned, u16  }
+static_assertions::assert_type_eq_all! { <Bits< 17> as Backing>::Unsigned, u32  }
+static_assertions::assert_type_eq_all! { <Bits< 32> as Backing>::Unsigned, u32  }
+static_assertions::assert_type_eq_all! { <Bits< 33> as Backing>::Unsigned, u64  }
+static_assertions::assert_type_eq_all! { <Bits< 64> as Backing>::Unsigned, u64  }
+static_assertions::assert_type_eq_all! { <Bits< 65> as Backing>::Unsigned, u128 }
+static_assertions::assert_type_eq_all! { <Bits<128> as Backing>::Unsigned, u128 }
+static_assertions::assert_type_eq_all! { <Bits< 13> as Backing>::Signed  , i16  }
+static_assertions::assert_eq_size! { UInt<5>, Int<5>, u8 }
+static_assertions::assert_eq_size! { UInt<13>, Int<13>, u16 }
+static_assertions::assert_eq_size! { UInt<100>, Int<100>, u128 }
+static_assertions::assert_not_impl_any! { Bits<0>: Backing }
+static_assertions::assert_not_impl_any! { Bits<129>: Backing }
+
+// Check that they all implement `Signed` / `IsSigned` / `IsUnsigned`
+static_assertions::assert_impl_all! { Int<1>   : crate::Signed, crate::IsSigned   }
+static_assertions::assert_impl_all! { Int<13>  : crate::Signed, crate::IsSigned   }
+static_assertions::assert_impl_all! { Int<128> : crate::Signed, crate::IsSigned   }
+static_assertions::assert_impl_all! { UInt<1>  : crate::Signed, crate::IsUnsigned }
+static_assertions::assert_impl_all! { UInt<5>  : crate::Signed, crate::IsUnsigned }
+static_assertions::assert_impl_all! { UInt<128>: crate::Signed, crate::IsUnsigned }
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use super::*;
+	use crate::{Sign, SignError, SignedExt};
+
+	#[test]
+	#[rustfmt::skip]
+	fn min_max() {
+		assert_eq!(UInt::<1>  ::MAX.get(), 1);
+		assert_eq!(UInt::<5>  ::MAX.get(), 0x1f);
+		assert_eq!(UInt::<128>::MAX.get(), u128::MAX);
+		assert_eq!( Int::<1>  ::MIN.get(), -1);
+		assert_eq!( Int::<1>  ::MAX.get(), 0);
+		assert_eq!( Int::<13> ::MIN.get(), -0x1000);
+		assert_eq!( Int::<13> ::MAX.get(), 0x0fff);
+		assert_eq!( Int::<128>::MIN.get(), i128::MIN);
+		assert_eq!( Int::<128>::MAX.get(), i128::MAX);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn new() {
+		assert_eq!(UInt::<5>::new(0x1f), Some(UInt::<5>::MAX));
+		assert_eq!(UInt::<5>::new(0x20), None);
+		assert_eq!( Int::<13>::new(-0x1000), Some(Int::<13>::MIN));
+		assert_eq!( Int::<13>::new(-0x1001), None);
+		assert_eq!( Int::<13>::new( 0x1000), None);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn signed() {
+		assert_eq!(Int::<13>::MIN.as_unsigned(), UInt::<13>::new(0x1000).unwrap());
+		assert_eq!(Int::<13>::new(-1).unwrap().as_unsigned(), UInt::<13>::MAX);
+		assert_eq!(UInt::<13>::MAX.as_signed(), Int::<13>::new(-1).unwrap());
+		assert_eq!(Int::<13>::MIN.abs_unsigned(), UInt::<13>::new(0x1000).unwrap());
+		assert_eq!(Int::<13>::new(-5).unwrap().abs_unsigned(), UInt::<13>::new(5).unwrap());
+		assert_eq!(Int::<13>::new(5).unwrap().abs_unsigned(), UInt::<13>::new(5).unwrap());
+		assert_eq!(Int::<128>::MIN.abs_unsigned(), UInt::<128>::new(1 << 127).unwrap());
+		assert_eq!(Int::<13>::MIN.sign(), Sign::Negative);
+		assert_eq!(Int::<13>::MAX.signum(), Int::<13>::new(1).unwrap());
+		assert_eq!(Int::<1>::MIN.signum(), Int::<1>::MIN);
+		assert_eq!(UInt::<1>::MAX.signum(), UInt::<1>::MAX);
+		assert_eq!(UInt::<13>::MIN.sign(), Sign::Zero);
+		assert!(!UInt::<13>::MAX.is_negative());
+		assert!(Int::<5>::new(-3).unwrap().is_negative());
+		assert_eq!(UInt::<5>::MAX.try_to_signed(), Err(SignError));
+		assert_eq!(UInt::<5>::MAX.saturating_to_signed(), Int::<5>::MAX);
+		assert_eq!(UInt::<5>::MAX.overflowing_to_signed(), (Int::<5>::new(-1).unwrap(), true));
+		assert_eq!(Int::<5>::MIN.try_to_unsigned(), Err(SignError));
+		assert_eq!(Int::<5>::MIN.saturating_to_unsigned(), UInt::<5>::MIN);
+		assert_eq!(Int::<5>::MAX.overflowing_to_unsigned(), (UInt::<5>::new(15).unwrap(), false));
+		assert_eq!(Int::<5>::try_from_sign_magnitude(true, Int::<5>::MIN.abs_unsigned()), Ok(Int::<5>::MIN));
+		assert_eq!(Int::<5>::try_from_sign_magnitude(false, Int::<5>::MIN.abs_unsigned()), Err(SignError));
+		assert_eq!(Int::<5>::saturating_from_sign_magnitude(false, UInt::<5>::MAX), Int::<5>::MAX);
+		assert_eq!(Int::<5>::wrapping_from_sign_magnitude(false, UInt::<5>::MAX), Int::<5>::new(-1).unwrap());
+		assert_eq!(UInt::<5>::saturating_from_sign_magnitude(true, UInt::<5>::MAX), UInt::<5>::MIN);
+		assert_eq!(Int::<5>::MIN.zigzag_encode(), UInt::<5>::MAX);
+		assert_eq!(Int::<5>::new(-3).unwrap().zigzag_encode(), UInt::<5>::new(5).unwrap());
+		assert_eq!(Int::<5>::zigzag_decode(UInt::<5>::new(30).unwrap()), Int::<5>::MAX);
+		assert_eq!(Int::<1>::zigzag_decode(UInt::<1>::MAX), Int::<1>::MIN);
+	}
+}
@@ -0,0 +1,470 @@
+//! Conversions between arbitrary-width integers and other integers
+//!
+//! As the widths are generic, the conversions are only implemented for valid widths
+//! through [`Narrower`], so using one with an invalid width fails to type check.
+
+// Imports
+use super::{Backing, Bits, Int, Narrower, UInt};
+use crate::{
+	i24, i40, i48, i56,
+	raw::{self, Raw},
+	u24, u40, u48, u56, Const, Extend, SaturatingTruncate, SignExtend, Truncate, TruncateError, TryTruncate, ZeroExtend,
+};
+
+/// Macro to help implement the conversions to the same type
+///
+/// Note: The traits themselves are implemented by their blanket implementations, so
+///       we only implement their `const` versions, and them for `&'a T`.
+macro_rules! impl_identity {
+	($( $T:ident ),+ $(,)?) => {
+		$(
+			impl<const N: u32> Const<$T<N>>
+			where
+				Bits<N>: Backing,
+			{
+				/// Zero extends `value` into the same type, returning it
+				///
+				/// This is the `const` version of [`ZeroExtend::zero_extend`].
+				#[must_use]
+				#[inline]
+				pub const fn zero_extend(value: $T<N>) -> $T<N> {
+					value
+				}
+
+				/// Sign extends `value` into the same type, returning it
+				///
+				/// This is the `const` version of [`SignExtend::sign_extend`].
+				#[must_use]
+				#[inline]
+				pub const fn sign_extend(value: $T<N>) -> $T<N> {
+					value
+				}
+
+				/// Extends `value` into the same type, returning it
+				///
+				/// This is the `const` version of [`Extend::extend`].
+				#[must_use]
+				#[inline]
+				pub const fn extend(value: $T<N>) -> $T<N> {
+					value
+				}
+
+				/// Truncates `value` into the same type, returning it
+				///
+				/// This is the `const` version of [`Truncate::truncate`].
+				#[must_use]
+				#[inline]
+				pub const fn truncate(value: $T<N>) -> $T<N> {
+					value
+				}
+
+				/// Truncates `value` into the same type, which always succeeds
+				///
+				/// This is the `const` version of [`TryTruncate::try_truncate`].
+				///
+				/// # Errors
+				/// Never returns an error.
+				#[inline]
+				pub const fn try_truncate(value: $T<N>) -> Result<$T<N>, TruncateError> {
+					Ok(value)
+				}
+
+				/// Truncates `value` into the same type, which never loses information
+				///
+				/// This is the `const` version of [`TryTruncate::overflowing_truncate`].
+				#[must_use]
+				#[inline]
+				pub const fn overflowing_truncate(value: $T<N>) -> ($T<N>, bool) {
+					(value, false)
+				}
+
+				/// Truncates `value` into the same type, returning it
+				///
+				/// This is the `const` version of [`SaturatingTruncate::saturating_truncate`].
+				#[must_use]
+				#[inline]
+				pub const fn saturating_truncate(value: $T<N>) -> $T<N> {
+					value
+				}
+			}
+		)+
+	};
+}
+
+/// Macro to help implement the extension traits
+///
+/// Note: We also implement these for `&'a T`, by copying the underlying type, like with primitives.
+macro_rules! impl_extend {
+	(impl<$( $N:ident ),+> $T:ty => $U:ty $( where $( $Bound:tt )+ )?) => {
+		impl<$( const $N: u32 ),+> Const<$T, $U>
+		where
+			$( Bits<$N>: Backing, )+
+			$( $( $Bound )+ )?
+		{
+			/// Zero extends `value`
+			///
+			/// This is the `const` version of [`ZeroExtend::zero_extend`].
+			#[must_use]
+			#[inline]
+			pub const fn zero_extend(value: $T) -> $U {
+				Const::<$U>::from_raw(Const::<$T>::to_raw(value))
+			}
+
+			/// Sign extends `value`
+			///
+			/// This is the `const` version of [`SignExtend::sign_extend`].
+			#[must_use]
+			#[inline]
+			pub const fn sign_extend(value: $T) -> $U {
+				Const::<$U>::from_raw(raw::sign_extend(Const::<$T>::to_raw(value), <$T as Raw>::BITS))
+			}
+
+			/// Extends `value`
+			///
+			/// This is the `const` version of [`Extend::extend`].
+			#[must_use]
+			#[inline]
+			pub const fn extend(value: $T) -> $U {
+				Const::<$U>::from_raw(raw::extend_raw::<$T>(Const::<$T>::to_raw(value)))
+			}
+		}
+
+		impl<$( const $N: u32 ),+> ZeroExtend<$U> for $T
+		where
+			$( Bits<$N>: Backing, )+
+			$( $( $Bound )+ )?
+		{
+			#[inline]
+			fn zero_extend(self) -> $U {
+				Const::<$T, $U>::zero_extend(self)
+			}
+		}
+
+		impl<$( const $N: u32 ),+> SignExtend<$U> for $T
+		where
+			$( Bits<$N>: Backing, )+
+			$( $( $Bound )+ )?
+		{
+			#[inline]
+			fn sign_extend(self) -> $U {
+				Const::<$T, $U>::sign_extend(self)
+			}
+		}
+
+		impl<$( const $N: u32 ),+> Extend<$U> for $T
+		where
+			$( Bits<$N>: Backing, )+
+			$( $( $Bound )+ )?
+		{
+			#[inline]
+			fn extend(self) -> $U {
+				Const::<$T, $U>::extend(self)
+			}
+		}
+
+		impl<'a, $( const $N: u32 ),+> ZeroExtend<$U> for &'a $T
+		where
+			$( Bits<$N>: Backing, )+
+			$( $( $Bound )+ )?
+		{
+			#[inline]
+			fn zero_extend(self) -> $U {
+				<$T as ZeroExtend<$U>>::zero_extend(*self)
+			}
+		}
+
+		impl<'a, $( const $N: u32 ),+> SignExtend<$U> for &'a $T
+		where
+			$( Bits<$N>: Backing, )+
+			$( $( $Bound )+ )?
+		{
+			#[inline]
+			fn sign_extend(self) -> $U {
+				<$T as SignExtend<$U>>::sign_extend(*self)
+			}
+		}
+
+		impl<'a, $( const $N: u32 ),+> Extend<$U> for &'a $T
+		where
+			$( Bits<$N>: Backing, )+
+			$( $( $Bound )+ )?
+		{
+			#[inline]
+			fn extend(self) -> $U {
+				<$T as Extend<$U>>::extend(*self)
+			}
+		}
+	};
+}
+
+/// Macro to help implement the truncation traits
+///
+/// Note: We also implement these for `&'a T`, by copying the underlying type, like with primitives.
+macro_rules! impl_truncate {
+	(impl<$( $N:ident ),+> $T:ty => $U:ty $( where $( $Bound:tt )+ )?) => {
+		impl<$( const $N: u32 ),+> Const<$T, $U>
+		where
+			$( Bits<$N>: Backing, )+
+			$( $( $Bound )+ )?
+		{
+			/// Truncates `value`
+			///
+			/// This is the `const` version of [`Truncate::truncate`].
+			#[must_use]
+			#[inline]
+			pub const fn truncate(value: $T) -> $U {
+				Const::<$U>::from_raw(Const::<$T>::to_raw(value))
+			}
+
+			/// Truncates `value`, if it fits
+			///
+			/// This is the `const` version of [`TryTruncate::try_truncate`].
+			///
+			/// # Errors
+			/// Returns [`TruncateError`] if the value doesn't fit.
+			#[inline]
+			pub const fn try_truncate(value: $T) -> Result<$U, TruncateError> {
+				if raw::fits_raw::<$T, $U>(Const::<$T>::to_raw(value)) {
+					Ok(Const::<$T, $U>::truncate(value))
+				} else {
+					Err(TruncateError)
+				}
+			}
+
+			/// Truncates `value`, returning if any information was lost
+			///
+			/// This is the `const` version of [`TryTruncate::overflowing_truncate`].
+			#[must_use]
+			#[inline]
+			pub const fn overflowing_truncate(value: $T) -> ($U, bool) {
+				let truncated = Const::<$T, $U>::truncate(value);
+				let overflowed = !raw::fits_raw::<$T, $U>(Const::<$T>::to_raw(value));
+				(truncated, overflowed)
+			}
+
+			/// Truncates `value`, clamping it to the range of the smaller integer
+			///
+			/// This is the `const` version of [`SaturatingTruncate::saturating_truncate`].
+			#[must_use]
+			#[inline]
+			pub const fn saturating_truncate(value: $T) -> $U {
+				Const::<$U>::from_raw(raw::saturate_raw::<$T, $U>(Const::<$T>::to_raw(value)))
+			}
+		}
+
+		impl<$( const $N: u32 ),+> Truncate<$U> for $T
+		where
+			$( Bits<$N>: Backing, )+
+			$( $( $Bound )+ )?
+		{
+			#[inline]
+			fn truncate(self) -> $U {
+				Const::<$T, $U>::truncate(self)
+			}
+		}
+
+		impl<$( const $N: u32 ),+> TryTruncate<$U> for $T
+		where
+			$( Bits<$N>: Backing, )+
+			$( $( $Bound )+ )?
+		{
+			#[inline]
+			fn try_truncate(self) -> Result<$U, TruncateError> {
+				Const::<$T, $U>::try_truncate(self)
+			}
+
+			#[inline]
+			fn overflowing_truncate(self) -> ($U, bool) {
+				Const::<$T, $U>::overflowing_truncate(self)
+			}
+		}
+
+		impl<$( const $N: u32 ),+> SaturatingTruncate<$U> for $T
+		where
+			$( Bits<$N>: Backing, )+
+			$( $( $Bound )+ )?
+		{
+			#[inline]
+			fn saturating_truncate(self) -> $U {
+				Const::<$T, $U>::saturating_truncate(self)
+			}
+		}
+
+		impl<'a, $( const $N: u32 ),+> Truncate<$U> for &'a $T
+		where
+			$( Bits<$N>: Backing, )+
+			$( $( $Bound )+ )?
+		{
+			#[inline]
+			fn truncate(self) -> $U {
+				<$T as Truncate<$U>>::truncate(*self)
+			}
+		}
+
+		impl<'a, $( const $N: u32 ),+> TryTruncate<$U> for &'a $T
+		where
+			$( Bits<$N>: Backing, )+
+			$( $( $Bound )+ )?
+		{
+			#[inline]
+			fn try_truncate(self) -> Result<$U, TruncateError> {
+				<$T as TryTruncate<$U>>::try_truncate(*self)
+			}
+
+			#[inline]
+			fn overflowing_truncate(self) -> ($U, bool) {
+				<$T as TryTruncate<$U>>::overflowing_truncate(*self)
+			}
+		}
+
+		impl<'a, $( const $N: u32 ),+> SaturatingTruncate<$U> for &'a $T
+		where
+			$( Bits<$N>: Backing, )+
+			$( $( $Bound )+ )?
+		{
+			#[inline]
+			fn saturating_truncate(self) -> $U {
+				<$T as SaturatingTruncate<$U>>::saturating_truncate(*self)
+			}
+		}
+	};
+}
+
+/// Macro to help implement all extension and truncation traits between arbitrary-width and other integers
+///
+/// Each integer is given with the widths below, at and above it, as `T: W - 1, W, W + 1`, and
+/// `$Same` / `$Other` are the arbitrary-width integers of the same and other signedness.
+macro_rules! impl_conv {
+	($Same:ident, $Other:ident => $( $( #[$attr:meta] )* $T:ty : $Below:literal, $Bits:literal, $Above:literal );+ $(;)?) => {
+		$(
+			$( #[$attr] )* impl_extend! { impl<N> $Same<N> => $T where Bits<N>: Narrower<Bits<$Above>> }
+			$( #[$attr] )* impl_extend! { impl<N> $Other<N> => $T where Bits<N>: Narrower<Bits<$Bits>> }
+			$( #[$attr] )* impl_extend! { impl<N> $T => $Same<N> where Bits<$Below>: Narrower<Bits<N>> }
+			$( #[$attr] )* impl_extend! { impl<N> $T => $Other<N> where Bits<$Bits>: Narrower<Bits<N>> }
+			$( #[$attr] )* impl_truncate! { impl<N> $Same<N> => $T where Bits<$Below>: Narrower<Bits<N>> }
+			$( #[$attr] )* impl_truncate! { impl<N> $Other<N> => $T where Bits<$Bits>: Narrower<Bits<N>> }
+			$( #[$attr] )* impl_truncate! { impl<N> $T => $Same<N> where Bits<N>: Narrower<Bits<$Above>> }
+			$( #[$attr] )* impl_truncate! { impl<N> $T => $Other<N> where Bits<N>: Narrower<Bits<$Bits>> }
+		)+
+	};
+}
+
+// Arbitrary-width
+impl_identity! { UInt, Int }
+
+// Note: Converting into the same width is done by the blanket identity implementations,
+//       so the extensions are only implemented into wider widths, and the truncations into narrower ones.
+impl_extend! { impl<N, M> UInt<N> => UInt<M> where Bits<N>: Narrower<Bits<M>> }
+impl_extend! { impl<N, M> UInt<N> => Int<M> where Bits<N>: Narrower<Bits<M>> }
+impl_extend! { impl<N, M> Int<N> => UInt<M> where Bits<N>: Narrower<Bits<M>> }
+impl_extend! { impl<N, M> Int<N> => Int<M> where Bits<N>: Narrower<Bits<M>> }
+impl_truncate! { impl<N, M> UInt<N> => UInt<M> where Bits<M>: Narrower<Bits<N>> }
+impl_truncate! { impl<N, M> UInt<N> => Int<M> where Bits<M>: Narrower<Bits<N>> }
+impl_truncate! { impl<N, M> Int<N> => UInt<M> where Bits<M>: Narrower<Bits<N>> }
+impl_truncate! { impl<N, M> Int<N> => Int<M> where Bits<M>: Narrower<Bits<N>> }
+
+// Primitives
+#[rustfmt::skip]
+impl_conv! { UInt, Int =>
+	u8    :   7,   8,   9;
+	u16   :  15,  16,  17;
+	u32   :  31,  32,  33;
+	u64   :  63,  64,  65;
+	u128  : 127, 128, 129;
+	#[cfg(target_pointer_width = "16")] usize : 15, 16, 17;
+	#[cfg(target_pointer_width = "32")] usize : 31, 32, 33;
+	#[cfg(target_pointer_width = "64")] usize : 63, 64, 65;
+}
+#[rustfmt::skip]
+impl_conv! { Int, UInt =>
+	i8    :   7,   8,   9;
+	i16   :  15,  16,  17;
+	i32   :  31,  32,  33;
+	i64   :  63,  64,  65;
+	i128  : 127, 128, 129;
+	#[cfg(target_pointer_width = "16")] isize : 15, 16, 17;
+	#[cfg(target_pointer_width = "32")] isize : 31, 32, 33;
+	#[cfg(target_pointer_width = "64")] isize : 63, 64, 65;
+}
+
+// Odd-width
+#[rustfmt::skip]
+impl_conv! { UInt, Int =>
+	u24 : 23, 24, 25;
+	u40 : 39, 40, 41;
+	u48 : 47, 48, 49;
+	u56 : 55, 56, 57;
+}
+#[rustfmt::skip]
+impl_conv! { Int, UInt =>
+	i24 : 23, 24, 25;
+	i40 : 39, 40, 41;
+	i48 : 47, 48, 49;
+	i56 : 55, 56, 57;
+}
+
+// Check that the conversions only exist for valid widths
+static_assertions::assert_impl_all! { UInt<8> : ZeroExtend<UInt<9>>, ZeroExtend<u8>, ZeroExtend<i16>, Truncate<UInt<4>>, Truncate<u8>, Truncate<Int<7>> }
+static_assertions::assert_impl_all! { Int<8>  : SignExtend<Int<9>>, SignExtend<i8>, SignExtend<u16>, Truncate<Int<4>>, Truncate<i8>, Truncate<UInt<7>> }
+static_assertions::assert_impl_all! { u8      : Extend<UInt<8>>, Extend<Int<9>>, Truncate<UInt<8>>, Truncate<Int<7>> }
+static_assertions::assert_not_impl_any! { UInt<8> : ZeroExtend<UInt<4>>, ZeroExtend<i8>, Truncate<UInt<9>>, Truncate<u16>, Truncate<i8> }
+static_assertions::assert_not_impl_any! { u8      : Extend<UInt<7>>, Extend<Int<8>>, Truncate<UInt<9>>, Truncate<Int<8>> }
+static_assertions::assert_not_impl_any! { u128    : Truncate<Int<128>> }
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use super::*;
+	use crate::{Extended, SaturatingTruncated, SignExtended, Truncated, TryTruncated, ZeroExtended};
+
+	#[test]
+	#[rustfmt::skip]
+	fn extend() {
+		assert_eq!(UInt::<5>::MAX.zero_extended::<UInt<7>>(), UInt::<7>::new(0x1f).unwrap());
+		assert_eq!(UInt::<5>::MAX.sign_extended::<UInt<7>>(), UInt::<7>::MAX);
+		assert_eq!(UInt::<5>::MAX.extended::<Int<6>>(), Int::<6>::new(0x1f).unwrap());
+		assert_eq!(UInt::<5>::MAX.extended::<UInt<5>>(), UInt::<5>::MAX);
+		assert_eq!(Int::<13>::MIN.extended::<Int<64>>(), Int::<64>::new(-0x1000).unwrap());
+		assert_eq!(Int::<13>::MIN.zero_extended::<UInt<14>>(), UInt::<14>::new(0x1000).unwrap());
+		assert_eq!(Int::<13>::MIN.extended::<i16>(), -0x1000);
+		assert_eq!(Int::<13>::MIN.zero_extended::<u16>(), 0x1000);
+		assert_eq!(Int::<13>::MIN.sign_extended::<u32>(), 0xffff_f000);
+		assert_eq!(UInt::<20>::MAX.extended::<u24>(), u24::new(0x000f_ffff).unwrap());
+		assert_eq!(0xffu8.zero_extended::<UInt<12>>(), UInt::<12>::new(0xff).unwrap());
+		assert_eq!((-1i8).extended::<Int<12>>(), Int::<12>::new(-1).unwrap());
+		assert_eq!((&UInt::<1>::MAX).sign_extended::<i128>(), -1);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn truncate() {
+		assert_eq!(0x1234_5678u32.truncated::<UInt<20>>(), UInt::<20>::new(0x4_5678).unwrap());
+		assert_eq!(0x0000_0400i32.truncated::<Int<11>>(), Int::<11>::MIN);
+		assert_eq!(UInt::<20>::MAX.truncated::<UInt<5>>(), UInt::<5>::MAX);
+		assert_eq!(UInt::<20>::MAX.truncated::<u8>(), u8::MAX);
+		assert_eq!(Int::<20>::MIN.truncated::<Int<20>>(), Int::<20>::MIN);
+		assert_eq!(u48::MAX.truncated::<UInt<40>>(), UInt::<40>::MAX);
+		assert_eq!((&Int::<9>::MIN).truncated::<UInt<8>>(), UInt::<8>::MIN);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn try_truncate() {
+		assert_eq!(0x1fu8.try_truncated::<UInt<5>>(), Ok(UInt::<5>::MAX));
+		assert_eq!(0x20u8.try_truncated::<UInt<5>>(), Err(TruncateError));
+		assert_eq!((-16i8).try_truncated::<Int<5>>(), Ok(Int::<5>::MIN));
+		assert_eq!((-17i8).try_truncated::<Int<5>>(), Err(TruncateError));
+		assert_eq!(Int::<13>::new(-1).unwrap().try_truncated::<UInt<5>>(), Err(TruncateError));
+		assert_eq!(UInt::<13>::MAX.overflowing_truncated::<UInt<5>>(), (UInt::<5>::MAX, true));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn saturating_truncate() {
+		assert_eq!(u32::MAX.saturating_truncated::<UInt<5>>(), UInt::<5>::MAX);
+		assert_eq!(i32::MIN.saturating_truncated::<Int<5>>(), Int::<5>::MIN);
+		assert_eq!(i32::MIN.saturating_truncated::<UInt<5>>(), UInt::<5>::MIN);
+		assert_eq!(Int::<100>::MAX.saturating_truncated::<i64>(), i64::MAX);
+		assert_eq!(UInt::<100>::MAX.saturating_truncated::<Int<13>>(), Int::<13>::MAX);
+	}
+}
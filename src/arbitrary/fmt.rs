@@ -0,0 +1,44 @@
+//! Formatting for arbitrary-width integers
+
+// Imports
+use super::{Backing, Bits, Int, UInt};
+use crate::Signed;
+use core::fmt;
+
+/// Macro to help implement formatting
+///
+/// Note: Signed integers are formatted as unsigned for hexadecimal, octal and binary,
+///       like primitives, so only the bits of the integer are shown.
+macro_rules! impl_fmt {
+	(@inner $T:ident, $( $Trait:path ),+) => {
+		$(
+			impl<const BITS: u32> $Trait for $T<BITS>
+			where
+				Bits<BITS>: Backing,
+			{
+				#[inline]
+				fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+					<_ as $Trait>::fmt(&self.0, f)
+				}
+			}
+		)+
+	};
+
+	(@unsigned $T:ident, $( $Trait:path ),+) => {
+		$(
+			impl<const BITS: u32> $Trait for $T<BITS>
+			where
+				Bits<BITS>: Backing,
+			{
+				#[inline]
+				fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+					<_ as $Trait>::fmt(&self.as_unsigned(), f)
+				}
+			}
+		)+
+	};
+}
+
+impl_fmt! { @inner Int, fmt::Debug, fmt::Display }
+impl_fmt! { @inner UInt, fmt::Debug, fmt::Display, fmt::LowerHex, fmt::UpperHex, fmt::Octal, fmt::Binary }
+impl_fmt! { @unsigned Int, fmt::LowerHex, fmt::UpperHex, fmt::Octal, fmt::Binary }
@@ -0,0 +1,160 @@
+//! `const` conversions
+//!
+//! Trait methods can't be called in `const` contexts, so this module provides
+//! [`Const`], which holds a `const fn` for every conversion between primitive,
+//! odd-width and arbitrary-width integers implemented by the crate's traits, with
+//! the same semantics.
+//!
+//! The functions are defined next to the trait implementations they mirror,
+//! by the same macros, and the traits simply call them. The exception is
+//! [`SignedExt`](crate::SignedExt), which is implemented for any [`Raw`](crate::Raw)
+//! type, through the same helpers as it's `const` versions.
+
+// Imports
+use core::marker::PhantomData;
+
+/// `const` conversions from `T` into `U`
+///
+/// This type is never constructed, it only serves to name
+/// the conversion through it's type parameters:
+///
+/// - Conversions between two types, such as [`ZeroExtend`](crate::ZeroExtend),
+///   [`Truncate`](crate::Truncate) or [`SplitInto`](crate::SplitInto), are
+///   available on `Const<T, U>`, e.g. `Const::<u8, u16>::zero_extend(value)`.
+///   The endian-aware splitting of [`SplitEndian`](crate::SplitEndian) is available on `Const<T, U>`, where `U`
+///   is the unit, e.g. `Const::<u32, u8>::split_be(value)`.
+/// - Conversions of a single type, such as [`Signed`](crate::Signed), [`Split`](crate::Split)
+///   or [`ExtendBits`](crate::ExtendBits), are available on `Const<T>`, e.g. `Const::<i8>::as_unsigned(value)`.
+///
+/// Arbitrary-width integers are named with their width, e.g. `Const::<UInt<5>, u8>::zero_extend(value)`.
+#[derive(Debug)]
+pub struct Const<T, U = T>(PhantomData<(T, U)>);
+
+/// Macro to reverse an array of units in a `const fn`
+///
+/// Note: `<[T]>::reverse` isn't `const`, so we swap them by hand.
+macro_rules! reverse_units {
+	($parts:expr) => {{
+		let mut parts = $parts;
+		let mut idx = 0;
+		while idx < parts.len() / 2 {
+			let last = parts.len() - 1 - idx;
+			let part = parts[idx];
+			parts[idx] = parts[last];
+			parts[last] = part;
+			idx += 1;
+		}
+		parts
+	}};
+}
+
+// Note: Exported by path, so it may be used by all modules, regardless of their order.
+pub(crate) use reverse_units;
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use super::*;
+	use crate::{i24, u24, u48, Int, Sign, SignError, TruncateError, UInt};
+
+	#[test]
+	#[rustfmt::skip]
+	fn conversions() {
+		const ZERO_EXTEND: u32        = Const::<i8, u32>::zero_extend(-1);
+		const SIGN_EXTEND: u32        = Const::<u8, u32>::sign_extend(0x80);
+		const EXTEND: i64             = Const::<i16, i64>::extend(-2);
+		const TRUNCATE: u8            = Const::<u32, u8>::truncate(0x1234);
+		const TRY_TRUNCATE: Result<i8, TruncateError> = Const::<u16, i8>::try_truncate(0x80);
+		const OVERFLOWING: (u8, bool) = Const::<i16, u8>::overflowing_truncate(-1);
+		const SATURATING: i8          = Const::<i32, i8>::saturating_truncate(-1000);
+		const AS_SIGNED: i16          = Const::<u16>::as_signed(0xffff);
+		const ABS_UNSIGNED: u8        = Const::<i8>::abs_unsigned(i8::MIN);
+		const TRY_SIGNED: Result<i8, SignError> = Const::<u8>::try_to_signed(0x80);
+		const SIGN: Sign              = Const::<i32>::sign(-5);
+		const TO_SIGNED: i16          = Const::<u16>::saturating_to_signed(0xffff);
+		const MAGNITUDE: i8           = Const::<i8>::saturating_from_sign_magnitude(true, 0xff);
+		const ZIGZAG: u64             = Const::<i64>::zigzag_encode(-3);
+		const LO_HI: (u32, i32)       = Const::<i64>::lo_hi(-2);
+		const JOIN: u16               = Const::<u16>::join(0x34, 0x12);
+		const SPLIT_INTO: [u8; 4]     = Const::<u32, [u8; 4]>::split_into(0x1234_5678);
+		const SPLIT_BE: [u16; 2]      = Const::<u32, u16>::split_be(0x1234_5678);
+		const JOIN_LE: i32            = Const::<i32, u8>::join_le([0xfe, 0xff, 0xff, 0xff]);
+		const EXTEND_FROM: u32        = Const::<u32>::sign_extend_from(0x0800, 12);
+		const TRUNCATE_TO: i32        = Const::<i32>::saturating_sign_truncate_to(i32::MAX, 12);
+
+		assert_eq!(ZERO_EXTEND , 0xff);
+		assert_eq!(SIGN_EXTEND , 0xffff_ff80);
+		assert_eq!(EXTEND      , -2);
+		assert_eq!(TRUNCATE    , 0x34);
+		assert_eq!(TRY_TRUNCATE, Err(TruncateError));
+		assert_eq!(OVERFLOWING , (0xff, true));
+		assert_eq!(SATURATING  , i8::MIN);
+		assert_eq!(AS_SIGNED   , -1);
+		assert_eq!(ABS_UNSIGNED, 0x80);
+		assert_eq!(TRY_SIGNED  , Err(SignError));
+		assert_eq!(SIGN        , Sign::Negative);
+		assert_eq!(TO_SIGNED   , i16::MAX);
+		assert_eq!(MAGNITUDE   , i8::MIN);
+		assert_eq!(ZIGZAG      , 5);
+		assert_eq!(LO_HI       , (0xffff_fffe, -1));
+		assert_eq!(JOIN        , 0x1234);
+		assert_eq!(SPLIT_INTO  , [0x78, 0x56, 0x34, 0x12]);
+		assert_eq!(SPLIT_BE    , [0x1234, 0x5678]);
+		assert_eq!(JOIN_LE     , -2);
+		assert_eq!(EXTEND_FROM , 0xffff_f800);
+		assert_eq!(TRUNCATE_TO , 0x07ff);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn odd() {
+		const EXTEND: i32       = Const::<i24, i32>::extend(i24::MIN);
+		const TRUNCATE: u24     = Const::<u32, u24>::truncate(0x1234_5678);
+		const SATURATING: u24   = Const::<i32, u24>::saturating_truncate(-1);
+		const AS_UNSIGNED: u24  = Const::<i24>::as_unsigned(i24::MIN);
+		const MAGNITUDE: u24    = Const::<i24>::abs_unsigned(i24::MIN);
+		const SIGNUM: i24       = Const::<i24>::signum(i24::MIN);
+		const UNSIGNED: Result<u24, SignError> = Const::<i24>::try_to_unsigned(i24::MIN);
+		const ZIGZAG: i24       = Const::<u24>::zigzag_decode(u24::MAX);
+		const LO_HI: (u24, u24) = Const::<u48>::lo_hi(u48::MAX);
+		const SPLIT_LE: [u8; 3] = Const::<u24, u8>::split_le(u24::MAX);
+		const EXTEND_FROM: i24  = Const::<i24>::sign_extend_from(i24::MAX, 12);
+
+		assert_eq!(EXTEND     , -0x0080_0000);
+		assert_eq!(TRUNCATE   , u24::new(0x0034_5678).unwrap());
+		assert_eq!(SATURATING , u24::MIN);
+		assert_eq!(AS_UNSIGNED, u24::new(0x0080_0000).unwrap());
+		assert_eq!(MAGNITUDE  , u24::new(0x0080_0000).unwrap());
+		assert_eq!(SIGNUM     , i24::new(-1).unwrap());
+		assert_eq!(UNSIGNED   , Err(SignError));
+		assert_eq!(ZIGZAG     , i24::MIN);
+		assert_eq!(LO_HI      , (u24::MAX, u24::MAX));
+		assert_eq!(SPLIT_LE   , [0xff, 0xff, 0xff]);
+		assert_eq!(EXTEND_FROM, i24::new(-1).unwrap());
+	}
+	#[test]
+	#[rustfmt::skip]
+	fn arbitrary() {
+		const ZERO_EXTEND: u8         = Const::<UInt<5>, u8>::zero_extend(UInt::<5>::MAX);
+		const EXTEND: Int<13>         = Const::<Int<5>, Int<13>>::extend(Int::<5>::MIN);
+		const IDENTITY: UInt<5>       = Const::<UInt<5>>::truncate(UInt::<5>::MAX);
+		const TRUNCATE: UInt<5>       = Const::<u8, UInt<5>>::truncate(0xff);
+		const TRY_TRUNCATE: Result<Int<5>, TruncateError> = Const::<i8, Int<5>>::try_truncate(-17);
+		const SATURATING: UInt<12>    = Const::<Int<100>, UInt<12>>::saturating_truncate(Int::<100>::MAX);
+		const AS_SIGNED: Int<5>       = Const::<UInt<5>>::as_signed(UInt::<5>::MAX);
+		const MAGNITUDE: UInt<128>    = Const::<Int<128>>::abs_unsigned(Int::<128>::MIN);
+		const SIGN: Sign              = Const::<Int<1>>::sign(Int::<1>::MIN);
+		const ZIGZAG: UInt<5>         = Const::<Int<5>>::zigzag_encode(Int::<5>::MIN);
+
+		assert_eq!(ZERO_EXTEND , 0x1f);
+		assert_eq!(EXTEND      , Int::<13>::new(-16).unwrap());
+		assert_eq!(IDENTITY    , UInt::<5>::MAX);
+		assert_eq!(TRUNCATE    , UInt::<5>::MAX);
+		assert_eq!(TRY_TRUNCATE, Err(TruncateError));
+		assert_eq!(SATURATING  , UInt::<12>::MAX);
+		assert_eq!(AS_SIGNED   , Int::<5>::new(-1).unwrap());
+		assert_eq!(MAGNITUDE   , UInt::<128>::new(1 << 127).unwrap());
+		assert_eq!(SIGN        , Sign::Negative);
+		assert_eq!(ZIGZAG      , UInt::<5>::MAX);
+	}
+}
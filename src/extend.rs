@@ -4,11 +4,13 @@
 //! an integer past it's range.
 
 // Modules
+pub mod bits;
 pub mod default;
 pub mod sign;
 pub mod zero;
 
 // Exports
+pub use bits::ExtendBits;
 pub use default::{Extend, Extended};
 pub use sign::{SignExtend, SignExtended};
 pub use zero::{ZeroExtend, ZeroExtended};
@@ -0,0 +1,271 @@
+//! Extension from an arbitrary bit width
+
+// Imports
+use crate::{Const, SignExtend, Signed, ZeroExtend};
+
+/// Extension from an arbitrary bit width
+///
+/// This trait serves to extend integers whose value is stored in
+/// their lower `bits` bits, such as fields decoded from an instruction.
+///
+/// Bits above the field are ignored, unless using the checked variants, which
+/// reject them. An empty field (`bits == 0`) always holds `0`, while a field at
+/// least as wide as the integer leaves it unchanged.
+pub trait ExtendBits: Sized {
+	/// Sign extends the lower `bits` bits of this integer
+	#[must_use]
+	fn sign_extend_from(self, bits: u32) -> Self;
+
+	/// Zero extends the lower `bits` bits of this integer
+	#[must_use]
+	fn zero_extend_from(self, bits: u32) -> Self;
+
+	/// Sign extends the lower `bits` bits of this integer, if no bits above them are set
+	fn checked_sign_extend_from(self, bits: u32) -> Option<Self>;
+
+	/// Zero extends the lower `bits` bits of this integer, if no bits above them are set
+	fn checked_zero_extend_from(self, bits: u32) -> Option<Self>;
+
+	/// Sign extends the lower `BITS` bits of this integer
+	#[inline]
+	#[must_use]
+	fn sign_extend_bits<const BITS: u32>(self) -> Self {
+		self.sign_extend_from(BITS)
+	}
+
+	/// Zero extends the lower `BITS` bits of this integer
+	#[inline]
+	#[must_use]
+	fn zero_extend_bits<const BITS: u32>(self) -> Self {
+		self.zero_extend_from(BITS)
+	}
+
+	/// Sign extends the lower `BITS` bits of this integer, if no bits above them are set
+	#[inline]
+	fn checked_sign_extend_bits<const BITS: u32>(self) -> Option<Self> {
+		self.checked_sign_extend_from(BITS)
+	}
+
+	/// Zero extends the lower `BITS` bits of this integer, if no bits above them are set
+	#[inline]
+	fn checked_zero_extend_bits<const BITS: u32>(self) -> Option<Self> {
+		self.checked_zero_extend_from(BITS)
+	}
+
+	/// Sign extends the lower `bits` bits of this integer into `T`
+	#[inline]
+	fn sign_extended_from<T>(self, bits: u32) -> T
+	where
+		Self: SignExtend<T>,
+	{
+		// Note: The sign bit of the field is now the sign bit of `self`, so sign extending preserves it
+		self.sign_extend_from(bits).sign_extend()
+	}
+
+	/// Zero extends the lower `bits` bits of this integer into `T`
+	#[inline]
+	fn zero_extended_from<T>(self, bits: u32) -> T
+	where
+		Self: ZeroExtend<T>,
+	{
+		self.zero_extend_from(bits).zero_extend()
+	}
+
+	/// Sign extends the lower `bits` bits of this integer into `T`, if no bits above them are set
+	#[inline]
+	fn checked_sign_extended_from<T>(self, bits: u32) -> Option<T>
+	where
+		Self: SignExtend<T>,
+	{
+		self.checked_sign_extend_from(bits).map(SignExtend::sign_extend)
+	}
+
+	/// Zero extends the lower `bits` bits of this integer into `T`, if no bits above them are set
+	#[inline]
+	fn checked_zero_extended_from<T>(self, bits: u32) -> Option<T>
+	where
+		Self: ZeroExtend<T>,
+	{
+		self.checked_zero_extend_from(bits).map(ZeroExtend::zero_extend)
+	}
+}
+
+/// Macro to help implement [`ExtendBits`]
+macro_rules! impl_extend_bits {
+	($( $T:ty ),+ $(,)?) => {
+		$(
+			impl Const<$T> {
+				/// Sign extends the lower `bits` bits of `value`
+				///
+				/// This is the `const` version of [`ExtendBits::sign_extend_from`].
+				#[must_use]
+				#[inline]
+				#[allow(clippy::as_conversions)]
+				pub const fn sign_extend_from(value: $T, bits: u32) -> $T {
+					match bits {
+						0 => 0,
+						_ if bits >= <$T>::BITS => value,
+						_ => {
+							// Note: Shifting the field's sign bit into our sign bit and then
+							//       arithmetic shifting it back will sign-extend it.
+							// Note: Casting between signedness is a no-op.
+							let shift = <$T>::BITS - bits;
+							(Const::<$T>::as_signed(value) << shift >> shift) as $T
+						},
+					}
+				}
+
+				/// Zero extends the lower `bits` bits of `value`
+				///
+				/// This is the `const` version of [`ExtendBits::zero_extend_from`].
+				#[must_use]
+				#[inline]
+				#[allow(clippy::as_conversions)]
+				pub const fn zero_extend_from(value: $T, bits: u32) -> $T {
+					match bits {
+						_ if bits >= <$T>::BITS => value,
+						_ => {
+							// Note: Casting between signedness is a no-op.
+							let mask = !(<<$T as Signed>::Unsigned>::MAX << bits);
+							(Const::<$T>::as_unsigned(value) & mask) as $T
+						},
+					}
+				}
+
+				/// Sign extends the lower `bits` bits of `value`, if no bits above them are set
+				///
+				/// This is the `const` version of [`ExtendBits::checked_sign_extend_from`].
+				#[must_use]
+				#[inline]
+				pub const fn checked_sign_extend_from(value: $T, bits: u32) -> Option<$T> {
+					match Const::<$T>::checked_zero_extend_from(value, bits) {
+						Some(value) => Some(Const::<$T>::sign_extend_from(value, bits)),
+						None => None,
+					}
+				}
+
+				/// Zero extends the lower `bits` bits of `value`, if no bits above them are set
+				///
+				/// This is the `const` version of [`ExtendBits::checked_zero_extend_from`].
+				#[must_use]
+				#[inline]
+				pub const fn checked_zero_extend_from(value: $T, bits: u32) -> Option<$T> {
+					let extended = Const::<$T>::zero_extend_from(value, bits);
+					if extended == value {
+						Some(extended)
+					} else {
+						None
+					}
+				}
+			}
+
+			impl ExtendBits for $T {
+				#[inline]
+				fn sign_extend_from(self, bits: u32) -> Self {
+					Const::<$T>::sign_extend_from(self, bits)
+				}
+
+				#[inline]
+				fn zero_extend_from(self, bits: u32) -> Self {
+					Const::<$T>::zero_extend_from(self, bits)
+				}
+
+				#[inline]
+				fn checked_sign_extend_from(self, bits: u32) -> Option<Self> {
+					Const::<$T>::checked_sign_extend_from(self, bits)
+				}
+
+				#[inline]
+				fn checked_zero_extend_from(self, bits: u32) -> Option<Self> {
+					Const::<$T>::checked_zero_extend_from(self, bits)
+				}
+			}
+		)+
+	};
+}
+
+impl_extend_bits! { u8, u16, u32, u64, u128, usize }
+impl_extend_bits! { i8, i16, i32, i64, i128, isize }
+
+// Check that all `ExtendBits` impls exist
+static_assertions::assert_impl_all! { i8   : ExtendBits }
+static_assertions::assert_impl_all! { i16  : ExtendBits }
+static_assertions::assert_impl_all! { i32  : ExtendBits }
+static_assertions::assert_impl_all! { i64  : ExtendBits }
+static_assertions::assert_impl_all! { i128 : ExtendBits }
+static_assertions::assert_impl_all! { isize: ExtendBits }
+static_assertions::assert_impl_all! { u8   : ExtendBits }
+static_assertions::assert_impl_all! { u16  : ExtendBits }
+static_assertions::assert_impl_all! { u32  : ExtendBits }
+static_assertions::assert_impl_all! { u64  : ExtendBits }
+static_assertions::assert_impl_all! { u128 : ExtendBits }
+static_assertions::assert_impl_all! { usize: ExtendBits }
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use super::*;
+
+	#[test]
+	#[rustfmt::skip]
+	fn sign_extend_from() {
+		assert_eq!(0x0000_0fffu32.sign_extend_from(12), 0xffff_ffff);
+		assert_eq!(0x0000_07ffu32.sign_extend_from(12), 0x0000_07ff);
+		assert_eq!(0x0000_f800u32.sign_extend_from(12), 0xffff_f800);
+		assert_eq!(0x0000_0fffi32.sign_extend_from(12), -1);
+		assert_eq!(0x0000_0800i32.sign_extend_from(12), -0x800);
+		assert_eq!(   0x80u8     .sign_extend_from( 8), 0x80);
+		assert_eq!(   (-1i8)     .sign_extend_from( 1), -1);
+		assert_eq!(      1i8     .sign_extend_from( 1), -1);
+		assert_eq!(      2i8     .sign_extend_from( 1),  0);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn zero_extend_from() {
+		assert_eq!(0xffff_ffffu32.zero_extend_from(12), 0x0000_0fff);
+		assert_eq!(       (-1i32).zero_extend_from(12), 0x0000_0fff);
+		assert_eq!(       (-1i32).zero_extend_from(31), i32::MAX);
+		assert_eq!(       (-1i32).zero_extend_from(32), -1);
+		assert_eq!(     u128::MAX.zero_extend_from(127), u128::MAX >> 1);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn extend_from_edges() {
+		assert_eq!(u32::MAX.sign_extend_from( 0), 0);
+		assert_eq!(u32::MAX.zero_extend_from( 0), 0);
+		assert_eq!(i32::MIN.sign_extend_from(32), i32::MIN);
+		assert_eq!(i32::MIN.sign_extend_from(64), i32::MIN);
+		assert_eq!(i32::MIN.zero_extend_from(64), i32::MIN);
+
+		assert_eq!(0u32.checked_sign_extend_from(0), Some(0));
+		assert_eq!(1u32.checked_sign_extend_from(0), None);
+		assert_eq!(u32::MAX.checked_zero_extend_from(32), Some(u32::MAX));
+		assert_eq!(u32::MAX.checked_zero_extend_from(64), Some(u32::MAX));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn extend_bits() {
+		assert_eq!(0x0fffu16.sign_extend_bits::<12>(), 0xffff);
+		assert_eq!(0xffffu16.zero_extend_bits::<12>(), 0x0fff);
+		assert_eq!(0x0fffu16.checked_sign_extend_bits::<12>(), Some(0xffff));
+		assert_eq!(0x1fffu16.checked_sign_extend_bits::<12>(), None);
+		assert_eq!(0x0fffu16.checked_zero_extend_bits::<12>(), Some(0x0fff));
+		assert_eq!(0x1fffu16.checked_zero_extend_bits::<12>(), None);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn extend_from_wider() {
+		assert_eq!(0x0000_0fffu32.sign_extended_from::<i64>(12), -1);
+		assert_eq!(0x0000_07ffu32.sign_extended_from::<i64>(12), 0x7ff);
+		assert_eq!(0xffff_ffffu32.zero_extended_from::<u64>(12), 0xfff);
+		assert_eq!(0xffff_ffffu32.zero_extended_from::<i64>(12), 0xfff);
+		assert_eq!(0x0000_0800u32.checked_sign_extended_from::<i64>(12), Some(-0x800));
+		assert_eq!(0x0000_1800u32.checked_sign_extended_from::<i64>(12), None);
+		assert_eq!(0x0000_0800u32.checked_zero_extended_from::<u128>(12), Some(0x800));
+		assert_eq!(0x0000_1800u32.checked_zero_extended_from::<u128>(12), None);
+	}
+}
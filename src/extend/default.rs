@@ -1,12 +1,17 @@
 //! Default extension
 
 // Imports
-use crate::{SignExtend, ZeroExtend};
+use crate::Const;
 
 /// Generic extension
 ///
 /// This type performs either a zero extend or
 /// a sign extend, depending if the type is signed.
+///
+/// When extending into an integer of a different signedness,
+/// the signedness of the source type is used. This means that
+/// unsigned integers are always zero extended, while signed
+/// integers are always sign extended.
 pub trait Extend<T>: Sized {
 	/// Extends this type
 	fn extend(self) -> T;
@@ -20,6 +25,37 @@ impl<T> Extend<T> for T {
 	}
 }
 
+/// Macro to help implement the `const` version of [`Extend`] to the same type
+///
+/// Note: The trait itself is implemented by the blanket implementation above, so
+///       we only implement it for references here, by copying the underlying type.
+macro_rules! impl_extend_identity {
+	($( $T:ty ),+ $(,)?) => {
+		$(
+			impl Const<$T> {
+				/// Extends `value` into the same type, returning it
+				///
+				/// This is the `const` version of [`Extend::extend`].
+				#[must_use]
+				#[inline]
+				pub const fn extend(value: $T) -> $T {
+					value
+				}
+			}
+
+			impl<'a> Extend<$T> for &'a $T {
+				#[inline]
+				fn extend(self) -> $T {
+					<$T as Extend<$T>>::extend(*self)
+				}
+			}
+		)+
+	};
+}
+
+impl_extend_identity! { u8, u16, u32, u64, u128, usize }
+impl_extend_identity! { i8, i16, i32, i64, i128, isize }
+
 /// Macro to help implement [`Extend`]
 ///
 /// Note: Regardless if `GAT`s are available, a `impl Extend<&'b U> for &'a T` isn't
@@ -28,11 +64,22 @@ impl<T> Extend<T> for T {
 macro_rules! impl_extend {
 	($T:ty => $( $U:ty ),+ $(,)? => $method:ident) => {
 		$(
+			impl Const<$T, $U> {
+				/// Extends `value`
+				///
+				/// This is the `const` version of [`Extend::extend`].
+				#[must_use]
+				#[inline]
+				pub const fn extend(value: $T) -> $U {
+					Const::<$T, $U>::$method(value)
+				}
+			}
+
 			impl Extend<$U> for $T
 			{
 				#[inline]
 				fn extend(self) -> $U {
-					self.$method()
+					Const::<$T, $U>::extend(self)
 				}
 			}
 
@@ -62,6 +109,51 @@ impl_extend! { i16  =>      i32, i64, i128 => sign_extend }
 impl_extend! { i32  =>           i64, i128 => sign_extend }
 impl_extend! { i64  =>                i128 => sign_extend }
 
+// Unsigned to signed
+impl_extend! { u8   => i16, i32, i64, i128 => zero_extend }
+impl_extend! { u16  =>      i32, i64, i128 => zero_extend }
+impl_extend! { u32  =>           i64, i128 => zero_extend }
+impl_extend! { u64  =>                i128 => zero_extend }
+
+// Signed to unsigned
+impl_extend! { i8   => u16, u32, u64, u128 => sign_extend }
+impl_extend! { i16  =>      u32, u64, u128 => sign_extend }
+impl_extend! { i32  =>           u64, u128 => sign_extend }
+impl_extend! { i64  =>                u128 => sign_extend }
+
+// Pointer-sized
+// Note: These only exist where the extension is lossless for the current pointer width.
+impl_extend! { u8    => usize, isize => zero_extend }
+impl_extend! { u16   => usize => zero_extend }
+impl_extend! { usize => u64, u128, i128 => zero_extend }
+impl_extend! { i8    => usize, isize => sign_extend }
+impl_extend! { i16   => isize => sign_extend }
+impl_extend! { isize => u128, i64, i128 => sign_extend }
+#[cfg(any(target_pointer_width = "16", target_pointer_width = "32"))]
+impl_extend! { usize => u32, i64 => zero_extend }
+#[cfg(any(target_pointer_width = "16", target_pointer_width = "32"))]
+impl_extend! { isize => u64, i32 => sign_extend }
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl_extend! { u16   => isize => zero_extend }
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl_extend! { u32   => usize => zero_extend }
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl_extend! { i16   => usize => sign_extend }
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl_extend! { i32   => isize => sign_extend }
+#[cfg(target_pointer_width = "16")]
+impl_extend! { usize => u16, i32 => zero_extend }
+#[cfg(target_pointer_width = "16")]
+impl_extend! { isize => u32, i16 => sign_extend }
+#[cfg(target_pointer_width = "64")]
+impl_extend! { u32   => isize => zero_extend }
+#[cfg(target_pointer_width = "64")]
+impl_extend! { u64   => usize => zero_extend }
+#[cfg(target_pointer_width = "64")]
+impl_extend! { i32   => usize => sign_extend }
+#[cfg(target_pointer_width = "64")]
+impl_extend! { i64   => isize => sign_extend }
+
 /// Helper trait for [`Extend`] to be used with turbofish syntax
 pub trait Extended {
 	/// Extends this type
@@ -87,6 +179,54 @@ static_assertions::assert_impl_all! { u32  :
 static_assertions::assert_impl_all! { u64  :                                                       Extend<u64>,     Extend<u128> }
 static_assertions::assert_impl_all! { u128 :                                                                        Extend<u128> }
 
+// Check that all mixed-signedness `Extend` impls exist
+static_assertions::assert_impl_all! { i8   :             Extend<u16>, Extend<u32>, Extend<u64>, Extend<u128> }
+static_assertions::assert_impl_all! { i16  :                          Extend<u32>, Extend<u64>, Extend<u128> }
+static_assertions::assert_impl_all! { i32  :                                       Extend<u64>, Extend<u128> }
+static_assertions::assert_impl_all! { i64  :                                                    Extend<u128> }
+static_assertions::assert_impl_all! { u8   :             Extend<i16>, Extend<i32>, Extend<i64>, Extend<i128> }
+static_assertions::assert_impl_all! { u16  :                          Extend<i32>, Extend<i64>, Extend<i128> }
+static_assertions::assert_impl_all! { u32  :                                       Extend<i64>, Extend<i128> }
+static_assertions::assert_impl_all! { u64  :                                                    Extend<i128> }
+
+// Check that integers of the same size, but different signedness, don't implement `Extend`
+static_assertions::assert_not_impl_any! { i8   : Extend<u8> }
+static_assertions::assert_not_impl_any! { i16  : Extend<u16> }
+static_assertions::assert_not_impl_any! { i32  : Extend<u32> }
+static_assertions::assert_not_impl_any! { i64  : Extend<u64> }
+static_assertions::assert_not_impl_any! { i128 : Extend<u128> }
+static_assertions::assert_not_impl_any! { u8   : Extend<i8> }
+static_assertions::assert_not_impl_any! { u16  : Extend<i16> }
+static_assertions::assert_not_impl_any! { u32  : Extend<i32> }
+static_assertions::assert_not_impl_any! { u64  : Extend<i64> }
+static_assertions::assert_not_impl_any! { u128 : Extend<i128> }
+
+// Check that all pointer-sized `Extend` impls exist for each pointer width
+#[cfg(target_pointer_width = "16")]
+static_assertions::assert_impl_all! { usize : Extend<usize>, Extend<u16>, Extend<u32>, Extend<u64>, Extend<u128>, Extend<i32>, Extend<i64>, Extend<i128> }
+#[cfg(target_pointer_width = "16")]
+static_assertions::assert_not_impl_any! { usize : Extend<u8>, Extend<i8>, Extend<i16>, Extend<isize> }
+#[cfg(target_pointer_width = "16")]
+static_assertions::assert_impl_all! { isize : Extend<isize>, Extend<u32>, Extend<u64>, Extend<u128>, Extend<i16>, Extend<i32>, Extend<i64>, Extend<i128> }
+#[cfg(target_pointer_width = "16")]
+static_assertions::assert_not_impl_any! { isize : Extend<u8>, Extend<u16>, Extend<usize>, Extend<i8> }
+#[cfg(target_pointer_width = "32")]
+static_assertions::assert_impl_all! { usize : Extend<usize>, Extend<u32>, Extend<u64>, Extend<u128>, Extend<i64>, Extend<i128> }
+#[cfg(target_pointer_width = "32")]
+static_assertions::assert_not_impl_any! { usize : Extend<u8>, Extend<u16>, Extend<i8>, Extend<i16>, Extend<i32>, Extend<isize> }
+#[cfg(target_pointer_width = "32")]
+static_assertions::assert_impl_all! { isize : Extend<isize>, Extend<u64>, Extend<u128>, Extend<i32>, Extend<i64>, Extend<i128> }
+#[cfg(target_pointer_width = "32")]
+static_assertions::assert_not_impl_any! { isize : Extend<u8>, Extend<u16>, Extend<u32>, Extend<usize>, Extend<i8>, Extend<i16> }
+#[cfg(target_pointer_width = "64")]
+static_assertions::assert_impl_all! { usize : Extend<usize>, Extend<u64>, Extend<u128>, Extend<i128> }
+#[cfg(target_pointer_width = "64")]
+static_assertions::assert_not_impl_any! { usize : Extend<u8>, Extend<u16>, Extend<u32>, Extend<i8>, Extend<i16>, Extend<i32>, Extend<i64>, Extend<isize> }
+#[cfg(target_pointer_width = "64")]
+static_assertions::assert_impl_all! { isize : Extend<isize>, Extend<u128>, Extend<i64>, Extend<i128> }
+#[cfg(target_pointer_width = "64")]
+static_assertions::assert_not_impl_any! { isize : Extend<u8>, Extend<u16>, Extend<u32>, Extend<u64>, Extend<usize>, Extend<i8>, Extend<i16>, Extend<i32> }
+
 #[cfg(test)]
 mod tests {
 	// Imports
@@ -157,4 +297,56 @@ mod tests {
 		assert_eq!(i32::extended::<i128>(-1), i32::sign_extended::<i128>(-1));
 		assert_eq!(i64::extended::<i128>(-1), i64::sign_extended::<i128>(-1));
 	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn extend_mixed() {
+		assert_eq!( u8::extended::< i16>( u8::MAX),  u8::zero_extended::< i16>( u8::MAX));
+		assert_eq!( u8::extended::<i128>( u8::MAX),  u8::zero_extended::<i128>( u8::MAX));
+		assert_eq!(u16::extended::< i32>(u16::MAX), u16::zero_extended::< i32>(u16::MAX));
+		assert_eq!(u32::extended::< i64>(u32::MAX), u32::zero_extended::< i64>(u32::MAX));
+		assert_eq!(u64::extended::<i128>(u64::MAX), u64::zero_extended::<i128>(u64::MAX));
+
+		assert_eq!( i8::extended::< u16>(-1),  i8::sign_extended::< u16>(-1));
+		assert_eq!( i8::extended::<u128>(-1),  i8::sign_extended::<u128>(-1));
+		assert_eq!(i16::extended::< u32>(-1), i16::sign_extended::< u32>(-1));
+		assert_eq!(i32::extended::< u64>(-1), i32::sign_extended::< u64>(-1));
+		assert_eq!(i64::extended::<u128>(-1), i64::sign_extended::<u128>(-1));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	#[cfg(target_pointer_width = "64")]
+	fn extend_pointer_sized() {
+		assert_eq!(u32::extended::<usize>(u32::MAX), u32::zero_extended::<usize>(u32::MAX));
+		assert_eq!(u32::extended::<isize>(u32::MAX), u32::zero_extended::<isize>(u32::MAX));
+		assert_eq!(i32::extended::<usize>(-1), i32::sign_extended::<usize>(-1));
+		assert_eq!(i32::extended::<isize>(-1), i32::sign_extended::<isize>(-1));
+		assert_eq!(usize::extended::<u128>(usize::MAX), usize::zero_extended::<u128>(usize::MAX));
+		assert_eq!(isize::extended::<u128>(-1), isize::sign_extended::<u128>(-1));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	#[cfg(target_pointer_width = "32")]
+	fn extend_pointer_sized() {
+		assert_eq!(u16::extended::<usize>(u16::MAX), u16::zero_extended::<usize>(u16::MAX));
+		assert_eq!(u16::extended::<isize>(u16::MAX), u16::zero_extended::<isize>(u16::MAX));
+		assert_eq!(i16::extended::<usize>(-1), i16::sign_extended::<usize>(-1));
+		assert_eq!(i16::extended::<isize>(-1), i16::sign_extended::<isize>(-1));
+		assert_eq!(usize::extended::<u64>(usize::MAX), usize::zero_extended::<u64>(usize::MAX));
+		assert_eq!(isize::extended::<u64>(-1), isize::sign_extended::<u64>(-1));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	#[cfg(target_pointer_width = "16")]
+	fn extend_pointer_sized() {
+		assert_eq!(u8::extended::<usize>(u8::MAX), u8::zero_extended::<usize>(u8::MAX));
+		assert_eq!(u8::extended::<isize>(u8::MAX), u8::zero_extended::<isize>(u8::MAX));
+		assert_eq!(i8::extended::<usize>(-1), i8::sign_extended::<usize>(-1));
+		assert_eq!(i8::extended::<isize>(-1), i8::sign_extended::<isize>(-1));
+		assert_eq!(usize::extended::<u32>(usize::MAX), usize::zero_extended::<u32>(usize::MAX));
+		assert_eq!(isize::extended::<u32>(-1), isize::sign_extended::<u32>(-1));
+	}
 }
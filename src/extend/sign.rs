@@ -1,13 +1,20 @@
 //! Sign extension
 
 // Imports
-use crate::Signed;
+use crate::{Const, Signed};
 use core::mem;
 
 /// Sign extends
 ///
 /// This trait serves to extend integers with their
 /// sign signal.
+///
+/// Unsigned integers are also supported, in which case
+/// their most significant bit is treated as the sign.
+///
+/// Extending into an integer of a different signedness is also supported,
+/// as long as it's larger. Integers of the same size but different signedness
+/// are instead interchanged with [`Signed`].
 pub trait SignExtend<T>: Sized {
 	/// Sign extends this type
 	fn sign_extend(self) -> T;
@@ -21,6 +28,37 @@ impl<T> SignExtend<T> for T {
 	}
 }
 
+/// Macro to help implement the `const` version of [`SignExtend`] to the same type
+///
+/// Note: The trait itself is implemented by the blanket implementation above, so
+///       we only implement it for references here, by copying the underlying type.
+macro_rules! impl_sign_extend_identity {
+	($( $T:ty ),+ $(,)?) => {
+		$(
+			impl Const<$T> {
+				/// Sign extends `value` into the same type, returning it
+				///
+				/// This is the `const` version of [`SignExtend::sign_extend`].
+				#[must_use]
+				#[inline]
+				pub const fn sign_extend(value: $T) -> $T {
+					value
+				}
+			}
+
+			impl<'a> SignExtend<$T> for &'a $T {
+				#[inline]
+				fn sign_extend(self) -> $T {
+					<$T as SignExtend<$T>>::sign_extend(*self)
+				}
+			}
+		)+
+	};
+}
+
+impl_sign_extend_identity! { u8, u16, u32, u64, u128, usize }
+impl_sign_extend_identity! { i8, i16, i32, i64, i128, isize }
+
 /// Macro to help implement [`SignExtend`]
 ///
 /// Note: Regardless if `GAT`s are available, a `impl SignExtend<&'b U> for &'a T` isn't
@@ -33,20 +71,31 @@ macro_rules! impl_sign_extend {
 			// Note: It is guaranteed that signed and unsigned variants have the same size
 			::static_assertions::const_assert!(mem::size_of::<$U>() >= mem::size_of::<$T>());
 
-			impl SignExtend<$U> for $T
-			{
+			impl Const<$T, $U> {
+				/// Sign extends `value`
+				///
+				/// This is the `const` version of [`SignExtend::sign_extend`].
+				#[must_use]
 				#[inline]
 				#[allow(clippy::as_conversions)]
-				fn sign_extend(self) -> $U {
+				pub const fn sign_extend(value: $T) -> $U {
 					// Casting between signedness is a no-op.
 					// Casting from a smaller to larger signed integer will sign-extend.
-					self
+					value
 						as <$T as Signed>::Signed
 						as <$U as Signed>::Signed
 						as $U
 				}
 			}
 
+			impl SignExtend<$U> for $T
+			{
+				#[inline]
+				fn sign_extend(self) -> $U {
+					Const::<$T, $U>::sign_extend(self)
+				}
+			}
+
 			// TODO: Replace with generic version once specialization is stable
 			impl<'a> SignExtend<$U> for &'a $T
 			where
@@ -61,12 +110,63 @@ macro_rules! impl_sign_extend {
 	};
 }
 
+// Unsigned
+impl_sign_extend! { u8   => u16, u32, u64, u128 }
+impl_sign_extend! { u16  =>      u32, u64, u128 }
+impl_sign_extend! { u32  =>           u64, u128 }
+impl_sign_extend! { u64  =>                u128 }
+
 // Signed
 impl_sign_extend! { i8   => i16, i32, i64, i128 }
 impl_sign_extend! { i16  =>      i32, i64, i128 }
 impl_sign_extend! { i32  =>           i64, i128 }
 impl_sign_extend! { i64  =>                i128 }
 
+// Unsigned to signed
+impl_sign_extend! { u8   => i16, i32, i64, i128 }
+impl_sign_extend! { u16  =>      i32, i64, i128 }
+impl_sign_extend! { u32  =>           i64, i128 }
+impl_sign_extend! { u64  =>                i128 }
+
+// Signed to unsigned
+impl_sign_extend! { i8   => u16, u32, u64, u128 }
+impl_sign_extend! { i16  =>      u32, u64, u128 }
+impl_sign_extend! { i32  =>           u64, u128 }
+impl_sign_extend! { i64  =>                u128 }
+
+// Pointer-sized
+// Note: These only exist where the extension is lossless for the current pointer width.
+impl_sign_extend! { u8    => usize, isize }
+impl_sign_extend! { u16   => usize }
+impl_sign_extend! { usize => u64, u128, i128 }
+impl_sign_extend! { i8    => usize, isize }
+impl_sign_extend! { i16   => isize }
+impl_sign_extend! { isize => u128, i64, i128 }
+#[cfg(any(target_pointer_width = "16", target_pointer_width = "32"))]
+impl_sign_extend! { usize => u32, i64 }
+#[cfg(any(target_pointer_width = "16", target_pointer_width = "32"))]
+impl_sign_extend! { isize => u64, i32 }
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl_sign_extend! { u16   => isize }
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl_sign_extend! { u32   => usize }
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl_sign_extend! { i16   => usize }
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl_sign_extend! { i32   => isize }
+#[cfg(target_pointer_width = "16")]
+impl_sign_extend! { usize => u16, i32 }
+#[cfg(target_pointer_width = "16")]
+impl_sign_extend! { isize => u32, i16 }
+#[cfg(target_pointer_width = "64")]
+impl_sign_extend! { u32   => isize }
+#[cfg(target_pointer_width = "64")]
+impl_sign_extend! { u64   => usize }
+#[cfg(target_pointer_width = "64")]
+impl_sign_extend! { i32   => usize }
+#[cfg(target_pointer_width = "64")]
+impl_sign_extend! { i64   => isize }
+
 /// Helper trait for [`SignExtend`] to be used with turbofish syntax
 pub trait SignExtended {
 	/// Sign extends this type
@@ -86,6 +186,59 @@ static_assertions::assert_impl_all! { i16  :                 SignExtend<i16>, Si
 static_assertions::assert_impl_all! { i32  :                                  SignExtend<i32>, SignExtend<i64>, SignExtend<i128> }
 static_assertions::assert_impl_all! { i64  :                                                   SignExtend<i64>, SignExtend<i128> }
 static_assertions::assert_impl_all! { i128 :                                                                    SignExtend<i128> }
+static_assertions::assert_impl_all! { u8   : SignExtend<u8>, SignExtend<u16>, SignExtend<u32>, SignExtend<u64>, SignExtend<u128> }
+static_assertions::assert_impl_all! { u16  :                 SignExtend<u16>, SignExtend<u32>, SignExtend<u64>, SignExtend<u128> }
+static_assertions::assert_impl_all! { u32  :                                  SignExtend<u32>, SignExtend<u64>, SignExtend<u128> }
+static_assertions::assert_impl_all! { u64  :                                                   SignExtend<u64>, SignExtend<u128> }
+static_assertions::assert_impl_all! { u128 :                                                                    SignExtend<u128> }
+
+// Check that all mixed-signedness `SignExtend` impls exist
+static_assertions::assert_impl_all! { i8   :                 SignExtend<u16>, SignExtend<u32>, SignExtend<u64>, SignExtend<u128> }
+static_assertions::assert_impl_all! { i16  :                                  SignExtend<u32>, SignExtend<u64>, SignExtend<u128> }
+static_assertions::assert_impl_all! { i32  :                                                   SignExtend<u64>, SignExtend<u128> }
+static_assertions::assert_impl_all! { i64  :                                                                    SignExtend<u128> }
+static_assertions::assert_impl_all! { u8   :                 SignExtend<i16>, SignExtend<i32>, SignExtend<i64>, SignExtend<i128> }
+static_assertions::assert_impl_all! { u16  :                                  SignExtend<i32>, SignExtend<i64>, SignExtend<i128> }
+static_assertions::assert_impl_all! { u32  :                                                   SignExtend<i64>, SignExtend<i128> }
+static_assertions::assert_impl_all! { u64  :                                                                    SignExtend<i128> }
+
+// Check that integers of the same size, but different signedness, don't implement `SignExtend`
+static_assertions::assert_not_impl_any! { i8   : SignExtend<u8> }
+static_assertions::assert_not_impl_any! { i16  : SignExtend<u16> }
+static_assertions::assert_not_impl_any! { i32  : SignExtend<u32> }
+static_assertions::assert_not_impl_any! { i64  : SignExtend<u64> }
+static_assertions::assert_not_impl_any! { i128 : SignExtend<u128> }
+static_assertions::assert_not_impl_any! { u8   : SignExtend<i8> }
+static_assertions::assert_not_impl_any! { u16  : SignExtend<i16> }
+static_assertions::assert_not_impl_any! { u32  : SignExtend<i32> }
+static_assertions::assert_not_impl_any! { u64  : SignExtend<i64> }
+static_assertions::assert_not_impl_any! { u128 : SignExtend<i128> }
+
+// Check that all pointer-sized `SignExtend` impls exist for each pointer width
+#[cfg(target_pointer_width = "16")]
+static_assertions::assert_impl_all! { usize : SignExtend<usize>, SignExtend<u16>, SignExtend<u32>, SignExtend<u64>, SignExtend<u128>, SignExtend<i32>, SignExtend<i64>, SignExtend<i128> }
+#[cfg(target_pointer_width = "16")]
+static_assertions::assert_not_impl_any! { usize : SignExtend<u8>, SignExtend<i8>, SignExtend<i16>, SignExtend<isize> }
+#[cfg(target_pointer_width = "16")]
+static_assertions::assert_impl_all! { isize : SignExtend<isize>, SignExtend<u32>, SignExtend<u64>, SignExtend<u128>, SignExtend<i16>, SignExtend<i32>, SignExtend<i64>, SignExtend<i128> }
+#[cfg(target_pointer_width = "16")]
+static_assertions::assert_not_impl_any! { isize : SignExtend<u8>, SignExtend<u16>, SignExtend<usize>, SignExtend<i8> }
+#[cfg(target_pointer_width = "32")]
+static_assertions::assert_impl_all! { usize : SignExtend<usize>, SignExtend<u32>, SignExtend<u64>, SignExtend<u128>, SignExtend<i64>, SignExtend<i128> }
+#[cfg(target_pointer_width = "32")]
+static_assertions::assert_not_impl_any! { usize : SignExtend<u8>, SignExtend<u16>, SignExtend<i8>, SignExtend<i16>, SignExtend<i32>, SignExtend<isize> }
+#[cfg(target_pointer_width = "32")]
+static_assertions::assert_impl_all! { isize : SignExtend<isize>, SignExtend<u64>, SignExtend<u128>, SignExtend<i32>, SignExtend<i64>, SignExtend<i128> }
+#[cfg(target_pointer_width = "32")]
+static_assertions::assert_not_impl_any! { isize : SignExtend<u8>, SignExtend<u16>, SignExtend<u32>, SignExtend<usize>, SignExtend<i8>, SignExtend<i16> }
+#[cfg(target_pointer_width = "64")]
+static_assertions::assert_impl_all! { usize : SignExtend<usize>, SignExtend<u64>, SignExtend<u128>, SignExtend<i128> }
+#[cfg(target_pointer_width = "64")]
+static_assertions::assert_not_impl_any! { usize : SignExtend<u8>, SignExtend<u16>, SignExtend<u32>, SignExtend<i8>, SignExtend<i16>, SignExtend<i32>, SignExtend<i64>, SignExtend<isize> }
+#[cfg(target_pointer_width = "64")]
+static_assertions::assert_impl_all! { isize : SignExtend<isize>, SignExtend<u128>, SignExtend<i64>, SignExtend<i128> }
+#[cfg(target_pointer_width = "64")]
+static_assertions::assert_not_impl_any! { isize : SignExtend<u8>, SignExtend<u16>, SignExtend<u32>, SignExtend<u64>, SignExtend<usize>, SignExtend<i8>, SignExtend<i16>, SignExtend<i32> }
 
 #[cfg(test)]
 mod tests {
@@ -121,4 +274,91 @@ mod tests {
 		assert_eq!(i32::sign_extended::<i128>(-1), -1);
 		assert_eq!(i64::sign_extended::<i128>(-1), -1);
 	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn sign_extend_unsigned_positive() {
+		assert_eq!( u8::sign_extended::< u16>( u8::MAX / 2),  u16::from( u8::MAX / 2));
+		assert_eq!( u8::sign_extended::< u32>( u8::MAX / 2),  u32::from( u8::MAX / 2));
+		assert_eq!( u8::sign_extended::< u64>( u8::MAX / 2),  u64::from( u8::MAX / 2));
+		assert_eq!( u8::sign_extended::<u128>( u8::MAX / 2), u128::from( u8::MAX / 2));
+		assert_eq!(u16::sign_extended::< u32>(u16::MAX / 2),  u32::from(u16::MAX / 2));
+		assert_eq!(u16::sign_extended::< u64>(u16::MAX / 2),  u64::from(u16::MAX / 2));
+		assert_eq!(u16::sign_extended::<u128>(u16::MAX / 2), u128::from(u16::MAX / 2));
+		assert_eq!(u32::sign_extended::< u64>(u32::MAX / 2),  u64::from(u32::MAX / 2));
+		assert_eq!(u32::sign_extended::<u128>(u32::MAX / 2), u128::from(u32::MAX / 2));
+		assert_eq!(u64::sign_extended::<u128>(u64::MAX / 2), u128::from(u64::MAX / 2));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn sign_extend_unsigned_negative() {
+		assert_eq!( u8::sign_extended::< u16>( u8::MAX),  u16::MAX);
+		assert_eq!( u8::sign_extended::< u32>( u8::MAX),  u32::MAX);
+		assert_eq!( u8::sign_extended::< u64>( u8::MAX),  u64::MAX);
+		assert_eq!( u8::sign_extended::<u128>( u8::MAX), u128::MAX);
+		assert_eq!(u16::sign_extended::< u32>(u16::MAX),  u32::MAX);
+		assert_eq!(u16::sign_extended::< u64>(u16::MAX),  u64::MAX);
+		assert_eq!(u16::sign_extended::<u128>(u16::MAX), u128::MAX);
+		assert_eq!(u32::sign_extended::< u64>(u32::MAX),  u64::MAX);
+		assert_eq!(u32::sign_extended::<u128>(u32::MAX), u128::MAX);
+		assert_eq!(u64::sign_extended::<u128>(u64::MAX), u128::MAX);
+
+		assert_eq!( u8::sign_extended::< u16>(0x80), 0xff80);
+		assert_eq!(u16::sign_extended::< u32>(0x8000), 0xffff_8000);
+		assert_eq!(u32::sign_extended::< u64>(0x8000_0000), 0xffff_ffff_8000_0000);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn sign_extend_mixed() {
+		assert_eq!( u8::sign_extended::< i16>( u8::MAX),  -1);
+		assert_eq!( u8::sign_extended::<i128>( u8::MAX),  -1);
+		assert_eq!(u16::sign_extended::< i32>(u16::MAX),  -1);
+		assert_eq!(u32::sign_extended::< i64>(u32::MAX),  -1);
+		assert_eq!(u64::sign_extended::<i128>(u64::MAX),  -1);
+		assert_eq!( u8::sign_extended::< i16>(1), 1);
+		assert_eq!(u64::sign_extended::<i128>(1), 1);
+
+		assert_eq!( i8::sign_extended::< u16>(-1),  u16::MAX);
+		assert_eq!( i8::sign_extended::<u128>(-1), u128::MAX);
+		assert_eq!(i16::sign_extended::< u32>(-1),  u32::MAX);
+		assert_eq!(i32::sign_extended::< u64>(-1),  u64::MAX);
+		assert_eq!(i64::sign_extended::<u128>(-1), u128::MAX);
+		assert_eq!( i8::sign_extended::< u16>(1), 1);
+		assert_eq!(i64::sign_extended::<u128>(1), 1);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	#[cfg(target_pointer_width = "64")]
+	fn sign_extend_pointer_sized() {
+		assert_eq!(i32::sign_extended::<isize>(-1), -1);
+		assert_eq!(u32::sign_extended::<isize>(u32::MAX), -1);
+		assert_eq!(u64::sign_extended::<usize>(u64::MAX), usize::MAX);
+		assert_eq!(usize::sign_extended::<u128>(usize::MAX), u128::MAX);
+		assert_eq!(isize::sign_extended::<i128>(isize::MIN), i128::from(i64::MIN));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	#[cfg(target_pointer_width = "32")]
+	fn sign_extend_pointer_sized() {
+		assert_eq!(i16::sign_extended::<isize>(-1), -1);
+		assert_eq!(u16::sign_extended::<isize>(u16::MAX), -1);
+		assert_eq!(u32::sign_extended::<usize>(u32::MAX), usize::MAX);
+		assert_eq!(usize::sign_extended::<u64>(usize::MAX), u64::MAX);
+		assert_eq!(isize::sign_extended::<i64>(isize::MIN), i64::from(i32::MIN));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	#[cfg(target_pointer_width = "16")]
+	fn sign_extend_pointer_sized() {
+		assert_eq!(i8::sign_extended::<isize>(-1), -1);
+		assert_eq!(u8::sign_extended::<isize>(u8::MAX), -1);
+		assert_eq!(u16::sign_extended::<usize>(u16::MAX), usize::MAX);
+		assert_eq!(usize::sign_extended::<u32>(usize::MAX), u32::MAX);
+		assert_eq!(isize::sign_extended::<i32>(isize::MIN), i32::from(i16::MIN));
+	}
 }
@@ -1,13 +1,17 @@
 //! Zero extension
 
 // Imports
-use crate::Signed;
+use crate::{Const, Signed};
 use core::mem;
 
 /// Zero extend
 ///
 /// This trait serves to extend integers with `0`s,
 /// including signed ones.
+///
+/// Extending into an integer of a different signedness is also supported,
+/// as long as it's larger. Integers of the same size but different signedness
+/// are instead interchanged with [`Signed`].
 pub trait ZeroExtend<T>: Sized {
 	/// Zero extends this type
 	fn zero_extend(self) -> T;
@@ -21,6 +25,37 @@ impl<T> ZeroExtend<T> for T {
 	}
 }
 
+/// Macro to help implement the `const` version of [`ZeroExtend`] to the same type
+///
+/// Note: The trait itself is implemented by the blanket implementation above, so
+///       we only implement it for references here, by copying the underlying type.
+macro_rules! impl_zero_extend_identity {
+	($( $T:ty ),+ $(,)?) => {
+		$(
+			impl Const<$T> {
+				/// Zero extends `value` into the same type, returning it
+				///
+				/// This is the `const` version of [`ZeroExtend::zero_extend`].
+				#[must_use]
+				#[inline]
+				pub const fn zero_extend(value: $T) -> $T {
+					value
+				}
+			}
+
+			impl<'a> ZeroExtend<$T> for &'a $T {
+				#[inline]
+				fn zero_extend(self) -> $T {
+					<$T as ZeroExtend<$T>>::zero_extend(*self)
+				}
+			}
+		)+
+	};
+}
+
+impl_zero_extend_identity! { u8, u16, u32, u64, u128, usize }
+impl_zero_extend_identity! { i8, i16, i32, i64, i128, isize }
+
 /// Macro to help implement [`ZeroExtend`]
 ///
 /// Note: Regardless if `GAT`s are available, a `impl ZeroExtend<&'b U> for &'a T` isn't
@@ -33,20 +68,31 @@ macro_rules! impl_zero_extend {
 			// Note: It is guaranteed that signed and unsigned variants have the same size
 			::static_assertions::const_assert!(mem::size_of::<$U>() >= mem::size_of::<$T>());
 
-			impl ZeroExtend<$U> for $T
-			{
+			impl Const<$T, $U> {
+				/// Zero extends `value`
+				///
+				/// This is the `const` version of [`ZeroExtend::zero_extend`].
+				#[must_use]
 				#[inline]
 				#[allow(clippy::as_conversions)]
-				fn zero_extend(self) -> $U {
+				pub const fn zero_extend(value: $T) -> $U {
 					// Casting between signedness is a no-op.
 					// Casting from a smaller to larger unsigned integer will zero-extend.
-					self
+					value
 						as <$T as Signed>::Unsigned
 						as <$U as Signed>::Unsigned
 						as $U
 				}
 			}
 
+			impl ZeroExtend<$U> for $T
+			{
+				#[inline]
+				fn zero_extend(self) -> $U {
+					Const::<$T, $U>::zero_extend(self)
+				}
+			}
+
 			// TODO: Replace with generic version once specialization is stable
 			impl<'a> ZeroExtend<$U> for &'a $T
 			where
@@ -73,6 +119,51 @@ impl_zero_extend! { i16  =>      i32, i64, i128 }
 impl_zero_extend! { i32  =>           i64, i128 }
 impl_zero_extend! { i64  =>                i128 }
 
+// Unsigned to signed
+impl_zero_extend! { u8   => i16, i32, i64, i128 }
+impl_zero_extend! { u16  =>      i32, i64, i128 }
+impl_zero_extend! { u32  =>           i64, i128 }
+impl_zero_extend! { u64  =>                i128 }
+
+// Signed to unsigned
+impl_zero_extend! { i8   => u16, u32, u64, u128 }
+impl_zero_extend! { i16  =>      u32, u64, u128 }
+impl_zero_extend! { i32  =>           u64, u128 }
+impl_zero_extend! { i64  =>                u128 }
+
+// Pointer-sized
+// Note: These only exist where the extension is lossless for the current pointer width.
+impl_zero_extend! { u8    => usize, isize }
+impl_zero_extend! { u16   => usize }
+impl_zero_extend! { usize => u64, u128, i128 }
+impl_zero_extend! { i8    => usize, isize }
+impl_zero_extend! { i16   => isize }
+impl_zero_extend! { isize => u128, i64, i128 }
+#[cfg(any(target_pointer_width = "16", target_pointer_width = "32"))]
+impl_zero_extend! { usize => u32, i64 }
+#[cfg(any(target_pointer_width = "16", target_pointer_width = "32"))]
+impl_zero_extend! { isize => u64, i32 }
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl_zero_extend! { u16   => isize }
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl_zero_extend! { u32   => usize }
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl_zero_extend! { i16   => usize }
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl_zero_extend! { i32   => isize }
+#[cfg(target_pointer_width = "16")]
+impl_zero_extend! { usize => u16, i32 }
+#[cfg(target_pointer_width = "16")]
+impl_zero_extend! { isize => u32, i16 }
+#[cfg(target_pointer_width = "64")]
+impl_zero_extend! { u32   => isize }
+#[cfg(target_pointer_width = "64")]
+impl_zero_extend! { u64   => usize }
+#[cfg(target_pointer_width = "64")]
+impl_zero_extend! { i32   => usize }
+#[cfg(target_pointer_width = "64")]
+impl_zero_extend! { i64   => isize }
+
 /// Helper trait for [`ZeroExtend`] to be used with turbofish syntax
 pub trait ZeroExtended: Sized {
 	/// Zero extends this type
@@ -98,6 +189,54 @@ static_assertions::assert_impl_all! { u32  :                                  Ze
 static_assertions::assert_impl_all! { u64  :                                                   ZeroExtend<u64>, ZeroExtend<u128> }
 static_assertions::assert_impl_all! { u128 :                                                                    ZeroExtend<u128> }
 
+// Check that all mixed-signedness `ZeroExtend` impls exist
+static_assertions::assert_impl_all! { i8   :                 ZeroExtend<u16>, ZeroExtend<u32>, ZeroExtend<u64>, ZeroExtend<u128> }
+static_assertions::assert_impl_all! { i16  :                                  ZeroExtend<u32>, ZeroExtend<u64>, ZeroExtend<u128> }
+static_assertions::assert_impl_all! { i32  :                                                   ZeroExtend<u64>, ZeroExtend<u128> }
+static_assertions::assert_impl_all! { i64  :                                                                    ZeroExtend<u128> }
+static_assertions::assert_impl_all! { u8   :                 ZeroExtend<i16>, ZeroExtend<i32>, ZeroExtend<i64>, ZeroExtend<i128> }
+static_assertions::assert_impl_all! { u16  :                                  ZeroExtend<i32>, ZeroExtend<i64>, ZeroExtend<i128> }
+static_assertions::assert_impl_all! { u32  :                                                   ZeroExtend<i64>, ZeroExtend<i128> }
+static_assertions::assert_impl_all! { u64  :                                                                    ZeroExtend<i128> }
+
+// Check that integers of the same size, but different signedness, don't implement `ZeroExtend`
+static_assertions::assert_not_impl_any! { i8   : ZeroExtend<u8> }
+static_assertions::assert_not_impl_any! { i16  : ZeroExtend<u16> }
+static_assertions::assert_not_impl_any! { i32  : ZeroExtend<u32> }
+static_assertions::assert_not_impl_any! { i64  : ZeroExtend<u64> }
+static_assertions::assert_not_impl_any! { i128 : ZeroExtend<u128> }
+static_assertions::assert_not_impl_any! { u8   : ZeroExtend<i8> }
+static_assertions::assert_not_impl_any! { u16  : ZeroExtend<i16> }
+static_assertions::assert_not_impl_any! { u32  : ZeroExtend<i32> }
+static_assertions::assert_not_impl_any! { u64  : ZeroExtend<i64> }
+static_assertions::assert_not_impl_any! { u128 : ZeroExtend<i128> }
+
+// Check that all pointer-sized `ZeroExtend` impls exist for each pointer width
+#[cfg(target_pointer_width = "16")]
+static_assertions::assert_impl_all! { usize : ZeroExtend<usize>, ZeroExtend<u16>, ZeroExtend<u32>, ZeroExtend<u64>, ZeroExtend<u128>, ZeroExtend<i32>, ZeroExtend<i64>, ZeroExtend<i128> }
+#[cfg(target_pointer_width = "16")]
+static_assertions::assert_not_impl_any! { usize : ZeroExtend<u8>, ZeroExtend<i8>, ZeroExtend<i16>, ZeroExtend<isize> }
+#[cfg(target_pointer_width = "16")]
+static_assertions::assert_impl_all! { isize : ZeroExtend<isize>, ZeroExtend<u32>, ZeroExtend<u64>, ZeroExtend<u128>, ZeroExtend<i16>, ZeroExtend<i32>, ZeroExtend<i64>, ZeroExtend<i128> }
+#[cfg(target_pointer_width = "16")]
+static_assertions::assert_not_impl_any! { isize : ZeroExtend<u8>, ZeroExtend<u16>, ZeroExtend<usize>, ZeroExtend<i8> }
+#[cfg(target_pointer_width = "32")]
+static_assertions::assert_impl_all! { usize : ZeroExtend<usize>, ZeroExtend<u32>, ZeroExtend<u64>, ZeroExtend<u128>, ZeroExtend<i64>, ZeroExtend<i128> }
+#[cfg(target_pointer_width = "32")]
+static_assertions::assert_not_impl_any! { usize : ZeroExtend<u8>, ZeroExtend<u16>, ZeroExtend<i8>, ZeroExtend<i16>, ZeroExtend<i32>, ZeroExtend<isize> }
+#[cfg(target_pointer_width = "32")]
+static_assertions::assert_impl_all! { isize : ZeroExtend<isize>, ZeroExtend<u64>, ZeroExtend<u128>, ZeroExtend<i32>, ZeroExtend<i64>, ZeroExtend<i128> }
+#[cfg(target_pointer_width = "32")]
+static_assertions::assert_not_impl_any! { isize : ZeroExtend<u8>, ZeroExtend<u16>, ZeroExtend<u32>, ZeroExtend<usize>, ZeroExtend<i8>, ZeroExtend<i16> }
+#[cfg(target_pointer_width = "64")]
+static_assertions::assert_impl_all! { usize : ZeroExtend<usize>, ZeroExtend<u64>, ZeroExtend<u128>, ZeroExtend<i128> }
+#[cfg(target_pointer_width = "64")]
+static_assertions::assert_not_impl_any! { usize : ZeroExtend<u8>, ZeroExtend<u16>, ZeroExtend<u32>, ZeroExtend<i8>, ZeroExtend<i16>, ZeroExtend<i32>, ZeroExtend<i64>, ZeroExtend<isize> }
+#[cfg(target_pointer_width = "64")]
+static_assertions::assert_impl_all! { isize : ZeroExtend<isize>, ZeroExtend<u128>, ZeroExtend<i64>, ZeroExtend<i128> }
+#[cfg(target_pointer_width = "64")]
+static_assertions::assert_not_impl_any! { isize : ZeroExtend<u8>, ZeroExtend<u16>, ZeroExtend<u32>, ZeroExtend<u64>, ZeroExtend<usize>, ZeroExtend<i8>, ZeroExtend<i16>, ZeroExtend<i32> }
+
 #[cfg(test)]
 mod tests {
 	// Imports
@@ -185,4 +324,56 @@ mod tests {
 		assert_eq!( i64::zero_extended::< i64>(-1), -1);
 		assert_eq!(i128::zero_extended::<i128>(-1), -1);
 	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn zero_extend_mixed() {
+		assert_eq!( u8::zero_extended::< i16>( u8::MAX),  i16::from( u8::MAX));
+		assert_eq!( u8::zero_extended::<i128>( u8::MAX), i128::from( u8::MAX));
+		assert_eq!(u16::zero_extended::< i32>(u16::MAX),  i32::from(u16::MAX));
+		assert_eq!(u32::zero_extended::< i64>(u32::MAX),  i64::from(u32::MAX));
+		assert_eq!(u64::zero_extended::<i128>(u64::MAX), i128::from(u64::MAX));
+
+		assert_eq!( i8::zero_extended::< u16>(-1),  u16::from( u8::MAX));
+		assert_eq!( i8::zero_extended::<u128>(-1), u128::from( u8::MAX));
+		assert_eq!(i16::zero_extended::< u32>(-1),  u32::from(u16::MAX));
+		assert_eq!(i32::zero_extended::< u64>(-1),  u64::from(u32::MAX));
+		assert_eq!(i64::zero_extended::<u128>(-1), u128::from(u64::MAX));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	#[cfg(target_pointer_width = "64")]
+	fn zero_extend_pointer_sized() {
+		assert_eq!(u32::zero_extended::<usize>(u32::MAX), usize::MAX >> 32);
+		assert_eq!(u64::zero_extended::<usize>(u64::MAX), usize::MAX);
+		assert_eq!(i32::zero_extended::<usize>(-1), usize::MAX >> 32);
+		assert_eq!(i32::zero_extended::<isize>(-1), isize::MAX >> 31);
+		assert_eq!(usize::zero_extended::<u128>(usize::MAX), u128::from(u64::MAX));
+		assert_eq!(isize::zero_extended::<i128>(-1), i128::from(u64::MAX));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	#[cfg(target_pointer_width = "32")]
+	fn zero_extend_pointer_sized() {
+		assert_eq!(u16::zero_extended::<usize>(u16::MAX), usize::MAX >> 16);
+		assert_eq!(u32::zero_extended::<usize>(u32::MAX), usize::MAX);
+		assert_eq!(i16::zero_extended::<usize>(-1), usize::MAX >> 16);
+		assert_eq!(i16::zero_extended::<isize>(-1), isize::MAX >> 15);
+		assert_eq!(usize::zero_extended::<u64>(usize::MAX), u64::from(u32::MAX));
+		assert_eq!(isize::zero_extended::<i64>(-1), i64::from(u32::MAX));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	#[cfg(target_pointer_width = "16")]
+	fn zero_extend_pointer_sized() {
+		assert_eq!(u8::zero_extended::<usize>(u8::MAX), usize::MAX >> 8);
+		assert_eq!(u16::zero_extended::<usize>(u16::MAX), usize::MAX);
+		assert_eq!(i8::zero_extended::<usize>(-1), usize::MAX >> 8);
+		assert_eq!(i8::zero_extended::<isize>(-1), isize::MAX >> 7);
+		assert_eq!(usize::zero_extended::<u32>(usize::MAX), u32::from(u16::MAX));
+		assert_eq!(isize::zero_extended::<i32>(-1), i32::from(u16::MAX));
+	}
 }
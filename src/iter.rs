@@ -0,0 +1,302 @@
+//! Iterator adapters
+//!
+//! This module provides [`ConvertIter`], which adapts iterators of integers to
+//! convert each of them, or to split and join them.
+//!
+//! Note: The adapters are suffixed with `_each` instead of sharing the names of the turbofish
+//!       helpers, such as [`ZeroExtended::zero_extended`](crate::ZeroExtended::zero_extended), as
+//!       those are implemented for all types, including iterators, so calls would be ambiguous.
+
+// Imports
+use crate::{Extend, Join, SaturatingTruncate, SignExtend, Split, Truncate, ZeroExtend};
+use core::{fmt, iter::FusedIterator, marker::PhantomData};
+
+/// Iterator adapters for conversions
+pub trait ConvertIter: Iterator + Sized {
+	/// Zero extends each item into `T`
+	#[inline]
+	fn zero_extend_each<T>(self) -> ZeroExtendEach<Self, T>
+	where
+		Self::Item: ZeroExtend<T>,
+	{
+		ZeroExtendEach {
+			iter: self,
+			phantom: PhantomData,
+		}
+	}
+
+	/// Sign extends each item into `T`
+	#[inline]
+	fn sign_extend_each<T>(self) -> SignExtendEach<Self, T>
+	where
+		Self::Item: SignExtend<T>,
+	{
+		SignExtendEach {
+			iter: self,
+			phantom: PhantomData,
+		}
+	}
+
+	/// Extends each item into `T`
+	#[inline]
+	fn extend_each<T>(self) -> ExtendEach<Self, T>
+	where
+		Self::Item: Extend<T>,
+	{
+		ExtendEach {
+			iter: self,
+			phantom: PhantomData,
+		}
+	}
+
+	/// Truncates each item into `T`
+	#[inline]
+	fn truncate_each<T>(self) -> TruncateEach<Self, T>
+	where
+		Self::Item: Truncate<T>,
+	{
+		TruncateEach {
+			iter: self,
+			phantom: PhantomData,
+		}
+	}
+
+	/// Truncates each item into `T`, clamping it to the range of `T`
+	#[inline]
+	fn saturating_truncate_each<T>(self) -> SaturatingTruncateEach<Self, T>
+	where
+		Self::Item: SaturatingTruncate<T>,
+	{
+		SaturatingTruncateEach {
+			iter: self,
+			phantom: PhantomData,
+		}
+	}
+
+	/// Splits each item into it's low and high part, yielding the low part first
+	///
+	/// Both parts must have the same type, so this is only available for unsigned integers.
+	#[inline]
+	fn split_lo_hi(self) -> SplitLoHi<Self>
+	where
+		Self::Item: Split<Hi = <Self::Item as Split>::Lo>,
+	{
+		SplitLoHi { iter: self, hi: None }
+	}
+
+	/// Joins each pair of consecutive items into `T`, with the low part first
+	///
+	/// This is the inverse of [`ConvertIter::split_lo_hi`]. If there's an odd
+	/// number of items, the last one is discarded.
+	#[inline]
+	fn join_pairs<T>(self) -> JoinPairs<Self, T>
+	where
+		T: Join<Lo = Self::Item, Hi = Self::Item>,
+	{
+		JoinPairs {
+			iter: self,
+			phantom: PhantomData,
+		}
+	}
+}
+
+impl<I: Iterator> ConvertIter for I {}
+
+/// Macro to help define the adapters that convert each item
+macro_rules! impl_convert_each {
+	($( $Adapter:ident => $Trait:ident :: $method:ident : $name:literal ),+ $(,)?) => {
+		$(
+			#[doc = concat!("Iterator returned by [`ConvertIter::", $name, "`]")]
+			#[derive(Clone, Debug)]
+			#[must_use = "iterators are lazy and do nothing unless consumed"]
+			pub struct $Adapter<I, T> {
+				/// Inner iterator
+				iter: I,
+
+				/// Type we convert into
+				phantom: PhantomData<fn() -> T>,
+			}
+
+			impl<I: Iterator, T> Iterator for $Adapter<I, T>
+			where
+				I::Item: $Trait<T>,
+			{
+				type Item = T;
+
+				#[inline]
+				fn next(&mut self) -> Option<T> {
+					self.iter.next().map($Trait::$method)
+				}
+
+				#[inline]
+				fn size_hint(&self) -> (usize, Option<usize>) {
+					self.iter.size_hint()
+				}
+			}
+
+			impl<I: DoubleEndedIterator, T> DoubleEndedIterator for $Adapter<I, T>
+			where
+				I::Item: $Trait<T>,
+			{
+				#[inline]
+				fn next_back(&mut self) -> Option<T> {
+					self.iter.next_back().map($Trait::$method)
+				}
+			}
+
+			impl<I: ExactSizeIterator, T> ExactSizeIterator for $Adapter<I, T> where I::Item: $Trait<T> {}
+
+			impl<I: FusedIterator, T> FusedIterator for $Adapter<I, T> where I::Item: $Trait<T> {}
+		)+
+	};
+}
+
+impl_convert_each! {
+	ZeroExtendEach         => ZeroExtend::zero_extend                 : "zero_extend_each",
+	SignExtendEach         => SignExtend::sign_extend                 : "sign_extend_each",
+	ExtendEach             => Extend::extend                          : "extend_each",
+	TruncateEach           => Truncate::truncate                      : "truncate_each",
+	SaturatingTruncateEach => SaturatingTruncate::saturating_truncate : "saturating_truncate_each",
+}
+
+/// Iterator returned by [`ConvertIter::split_lo_hi`]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SplitLoHi<I: Iterator>
+where
+	I::Item: Split,
+{
+	/// Inner iterator
+	iter: I,
+
+	/// High part of the last item, yet to be returned
+	hi: Option<<I::Item as Split>::Hi>,
+}
+
+// Note: We can't derive these, as they'd only require `I` to implement them, but not `Hi`
+impl<I: Iterator + Clone> Clone for SplitLoHi<I>
+where
+	I::Item: Split,
+	<I::Item as Split>::Hi: Clone,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		Self {
+			iter: self.iter.clone(),
+			hi: self.hi.clone(),
+		}
+	}
+}
+
+impl<I: Iterator + fmt::Debug> fmt::Debug for SplitLoHi<I>
+where
+	I::Item: Split,
+	<I::Item as Split>::Hi: fmt::Debug,
+{
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("SplitLoHi").field("iter", &self.iter).field("hi", &self.hi).finish()
+	}
+}
+
+impl<I: Iterator> Iterator for SplitLoHi<I>
+where
+	I::Item: Split<Hi = <I::Item as Split>::Lo>,
+{
+	type Item = <I::Item as Split>::Lo;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if let Some(hi) = self.hi.take() {
+			return Some(hi);
+		}
+
+		let (lo, hi) = self.iter.next()?.lo_hi();
+		self.hi = Some(hi);
+		Some(lo)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let (min, max) = self.iter.size_hint();
+		let pending = usize::from(self.hi.is_some());
+		let min = min.saturating_mul(2).saturating_add(pending);
+		let max = max.and_then(|max| max.checked_mul(2)?.checked_add(pending));
+		(min, max)
+	}
+}
+
+impl<I: FusedIterator> FusedIterator for SplitLoHi<I> where I::Item: Split<Hi = <I::Item as Split>::Lo> {}
+
+/// Iterator returned by [`ConvertIter::join_pairs`]
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct JoinPairs<I, T> {
+	/// Inner iterator
+	iter: I,
+
+	/// Type we join into
+	phantom: PhantomData<fn() -> T>,
+}
+
+impl<I: Iterator, T> Iterator for JoinPairs<I, T>
+where
+	T: Join<Lo = I::Item, Hi = I::Item>,
+{
+	type Item = T;
+
+	#[inline]
+	fn next(&mut self) -> Option<T> {
+		let lo = self.iter.next()?;
+		let hi = self.iter.next()?;
+		Some(T::join(lo, hi))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let (min, max) = self.iter.size_hint();
+		(min / 2, max.map(|max| max / 2))
+	}
+}
+
+impl<I: FusedIterator, T> FusedIterator for JoinPairs<I, T> where T: Join<Lo = I::Item, Hi = I::Item> {}
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use super::*;
+	use crate::u24;
+
+	#[test]
+	#[rustfmt::skip]
+	fn convert_each() {
+		assert!([0x80u8, 0xff].iter().copied().zero_extend_each::<u32>().eq([0x80, 0xff]));
+		assert!([0x80u8, 0xff].iter().copied().sign_extend_each::<u32>().eq([0xffff_ff80, 0xffff_ffff]));
+		assert!([-1i8, 1].iter().extend_each::<i64>().eq([-1, 1]));
+		assert!([0x1234u16, 0xff].iter().copied().truncate_each::<u8>().eq([0x34, 0xff]));
+		assert!([0x1234u16, 0xff].iter().copied().saturating_truncate_each::<u8>().eq([0xff, 0xff]));
+		assert!(core::iter::once(0x12_3456u32).truncate_each::<u24>().eq([u24::new(0x12_3456).unwrap()]));
+		assert!([1u8, 2, 3].iter().copied().zero_extend_each::<u16>().rev().eq([3, 2, 1]));
+		assert_eq!([1u8, 2, 3].iter().copied().extend_each::<u16>().len(), 3);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn split_lo_hi() {
+		assert!([0x1234u16, 0x5678].iter().copied().split_lo_hi().eq([0x34, 0x12, 0x78, 0x56]));
+		assert!(core::iter::once(0xdead_beefu32).split_lo_hi().eq([0xbeef, 0xdead]));
+		assert_eq!([0x1234u16, 0x5678].iter().copied().split_lo_hi().size_hint(), (4, Some(4)));
+
+		let mut iter = [0x1234u16, 0x5678].iter().copied().split_lo_hi();
+		assert_eq!(iter.next(), Some(0x34));
+		assert_eq!(iter.size_hint(), (3, Some(3)));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn join_pairs() {
+		assert!([0x34u8, 0x12, 0x78, 0x56].iter().copied().join_pairs::<u16>().eq([0x1234, 0x5678]));
+		assert!([0x34u8, 0x12, 0x78].iter().copied().join_pairs::<u16>().eq([0x1234]));
+		assert_eq!([0x34u8, 0x12, 0x78].iter().copied().join_pairs::<u16>().size_hint(), (1, Some(1)));
+		assert!([0xdead_beefu32, 1].iter().copied().split_lo_hi().join_pairs::<u32>().eq([0xdead_beef, 1]));
+	}
+}
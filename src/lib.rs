@@ -5,14 +5,35 @@
 //! # Features
 //!
 //! - [`ZeroExtend`] / [`SignExtend`] / [`Extend`]: Extend from a smaller to larger integer.
+//! - [`ExtendBits`]: Extend from an arbitrary bit width.
 //! - [`Signed`] / [`IsSigned`] / [`IsUnsigned`]: Interchange between signed and unsigned types.
+//! - [`SignedExt`]: Inspect the [`Sign`] of signed and unsigned types, convert between them preserving their value, create them from a sign and magnitude, and zigzag encode them.
+//! - [`Raw`]: Represent integers by their raw bits, implementing [`SignedExt`] for any [`Signed`] type.
+//! - [`ReinterpretSign`]: Interchange between signed and unsigned types behind references, slices and [`Cell`](core::cell::Cell)s.
 //! - [`Truncate`]: Truncate integers.
+//! - [`TryTruncate`]: Truncate integers, checking if the value fits.
+//! - [`SaturatingTruncate`]: Truncate integers, clamping them to the smaller integer's range.
+//! - [`TruncateBits`]: Truncate integers to an arbitrary bit width.
 //! - [`Split`] / [`Join`]: Split integers in half and joins them back together.
+//! - [`SplitInto`] / [`JoinFrom`]: Split integers into arrays of smaller integers and joins them back together.
+//! - [`SplitUnits`] / [`SplitEndian`]: Split integers into smaller integers in little or big endian order and joins them back together.
+//! - [`u24`] / [`i24`] / [`u48`] / ...: Integers with widths that aren't a power of two.
+//! - [`UInt`] / [`Int`]: Integers of any width up to 128 bits, chosen with a const generic.
+//! - [`Const`]: `const` versions of the conversions between primitive, odd-width and arbitrary-width integers, to be used in `const` items.
+//! - [`OnesComplement`] / [`SignMagnitude`]: Convert signed integers to and from other representations.
+//! - [`Leb128`] / [`QuicVarint`] / [`SqliteVarint`]: Encode and decode integers with variable-length encodings.
+//! - [`NonZero`](core::num::NonZero): Extend, truncate and interchange the signedness of non-zero integers, keeping them non-zero.
+//! - [`Wrapping`](core::num::Wrapping) / `Saturating`: Convert wrapped integers, keeping the wrapper. `Saturating` requires the `saturating` feature.
+//! - [`ConvertSlice`]: Convert slices of integers into other slices.
+//! - [`ConvertIter`]: Convert, split and join the integers of iterators.
+//! - [`lift`]: Extend and truncate arrays, tuples and [`Option`]s of integers, and interchange the signedness of arrays, tuples and [`Option`]s of them.
 //!
 //! Various helpers are also provided to be used with the turbofish syntax (`::<>`).
 
 // Features
 #![no_std]
+// `Narrower` is implemented recursively for each width
+#![recursion_limit = "256"]
 // Lints
 #![warn(clippy::restriction, clippy::pedantic, clippy::nursery)]
 // We turn off warnings we don't need
@@ -33,13 +54,34 @@
 #![cfg_attr(test, allow(clippy::cognitive_complexity))]
 
 // Modules
+pub mod arbitrary;
+pub mod consts;
 pub mod extend;
+pub mod iter;
+pub mod lift;
+pub mod nonzero;
+pub mod odd;
+mod raw;
+pub mod reinterpret;
+pub mod repr;
 pub mod sign;
+pub mod slice;
 pub mod split;
 pub mod trunc;
+pub mod varint;
+pub mod wrapper;
 
 // Exports
-pub use extend::{Extend, Extended, SignExtend, SignExtended, ZeroExtend, ZeroExtended};
-pub use sign::{IsSigned, IsUnsigned, Signed};
-pub use split::{Join, Split};
-pub use trunc::{Truncate, Truncated};
+pub use arbitrary::{Backing, Bits, Int, Narrower, UInt};
+pub use consts::Const;
+pub use extend::{Extend, ExtendBits, Extended, SignExtend, SignExtended, ZeroExtend, ZeroExtended};
+pub use iter::ConvertIter;
+pub use odd::{i24, i40, i48, i56, u24, u40, u48, u56};
+pub use raw::Raw;
+pub use reinterpret::ReinterpretSign;
+pub use repr::{OnesComplement, OnesComplementAdd, ReprError, SignMagnitude};
+pub use sign::{IsSigned, IsUnsigned, Sign, SignError, Signed, SignedExt};
+pub use slice::{ConvertSlice, LengthError};
+pub use split::{Join, JoinFrom, Split, SplitEndian, SplitInto, SplitUnits};
+pub use trunc::{SaturatingTruncate, SaturatingTruncated, Truncate, TruncateBits, TruncateError, Truncated, TryTruncate, TryTruncated};
+pub use varint::{Leb128, QuicVarint, SqliteVarint, VarintError};
@@ -0,0 +1,448 @@
+//! Lifted conversions
+//!
+//! This module implements [`ZeroExtend`] / [`SignExtend`] / [`Extend`] / [`Truncate`]
+//! for arrays, tuples of up to 12 elements and [`Option`]s of integers, converting each of them, e.g.
+//! `[u8; 4]` extends into `[u16; 4]`, `(u8, i8)` extends into `(u32, i32)` and `Option<u64>`
+//! truncates into `Option<u32>`. Arrays and [`Option`]s of arrays and [`Option`]s of integers,
+//! such as `[[u8; 2]; 2]` and `Option<[u8; 2]>`, are converted too.
+//!
+//! It also implements [`Signed`] for arrays, tuples of up to 12 elements and [`Option`]s
+//! of [`Signed`] types, e.g. `(u8, i16)` has `(i8, i16)` as it's signed variant.
+//!
+//! Note: These traits are implemented for each type into itself by their blanket implementations,
+//!       which implementations generic over the integers would overlap with, so they're instead
+//!       implemented for each integer, into any [`Distinct`] integer. Tuples are implemented for
+//!       any elements, but bound to be [`Distinct`] from the tuple they convert into, which they only
+//!       are when the first element that differs between them is an integer, e.g. `(u8, [u8; 2])`
+//!       extends into `(u16, [u16; 2])`, but `([u8; 2], u8)` doesn't extend into `([u16; 2], u16)`.
+//!
+//! Note: [`SignedExt`](crate::SignedExt) isn't lifted, as most of it's methods, such as
+//!       [`SignedExt::sign`](crate::SignedExt::sign) and [`SignedExt::try_from_sign_magnitude`](crate::SignedExt::try_from_sign_magnitude),
+//!       take or return a single sign, which there's no single value for when there are multiple integers.
+
+// Imports
+use crate::{
+	i24, i40, i48, i56, u24, u40, u48, u56, Backing, Bits, Extend, Int, IsSigned, IsUnsigned, Narrower, SignExtend, Signed, Truncate, UInt,
+	ZeroExtend,
+};
+
+/// Integers distinct from `T`
+///
+/// This trait bounds the lifted conversions, so they don't overlap with the blanket identity ones.
+///
+/// Arbitrary-width integers of the same signedness only implement it for wider widths, as those
+/// are the only ones they extend into, and the only ones narrower widths are truncated from.
+///
+/// Tuples implement it for tuples whose first differing element is a distinct integer.
+pub trait Distinct<T> {}
+
+/// Macro to help implement [`Distinct`] between each pair of integers
+macro_rules! impl_distinct {
+	($T:ty $(, $U:ty )* $(,)?) => {
+		$(
+			impl Distinct<$U> for $T {}
+			impl Distinct<$T> for $U {}
+		)*
+
+		impl<const N: u32> Distinct<UInt<N>> for $T where Bits<N>: Backing {}
+		impl<const N: u32> Distinct<Int<N>> for $T where Bits<N>: Backing {}
+		impl<const N: u32> Distinct<$T> for UInt<N> where Bits<N>: Backing {}
+		impl<const N: u32> Distinct<$T> for Int<N> where Bits<N>: Backing {}
+
+		impl_distinct! { $( $U ),* }
+	};
+
+	() => {};
+}
+
+impl_distinct! {
+	u8, u16, u32, u64, u128, usize,
+	i8, i16, i32, i64, i128, isize,
+	u24, u40, u48, u56,
+	i24, i40, i48, i56,
+}
+
+impl<const N: u32, const M: u32> Distinct<Int<M>> for UInt<N>
+where
+	Bits<N>: Backing,
+	Bits<M>: Backing,
+{
+}
+
+impl<const N: u32, const M: u32> Distinct<UInt<M>> for Int<N>
+where
+	Bits<N>: Backing,
+	Bits<M>: Backing,
+{
+}
+
+impl<const N: u32, const M: u32> Distinct<UInt<M>> for UInt<N>
+where
+	Bits<N>: Backing + Narrower<Bits<M>>,
+	Bits<M>: Backing,
+{
+}
+
+impl<const N: u32, const M: u32> Distinct<Int<M>> for Int<N>
+where
+	Bits<N>: Backing + Narrower<Bits<M>>,
+	Bits<M>: Backing,
+{
+}
+
+/// Macro to help implement [`Distinct`] for tuples whose first differing element is an integer
+///
+/// Each tuple gets an implementation for each position of the integer, with the elements before it
+/// the same in both tuples and the elements after it any type. As the integer is distinct from the
+/// element in the other tuple, these don't overlap with each other, and no tuple is distinct from itself.
+macro_rules! impl_distinct_tuple {
+	(@impl [$( $N:ident )?] $T:ty ; [$( $P:ident ),*] [$( $S:ident $D:ident ),*]) => {
+		impl<U, $( $P, )* $( $S, $D, )* $( const $N: u32 )?> Distinct<($( $P, )* U, $( $D, )*)> for ($( $P, )* $T, $( $S, )*)
+		where
+			$( Bits<$N>: Backing, )?
+			$T: Distinct<U>,
+		{
+		}
+	};
+
+	(@positions $leaf:tt [$( $P:ident ),*] [$S:ident $D:ident $(, $Rest:ident $RestD:ident )*]) => {
+		impl_distinct_tuple! { @leaves $leaf [$( $P ),*] [$( $Rest $RestD ),*] }
+		impl_distinct_tuple! { @positions $leaf [$( $P, )* $S] [$( $Rest $RestD ),*] }
+	};
+
+	(@positions $leaf:tt [$( $P:ident ),*] []) => {};
+
+	(@leaves [$( [$( $N:ident )?] $T:ty ),+] $pre:tt $post:tt) => {
+		$( impl_distinct_tuple! { @impl [$( $N )?] $T ; $pre $post } )+
+	};
+
+	(@tuples $leaf:tt $S:ident $D:ident $(, $Rest:ident $RestD:ident )*) => {
+		impl_distinct_tuple! { @positions $leaf [] [$S $D $(, $Rest $RestD )*] }
+		impl_distinct_tuple! { @tuples $leaf $( $Rest $RestD ),* }
+	};
+
+	(@tuples $leaf:tt) => {};
+
+	($( [$( $N:ident )?] $T:ty ),+ $(,)?) => {
+		impl_distinct_tuple! { @tuples [$( [$( $N )?] $T ),+] A UA, B UB, C UC, D UD, E UE, F UF, G UG, H UH, I UI, J UJ, K UK, L UL }
+	};
+}
+
+impl_distinct_tuple! {
+	[] u8, [] u16, [] u32, [] u64, [] u128, [] usize,
+	[] i8, [] i16, [] i32, [] i64, [] i128, [] isize,
+	[] u24, [] u40, [] u48, [] u56,
+	[] i24, [] i40, [] i48, [] i56,
+	[N] UInt<N>, [N] Int<N>,
+}
+
+/// Macro to help implement the lifted traits for arrays and [`Option`]s of an integer
+///
+/// Arrays and [`Option`]s are also lifted once more, e.g. `[[u8; 2]; 2]` and `Option<[u8; 2]>`.
+macro_rules! impl_lift {
+	(@impl [$( $N:ident )?] $T:ty : $Trait:ident :: $method:ident where $( $Bound:tt )+) => {
+		impl<U, const L: usize $(, const $N: u32 )?> $Trait<[U; L]> for [$T; L]
+		where
+			$( Bits<$N>: Backing, )?
+			$( $Bound )+
+		{
+			#[inline]
+			fn $method(self) -> [U; L] {
+				self.map(<$T as $Trait<U>>::$method)
+			}
+		}
+
+		impl<U $(, const $N: u32 )?> $Trait<Option<U>> for Option<$T>
+		where
+			$( Bits<$N>: Backing, )?
+			$( $Bound )+
+		{
+			#[inline]
+			fn $method(self) -> Option<U> {
+				self.map(<$T as $Trait<U>>::$method)
+			}
+		}
+
+		impl<U, const L: usize, const L2: usize $(, const $N: u32 )?> $Trait<[[U; L2]; L]> for [[$T; L2]; L]
+		where
+			$( Bits<$N>: Backing, )?
+			$( $Bound )+
+		{
+			#[inline]
+			fn $method(self) -> [[U; L2]; L] {
+				self.map(<[$T; L2] as $Trait<[U; L2]>>::$method)
+			}
+		}
+
+		impl<U, const L: usize $(, const $N: u32 )?> $Trait<[Option<U>; L]> for [Option<$T>; L]
+		where
+			$( Bits<$N>: Backing, )?
+			$( $Bound )+
+		{
+			#[inline]
+			fn $method(self) -> [Option<U>; L] {
+				self.map(<Option<$T> as $Trait<Option<U>>>::$method)
+			}
+		}
+
+		impl<U, const L: usize $(, const $N: u32 )?> $Trait<Option<[U; L]>> for Option<[$T; L]>
+		where
+			$( Bits<$N>: Backing, )?
+			$( $Bound )+
+		{
+			#[inline]
+			fn $method(self) -> Option<[U; L]> {
+				self.map(<[$T; L] as $Trait<[U; L]>>::$method)
+			}
+		}
+
+		impl<U $(, const $N: u32 )?> $Trait<Option<Option<U>>> for Option<Option<$T>>
+		where
+			$( Bits<$N>: Backing, )?
+			$( $Bound )+
+		{
+			#[inline]
+			fn $method(self) -> Option<Option<U>> {
+				self.map(<Option<$T> as $Trait<Option<U>>>::$method)
+			}
+		}
+	};
+
+	(@impl [$( $N:ident )?] $T:ty) => {
+		impl_lift! { @impl [$( $N )?] $T : ZeroExtend::zero_extend where $T: ZeroExtend<U> + Distinct<U> }
+		impl_lift! { @impl [$( $N )?] $T : SignExtend::sign_extend where $T: SignExtend<U> + Distinct<U> }
+		impl_lift! { @impl [$( $N )?] $T : Extend::extend where $T: Extend<U> + Distinct<U> }
+		impl_lift! { @impl [$( $N )?] $T : Truncate::truncate where $T: Truncate<U>, U: Distinct<$T> }
+	};
+
+	(impl<$N:ident> $( $T:ty ),+ $(,)?) => {
+		$( impl_lift! { @impl [$N] $T } )+
+	};
+
+	($( $T:ty ),+ $(,)?) => {
+		$( impl_lift! { @impl [] $T } )+
+	};
+}
+
+// Primitives
+impl_lift! { u8, u16, u32, u64, u128, usize }
+impl_lift! { i8, i16, i32, i64, i128, isize }
+
+// Odd-width
+impl_lift! { u24, u40, u48, u56 }
+impl_lift! { i24, i40, i48, i56 }
+
+// Arbitrary-width
+impl_lift! { impl<N> UInt<N>, Int<N> }
+
+/// Macro to help implement the lifted traits for tuples of each length up to the given one
+///
+/// Note: The tuples are bound to be [`Distinct`] from the ones they convert into,
+///       so these don't overlap with the blanket implementations.
+macro_rules! impl_lift_tuple {
+	(@impl $Trait:ident :: $method:ident : $( $T:ident $U:ident $value:ident ),+ ; $( $Bound:tt )+) => {
+		impl<$( $T, $U ),+> $Trait<($( $U, )+)> for ($( $T, )+)
+		where
+			$( $T: $Trait<$U>, )+
+			$( $Bound )+
+		{
+			#[inline]
+			fn $method(self) -> ($( $U, )+) {
+				let ($( $value, )+) = self;
+				($( <$T as $Trait<$U>>::$method($value), )+)
+			}
+		}
+	};
+
+	($T:ident $U:ident $value:ident $(, $Rest:ident $RestU:ident $rest:ident )*) => {
+		impl_lift_tuple! { @impl ZeroExtend::zero_extend : $T $U $value $(, $Rest $RestU $rest )* ; ($T, $( $Rest, )*): Distinct<($U, $( $RestU, )*)> }
+		impl_lift_tuple! { @impl SignExtend::sign_extend : $T $U $value $(, $Rest $RestU $rest )* ; ($T, $( $Rest, )*): Distinct<($U, $( $RestU, )*)> }
+		impl_lift_tuple! { @impl Extend::extend : $T $U $value $(, $Rest $RestU $rest )* ; ($T, $( $Rest, )*): Distinct<($U, $( $RestU, )*)> }
+		impl_lift_tuple! { @impl Truncate::truncate : $T $U $value $(, $Rest $RestU $rest )* ; ($U, $( $RestU, )*): Distinct<($T, $( $Rest, )*)> }
+		impl_lift_tuple! { $( $Rest $RestU $rest ),* }
+	};
+
+	() => {};
+}
+
+impl_lift_tuple! { A UA a, B UB b, C UC c, D UD d, E UE e, F UF f, G UG g, H UH h, I UI i, J UJ j, K UK k, L UL l }
+
+impl<T: Signed, const N: usize> Signed for [T; N] {
+	type Signed = [T::Signed; N];
+	type Unsigned = [T::Unsigned; N];
+
+	#[inline]
+	fn as_unsigned(self) -> Self::Unsigned {
+		self.map(T::as_unsigned)
+	}
+
+	#[inline]
+	fn as_signed(self) -> Self::Signed {
+		self.map(T::as_signed)
+	}
+
+	#[inline]
+	fn abs_unsigned(self) -> Self::Unsigned {
+		self.map(T::abs_unsigned)
+	}
+}
+
+impl<T: Signed> Signed for Option<T> {
+	type Signed = Option<T::Signed>;
+	type Unsigned = Option<T::Unsigned>;
+
+	#[inline]
+	fn as_unsigned(self) -> Self::Unsigned {
+		self.map(T::as_unsigned)
+	}
+
+	#[inline]
+	fn as_signed(self) -> Self::Signed {
+		self.map(T::as_signed)
+	}
+
+	#[inline]
+	fn abs_unsigned(self) -> Self::Unsigned {
+		self.map(T::abs_unsigned)
+	}
+}
+
+/// Macro to help implement [`Signed`] for tuples of each length up to the given one
+macro_rules! impl_signed_tuple {
+	(@impl $( $T:ident $value:ident ),+) => {
+		impl<$( $T: Signed ),+> Signed for ($( $T, )+) {
+			type Signed = ($( $T::Signed, )+);
+			type Unsigned = ($( $T::Unsigned, )+);
+
+			#[inline]
+			fn as_unsigned(self) -> Self::Unsigned {
+				let ($( $value, )+) = self;
+				($( $value.as_unsigned(), )+)
+			}
+
+			#[inline]
+			fn as_signed(self) -> Self::Signed {
+				let ($( $value, )+) = self;
+				($( $value.as_signed(), )+)
+			}
+
+			#[inline]
+			fn abs_unsigned(self) -> Self::Unsigned {
+				let ($( $value, )+) = self;
+				($( $value.abs_unsigned(), )+)
+			}
+		}
+	};
+
+	($T:ident $value:ident $(, $Rest:ident $rest:ident )*) => {
+		impl_signed_tuple! { @impl $T $value $(, $Rest $rest )* }
+		impl_signed_tuple! { $( $Rest $rest ),* }
+	};
+
+	() => {};
+}
+
+impl_signed_tuple! { A a, B b, C c, D d, E e, F f, G g, H h, I i, J j, K k, L l }
+
+// Check that the lifted impls exist
+static_assertions::assert_impl_all! { [u8; 4]     : Extend<[u16; 4]>, ZeroExtend<[i32; 4]>, SignExtend<[u64; 4]> }
+static_assertions::assert_impl_all! { [u64; 2]    : Truncate<[u32; 2]>, Truncate<[u64; 2]> }
+static_assertions::assert_impl_all! { [UInt<5>; 2]: Extend<[UInt<7>; 2]>, Extend<[u8; 2]>, Truncate<[UInt<3>; 2]> }
+static_assertions::assert_impl_all! { Option<u64> : Truncate<Option<u32>>, Extend<Option<u128>> }
+static_assertions::assert_impl_all! { (u8, i8)    : Extend<(u32, i32)>, Truncate<(u8, i8)>, ZeroExtend<(u8, u16)> }
+static_assertions::assert_impl_all! { (u64, UInt<5>, [u16; 2]): Truncate<(u64, UInt<3>, [u8; 2])> }
+static_assertions::assert_impl_all! { [[u8; 2]; 2]: Extend<[[u16; 2]; 2]> }
+static_assertions::assert_impl_all! { Option<[u8; 2]>: Extend<Option<[u16; 2]>> }
+static_assertions::assert_impl_all! { [u8; 4]     : IsUnsigned }
+static_assertions::assert_impl_all! { (i8, i16)   : IsSigned }
+static_assertions::assert_impl_all! { Option<u24> : IsUnsigned }
+
+// Check that arrays of different lengths don't convert, and arbitrary-width integers only into valid widths
+static_assertions::assert_not_impl_any! { [u8; 4] : Extend<[u16; 2]>, Extend<[u16; 8]> }
+static_assertions::assert_not_impl_any! { [UInt<5>; 2] : Extend<[UInt<3>; 2]>, Truncate<[UInt<7>; 2]> }
+static_assertions::assert_not_impl_any! { (u8, i16) : IsSigned, IsUnsigned }
+
+// Check that tuples only convert when their first differing element is an integer
+static_assertions::assert_not_impl_any! { ([u8; 2], u8) : Extend<([u16; 2], u16)> }
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use crate::{i24, u24, Extend, Extended, Int, SignExtended, Signed, Truncate, Truncated, UInt, ZeroExtended};
+
+	#[test]
+	#[rustfmt::skip]
+	fn array() {
+		assert_eq!([0x00u8, 0x7f, 0x80, 0xff].extended::<[u16; 4]>(), [0x00, 0x7f, 0x80, 0xff]);
+		assert_eq!([0x00u8, 0x7f, 0x80, 0xff].sign_extended::<[u16; 4]>(), [0x0000, 0x007f, 0xff80, 0xffff]);
+		assert_eq!([-1i8, 1].zero_extended::<[u32; 2]>(), [0xff, 1]);
+		assert_eq!([-1i16].extended::<[i24; 1]>(), [i24::new(-1).unwrap()]);
+		assert_eq!([0x1234_5678u32, 0xffff].truncated::<[u16; 2]>(), [0x5678, 0xffff]);
+		assert_eq!([0u8; 0].extended::<[u64; 0]>(), []);
+		assert_eq!([UInt::<5>::MAX; 2].sign_extended::<[Int<7>; 2]>(), [Int::<7>::new(-1).unwrap(); 2]);
+		assert_eq!([UInt::<5>::MAX; 2].truncated::<[UInt<3>; 2]>(), [UInt::<3>::MAX; 2]);
+		assert_eq!([0x1ffu16].truncated::<[u16; 1]>(), [0x1ff]);
+		assert_eq!([[1u8, 2], [3, 4]].extended::<[[u32; 2]; 2]>(), [[1, 2], [3, 4]]);
+		assert_eq!([Some(-1i8), None].sign_extended::<[Option<u16>; 2]>(), [Some(0xffff), None]);
+
+		let values: [u16; 3] = [1u8, 2, 0xff].extend();
+		assert_eq!(values, [1, 2, 0xff]);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn tuple() {
+		let t: (u32, i32) = (1u8, -1i8).extend();
+		assert_eq!(t, (1, -1));
+
+		assert_eq!((0xffu8, -1i8).extended::<(u32, i32)>(), (0xff, -1));
+		assert_eq!((0xffu8, -1i8).zero_extended::<(u32, u32)>(), (0xff, 0xff));
+		assert_eq!((0xffu8, -1i8).sign_extended::<(u32, i32)>(), (0xffff_ffff, -1));
+		assert_eq!((0x1234u16, -1i64, 0x1_0000u32).truncated::<(u8, i16, u16)>(), (0x34, -1, 0));
+		assert_eq!((1u8,).extended::<(u16,)>(), (1,));
+		assert_eq!((1u8, 0xffu8).zero_extended::<(u8, u16)>(), (1, 0xff));
+		assert_eq!(([1u8, 2], 3u8).extended::<([u8; 2], u16)>(), ([1, 2], 3));
+		assert_eq!((3u8, [1u8, 2]).extended::<(u16, [u16; 2])>(), (3, [1, 2]));
+		assert_eq!((UInt::<5>::MAX, -1i8).truncated::<(UInt<3>, i8)>(), (UInt::<3>::MAX, -1));
+		assert_eq!(
+			(0u8, 1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8, 10u8, -1i8).extended::<(u16, u16, u16, u16, u16, u16, u16, u16, u16, u16, u16, i16)>(),
+			(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, -1),
+		);
+		assert_eq!(
+			(0u8, 1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8, 10u8, -1i8).extended::<(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, i16)>(),
+			(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, -1),
+		);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn option() {
+		assert_eq!(Some(0x1_0000_0002u64).truncated::<Option<u32>>(), Some(2));
+		assert_eq!(None::<u64>          .truncated::<Option<u32>>(), None);
+		assert_eq!(Some(-1i8)           .extended::<Option<i64>>(), Some(-1));
+		assert_eq!(Some(-1i8)           .zero_extended::<Option<u16>>(), Some(0xff));
+		assert_eq!(Some(0x80u8)         .sign_extended::<Option<u16>>(), Some(0xff80));
+		assert_eq!(Some(-1i16)          .extended::<Option<i24>>(), Some(i24::new(-1).unwrap()));
+		assert_eq!(Some([1u8, 2])       .extended::<Option<[u16; 2]>>(), Some([1, 2]));
+		assert_eq!(Some(Some(0xffu8))   .zero_extended::<Option<Option<u32>>>(), Some(Some(0xff)));
+
+		let value: Option<u32> = Some(0x1_0000_0002u64).truncate();
+		assert_eq!(value, Some(2));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn signed() {
+		assert_eq!([0x00u8, 0x7f, 0x80, 0xff].as_signed(), [0, 127, -128, -1]);
+		assert_eq!([-1i16, i16::MIN].as_unsigned(), [0xffff, 0x8000]);
+		assert_eq!([-1i32, i32::MIN, 5].abs_unsigned(), [1, 0x8000_0000, 5]);
+		assert_eq!((0xffu8, -1i16, i24::new(-2).unwrap()).as_unsigned(), (0xff, 0xffff, u24::new(0xff_fffe).unwrap()));
+		assert_eq!((0xffu8, -1i16).as_signed(), (-1, -1));
+		assert_eq!((i8::MIN,).abs_unsigned(), (0x80,));
+		assert_eq!((1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8, 10u8, 11u8, 0xffu8).as_signed(), (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, -1));
+		assert_eq!(Some(0x80u8)    .as_signed(), Some(-128));
+		assert_eq!(None::<i64>     .as_unsigned(), None);
+		assert_eq!(Some([-1i8, 1]) .abs_unsigned(), Some([1, 1]));
+		assert_eq!([(1u8, Some(-1i8))].as_signed(), [(1, Some(-1))]);
+	}
+}
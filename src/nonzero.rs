@@ -0,0 +1,450 @@
+//! Non-zero integers
+//!
+//! This module implements the crate's traits for non-zero integers, such as [`NonZeroU8`], where
+//! the conversions keep them non-zero:
+//!
+//! - [`ZeroExtend`] / [`SignExtend`] / [`Extend`]: Extending a non-zero integer never makes it zero.
+//! - [`Signed`]: `NonZeroI*` is paired with `NonZeroU*`. The saturating conversions clamp to `1`
+//!   instead of `0` for non-zero unsigned integers.
+//! - [`Truncate`]: Truncating a non-zero integer may make it zero, so it truncates into `Option<NonZero*>`,
+//!   which is `None` if all the remaining bits are `0`.
+//!
+//! Note: As the conversions must check that the result isn't zero, they're only
+//!       available through the traits, and not through [`Const`](crate::Const).
+
+// Imports
+use crate::{Extend, Sign, SignError, SignExtend, Signed, SignedExt, Truncate, ZeroExtend};
+use core::num::{
+	NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8,
+	NonZeroUsize,
+};
+
+/// Macro to get the non-zero type of an integer
+macro_rules! non_zero {
+	(u8) => {
+		NonZeroU8
+	};
+	(u16) => {
+		NonZeroU16
+	};
+	(u32) => {
+		NonZeroU32
+	};
+	(u64) => {
+		NonZeroU64
+	};
+	(u128) => {
+		NonZeroU128
+	};
+	(usize) => {
+		NonZeroUsize
+	};
+	(i8) => {
+		NonZeroI8
+	};
+	(i16) => {
+		NonZeroI16
+	};
+	(i32) => {
+		NonZeroI32
+	};
+	(i64) => {
+		NonZeroI64
+	};
+	(i128) => {
+		NonZeroI128
+	};
+	(isize) => {
+		NonZeroIsize
+	};
+}
+
+/// Macro to help implement the extension traits and [`Truncate`] to the same type
+///
+/// Note: The extension traits are implemented by their blanket implementations, so
+///       we only implement them for references here.
+macro_rules! impl_extend_identity {
+	($( $T:ident ),+ $(,)?) => {
+		$(
+			impl<'a> ZeroExtend<non_zero!($T)> for &'a non_zero!($T) {
+				#[inline]
+				fn zero_extend(self) -> non_zero!($T) {
+					*self
+				}
+			}
+
+			impl<'a> SignExtend<non_zero!($T)> for &'a non_zero!($T) {
+				#[inline]
+				fn sign_extend(self) -> non_zero!($T) {
+					*self
+				}
+			}
+
+			impl<'a> Extend<non_zero!($T)> for &'a non_zero!($T) {
+				#[inline]
+				fn extend(self) -> non_zero!($T) {
+					*self
+				}
+			}
+
+			impl Truncate<Option<non_zero!($T)>> for non_zero!($T) {
+				#[inline]
+				fn truncate(self) -> Option<non_zero!($T)> {
+					Some(self)
+				}
+			}
+
+			impl<'a> Truncate<Option<non_zero!($T)>> for &'a non_zero!($T) {
+				#[inline]
+				fn truncate(self) -> Option<non_zero!($T)> {
+					Some(*self)
+				}
+			}
+		)+
+	};
+}
+
+impl_extend_identity! { u8, u16, u32, u64, u128, usize }
+impl_extend_identity! { i8, i16, i32, i64, i128, isize }
+
+/// Macro to help implement the extension traits and [`Truncate`]
+///
+/// Each `T => U` implements the extension traits from `T` into `U` and [`Truncate`] from `U` into `T`.
+macro_rules! impl_conv {
+	($T:ident => $( $U:ident ),+ $(,)?) => {
+		$(
+			impl ZeroExtend<non_zero!($U)> for non_zero!($T) {
+				#[inline]
+				fn zero_extend(self) -> non_zero!($U) {
+					// SAFETY: Zero extending keeps all of the bits of `self`, so the result is non-zero
+					unsafe { <non_zero!($U)>::new_unchecked(ZeroExtend::<$U>::zero_extend(self.get())) }
+				}
+			}
+
+			impl SignExtend<non_zero!($U)> for non_zero!($T) {
+				#[inline]
+				fn sign_extend(self) -> non_zero!($U) {
+					// SAFETY: Sign extending keeps all of the bits of `self`, so the result is non-zero
+					unsafe { <non_zero!($U)>::new_unchecked(SignExtend::<$U>::sign_extend(self.get())) }
+				}
+			}
+
+			impl Extend<non_zero!($U)> for non_zero!($T) {
+				#[inline]
+				fn extend(self) -> non_zero!($U) {
+					// SAFETY: Extending keeps all of the bits of `self`, so the result is non-zero
+					unsafe { <non_zero!($U)>::new_unchecked(Extend::<$U>::extend(self.get())) }
+				}
+			}
+
+			impl Truncate<Option<non_zero!($T)>> for non_zero!($U) {
+				#[inline]
+				fn truncate(self) -> Option<non_zero!($T)> {
+					<non_zero!($T)>::new(Truncate::<$T>::truncate(self.get()))
+				}
+			}
+
+			impl<'a> ZeroExtend<non_zero!($U)> for &'a non_zero!($T) {
+				#[inline]
+				fn zero_extend(self) -> non_zero!($U) {
+					<non_zero!($T) as ZeroExtend<non_zero!($U)>>::zero_extend(*self)
+				}
+			}
+
+			impl<'a> SignExtend<non_zero!($U)> for &'a non_zero!($T) {
+				#[inline]
+				fn sign_extend(self) -> non_zero!($U) {
+					<non_zero!($T) as SignExtend<non_zero!($U)>>::sign_extend(*self)
+				}
+			}
+
+			impl<'a> Extend<non_zero!($U)> for &'a non_zero!($T) {
+				#[inline]
+				fn extend(self) -> non_zero!($U) {
+					<non_zero!($T) as Extend<non_zero!($U)>>::extend(*self)
+				}
+			}
+
+			impl<'a> Truncate<Option<non_zero!($T)>> for &'a non_zero!($U) {
+				#[inline]
+				fn truncate(self) -> Option<non_zero!($T)> {
+					<non_zero!($U) as Truncate<Option<non_zero!($T)>>>::truncate(*self)
+				}
+			}
+		)+
+	};
+}
+
+// Unsigned
+impl_conv! { u8   => u16, u32, u64, u128 }
+impl_conv! { u16  =>      u32, u64, u128 }
+impl_conv! { u32  =>           u64, u128 }
+impl_conv! { u64  =>                u128 }
+
+// Signed
+impl_conv! { i8   => i16, i32, i64, i128 }
+impl_conv! { i16  =>      i32, i64, i128 }
+impl_conv! { i32  =>           i64, i128 }
+impl_conv! { i64  =>                i128 }
+
+// Unsigned to signed
+impl_conv! { u8   => i16, i32, i64, i128 }
+impl_conv! { u16  =>      i32, i64, i128 }
+impl_conv! { u32  =>           i64, i128 }
+impl_conv! { u64  =>                i128 }
+
+// Signed to unsigned
+impl_conv! { i8   => u16, u32, u64, u128 }
+impl_conv! { i16  =>      u32, u64, u128 }
+impl_conv! { i32  =>           u64, u128 }
+impl_conv! { i64  =>                u128 }
+
+// Pointer-sized
+// Note: These only exist where the extension is lossless for the current pointer width.
+impl_conv! { u8    => usize, isize }
+impl_conv! { u16   => usize }
+impl_conv! { usize => u64, u128, i128 }
+impl_conv! { i8    => usize, isize }
+impl_conv! { i16   => isize }
+impl_conv! { isize => u128, i64, i128 }
+#[cfg(any(target_pointer_width = "16", target_pointer_width = "32"))]
+impl_conv! { usize => u32, i64 }
+#[cfg(any(target_pointer_width = "16", target_pointer_width = "32"))]
+impl_conv! { isize => u64, i32 }
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl_conv! { u16   => isize }
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl_conv! { u32   => usize }
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl_conv! { i16   => usize }
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl_conv! { i32   => isize }
+#[cfg(target_pointer_width = "16")]
+impl_conv! { usize => u16, i32 }
+#[cfg(target_pointer_width = "16")]
+impl_conv! { isize => u32, i16 }
+#[cfg(target_pointer_width = "64")]
+impl_conv! { u32   => isize }
+#[cfg(target_pointer_width = "64")]
+impl_conv! { u64   => usize }
+#[cfg(target_pointer_width = "64")]
+impl_conv! { i32   => usize }
+#[cfg(target_pointer_width = "64")]
+impl_conv! { i64   => isize }
+
+// Check that all `Extend` impls exist
+static_assertions::assert_impl_all! { NonZeroI8  : Extend<NonZeroI8>, Extend<NonZeroI16>, Extend<NonZeroI32>, Extend<NonZeroI64>, Extend<NonZeroI128> }
+static_assertions::assert_impl_all! { NonZeroU8  : Extend<NonZeroU8>, Extend<NonZeroU16>, Extend<NonZeroU32>, Extend<NonZeroU64>, Extend<NonZeroU128> }
+static_assertions::assert_impl_all! { NonZeroI16 :                      Extend<NonZeroU32>, Extend<NonZeroU64>, Extend<NonZeroU128> }
+static_assertions::assert_impl_all! { NonZeroU16 :                      Extend<NonZeroI32>, Extend<NonZeroI64>, Extend<NonZeroI128> }
+
+// Check that all `Truncate` impls exist
+static_assertions::assert_impl_all! { NonZeroI128 : Truncate<Option<NonZeroI128>>, Truncate<Option<NonZeroI64>>, Truncate<Option<NonZeroI32>>, Truncate<Option<NonZeroI16>>, Truncate<Option<NonZeroI8>> }
+static_assertions::assert_impl_all! { NonZeroU128 : Truncate<Option<NonZeroU128>>, Truncate<Option<NonZeroU64>>, Truncate<Option<NonZeroU32>>, Truncate<Option<NonZeroU16>>, Truncate<Option<NonZeroU8>> }
+
+// Check that truncation can't produce a non-zero integer directly, as it may produce zero
+static_assertions::assert_not_impl_any! { NonZeroU16 : Truncate<NonZeroU8> }
+static_assertions::assert_not_impl_any! { NonZeroI16 : Truncate<NonZeroI8> }
+
+/// Macro to help implement [`Signed`]
+///
+/// Every method is forwarded to the underlying integer, whose result is
+/// non-zero whenever it's input is, except for the saturating ones.
+macro_rules! impl_signed {
+	($T:ident : - $TSigned:ident : + $TUnsigned:ident) => {
+		impl Signed for non_zero!($T) {
+			type Signed = non_zero!($TSigned);
+			type Unsigned = non_zero!($TUnsigned);
+
+			#[inline]
+			fn as_unsigned(self) -> Self::Unsigned {
+				// SAFETY: Reinterpreting keeps all of the bits of `self`, so the result is non-zero
+				unsafe { <Self::Unsigned>::new_unchecked(self.get().as_unsigned()) }
+			}
+
+			#[inline]
+			fn as_signed(self) -> Self::Signed {
+				// SAFETY: Reinterpreting keeps all of the bits of `self`, so the result is non-zero
+				unsafe { <Self::Signed>::new_unchecked(self.get().as_signed()) }
+			}
+
+			#[inline]
+			fn abs_unsigned(self) -> Self::Unsigned {
+				// SAFETY: The magnitude of a non-zero value is non-zero, and is always in range of the unsigned variant
+				unsafe { <Self::Unsigned>::new_unchecked(self.get().abs_unsigned()) }
+			}
+		}
+
+		impl SignedExt for non_zero!($T) {
+			#[inline]
+			fn sign(self) -> Sign {
+				self.get().sign()
+			}
+
+			#[inline]
+			fn is_negative(self) -> bool {
+				self.get().is_negative()
+			}
+
+			#[inline]
+			fn is_positive(self) -> bool {
+				self.get().is_positive()
+			}
+
+			#[inline]
+			fn signum(self) -> Self {
+				// SAFETY: The signum of a non-zero value is `-1` or `1`
+				unsafe { Self::new_unchecked(self.get().signum()) }
+			}
+
+			#[inline]
+			fn try_to_signed(self) -> Result<Self::Signed, SignError> {
+				self.get().try_to_signed().map(|value| {
+					// SAFETY: The conversion preserves the value of `self`, which is non-zero
+					unsafe { <Self::Signed>::new_unchecked(value) }
+				})
+			}
+
+			#[inline]
+			fn try_to_unsigned(self) -> Result<Self::Unsigned, SignError> {
+				self.get().try_to_unsigned().map(|value| {
+					// SAFETY: The conversion preserves the value of `self`, which is non-zero
+					unsafe { <Self::Unsigned>::new_unchecked(value) }
+				})
+			}
+
+			#[inline]
+			fn saturating_to_signed(self) -> Self::Signed {
+				// SAFETY: The value of `self` is either preserved or clamped to the signed variant's maximum, which are both non-zero
+				unsafe { <Self::Signed>::new_unchecked(self.get().saturating_to_signed()) }
+			}
+
+			#[inline]
+			fn saturating_to_unsigned(self) -> Self::Unsigned {
+				// Note: Negative values are clamped to `0`, which we replace with the smallest non-zero value
+				// SAFETY: `1` is non-zero
+				<Self::Unsigned>::new(self.get().saturating_to_unsigned()).unwrap_or(unsafe { <Self::Unsigned>::new_unchecked(1) })
+			}
+
+			#[inline]
+			fn overflowing_to_signed(self) -> (Self::Signed, bool) {
+				let (value, overflowed) = self.get().overflowing_to_signed();
+				// SAFETY: Reinterpreting keeps all of the bits of `self`, so the result is non-zero
+				(unsafe { <Self::Signed>::new_unchecked(value) }, overflowed)
+			}
+
+			#[inline]
+			fn overflowing_to_unsigned(self) -> (Self::Unsigned, bool) {
+				let (value, overflowed) = self.get().overflowing_to_unsigned();
+				// SAFETY: Reinterpreting keeps all of the bits of `self`, so the result is non-zero
+				(unsafe { <Self::Unsigned>::new_unchecked(value) }, overflowed)
+			}
+
+			#[inline]
+			fn try_from_sign_magnitude(negative: bool, magnitude: Self::Unsigned) -> Result<Self, SignError> {
+				<$T>::try_from_sign_magnitude(negative, magnitude.get()).map(|value| {
+					// SAFETY: The magnitude is non-zero, and is only returned when it's in range, so the value is non-zero
+					unsafe { Self::new_unchecked(value) }
+				})
+			}
+
+			#[inline]
+			fn saturating_from_sign_magnitude(negative: bool, magnitude: Self::Unsigned) -> Self {
+				// Note: Negative values are clamped to `0` for unsigned integers, which we replace with the smallest non-zero value
+				// SAFETY: `1` is non-zero
+				Self::new(<$T>::saturating_from_sign_magnitude(negative, magnitude.get())).unwrap_or(unsafe { Self::new_unchecked(1) })
+			}
+
+			#[inline]
+			fn wrapping_from_sign_magnitude(negative: bool, magnitude: Self::Unsigned) -> Self {
+				// SAFETY: The magnitude is non-zero and in range of the unsigned integer, so negating it never wraps around to `0`
+				unsafe { Self::new_unchecked(<$T>::wrapping_from_sign_magnitude(negative, magnitude.get())) }
+			}
+
+			#[inline]
+			fn zigzag_encode(self) -> Self::Unsigned {
+				// SAFETY: Zigzag encoding only maps `0` to `0`, so the result is non-zero
+				unsafe { <Self::Unsigned>::new_unchecked(self.get().zigzag_encode()) }
+			}
+
+			#[inline]
+			fn zigzag_decode(value: Self::Unsigned) -> Self::Signed {
+				// SAFETY: Zigzag decoding only maps `0` to `0`, so the result is non-zero
+				unsafe { <Self::Signed>::new_unchecked(<$T>::zigzag_decode(value.get())) }
+			}
+		}
+	};
+
+	($( - $TSigned:ident : + $TUnsigned:ident ),+ $(,)?) => {
+		$(
+			impl_signed! { $TSigned   : - $TSigned : + $TUnsigned }
+			impl_signed! { $TUnsigned : - $TSigned : + $TUnsigned }
+		)+
+	};
+}
+
+impl_signed! {
+	- i8    : + u8,
+	- i16   : + u16,
+	- i32   : + u32,
+	- i64   : + u64,
+	- i128  : + u128,
+	- isize : + usize,
+}
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use super::*;
+	use crate::{Extended, IsSigned, IsUnsigned, SignExtended, Truncated, ZeroExtended};
+	use core::num::{NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+
+	#[test]
+	#[rustfmt::skip]
+	fn extend() {
+		assert_eq!(NonZeroU32::new(u32::MAX).unwrap().zero_extended::<NonZeroU64>().get(), u64::from(u32::MAX));
+		assert_eq!(NonZeroI8::new(i8::MIN).unwrap().extended::<NonZeroI64>().get(), -128);
+		assert_eq!(NonZeroI8::new(i8::MIN).unwrap().zero_extended::<NonZeroU16>().get(), 0x80);
+		assert_eq!(NonZeroI8::new(i8::MIN).unwrap().sign_extended::<NonZeroU16>().get(), 0xff80);
+		assert_eq!(NonZeroU8::new(u8::MAX).unwrap().extended::<NonZeroI16>().get(), 0xff);
+		assert_eq!(NonZeroU16::new(1).unwrap().extended::<NonZeroUsize>().get(), 1);
+		assert_eq!((&NonZeroU32::new(1).unwrap()).extended::<NonZeroU32>(), NonZeroU32::new(1).unwrap());
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn truncate() {
+		assert_eq!(NonZeroU64::new(0x1_0000_0001).unwrap().truncated::<Option<NonZeroU32>>(), NonZeroU32::new(1));
+		assert_eq!(NonZeroU64::new(0x1_0000_0000).unwrap().truncated::<Option<NonZeroU32>>(), None);
+		assert_eq!(NonZeroI32::new(i32::MIN).unwrap().truncated::<Option<NonZeroI16>>(), None);
+		assert_eq!(NonZeroI32::new(-1).unwrap().truncated::<Option<NonZeroU8>>(), NonZeroU8::new(0xff));
+		assert_eq!((&NonZeroUsize::new(usize::MAX).unwrap()).truncated::<Option<NonZeroU8>>(), NonZeroU8::new(0xff));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn signed() {
+		assert_eq!(NonZeroI8::new(i8::MIN).unwrap().as_unsigned(), NonZeroU8::new(0x80).unwrap());
+		assert_eq!(NonZeroU32::new(u32::MAX).unwrap().as_signed(), NonZeroI32::new(-1).unwrap());
+		assert_eq!(NonZeroI16::new(i16::MIN).unwrap().abs_unsigned(), NonZeroU16::new(0x8000).unwrap());
+		assert_eq!(NonZeroI64::new(-5).unwrap().sign(), Sign::Negative);
+		assert_eq!(NonZeroI64::new(-5).unwrap().signum(), NonZeroI64::new(-1).unwrap());
+		assert_eq!(NonZeroU8::new(u8::MAX).unwrap().try_to_signed(), Err(SignError));
+		assert_eq!(NonZeroI8::new(i8::MIN).unwrap().saturating_to_unsigned(), NonZeroU8::new(1).unwrap());
+		assert_eq!(NonZeroU8::new(u8::MAX).unwrap().saturating_to_signed(), NonZeroI8::new(i8::MAX).unwrap());
+		assert_eq!(NonZeroU8::new(u8::MAX).unwrap().overflowing_to_signed(), (NonZeroI8::new(-1).unwrap(), true));
+		assert_eq!(NonZeroI8::try_from_sign_magnitude(true, NonZeroU8::new(0x80).unwrap()), Ok(NonZeroI8::new(i8::MIN).unwrap()));
+		assert_eq!(NonZeroU8::saturating_from_sign_magnitude(true, NonZeroU8::new(u8::MAX).unwrap()), NonZeroU8::new(1).unwrap());
+		assert_eq!(NonZeroI8::wrapping_from_sign_magnitude(false, NonZeroU8::new(u8::MAX).unwrap()), NonZeroI8::new(-1).unwrap());
+		assert_eq!(NonZeroI32::new(-1).unwrap().zigzag_encode(), NonZeroU32::new(1).unwrap());
+		assert_eq!(NonZeroI32::zigzag_decode(NonZeroU32::new(u32::MAX).unwrap()), NonZeroI32::new(i32::MIN).unwrap());
+	}
+
+	#[test]
+	fn is_signed() {
+		fn is_signed<T: IsSigned>() {}
+		fn is_unsigned<T: IsUnsigned>() {}
+		is_signed::<NonZeroI8>();
+		is_unsigned::<NonZeroU64>();
+	}
+}
@@ -0,0 +1,294 @@
+//! Odd-width integers
+//!
+//! This module provides integers whose width isn't a power of two, such as
+//! the 24-bit samples of audio formats, or the 48-bit addresses of network formats.
+//!
+//! Each integer is stored in the smallest primitive that fits it, and is always
+//! kept within it's range, so they may be compared, hashed and formatted as usual.
+//!
+//! They take part in all of the crate's traits, the same way primitives do, e.g.
+//! `u24` zero extends into `u32` and `u32` truncates into `u24`. The only exception
+//! is [`Split`](crate::Split), which is only implemented by integers that may be
+//! split into halves of another integer of this crate, such as `u48`.
+
+// Modules
+mod bits;
+mod conv;
+mod fmt;
+mod ops;
+mod split;
+
+// Imports
+use crate::{
+	raw::{self, Raw},
+	sign::impl_signed_const,
+	Const, Signed,
+};
+
+/// Macro to define a signed / unsigned pair of odd-width integers
+macro_rules! odd_int {
+	($BITS:literal : - $TSigned:ident($ISigned:ty) : + $TUnsigned:ident($IUnsigned:ty)) => {
+		#[doc = concat!(stringify!($BITS), "-bit unsigned integer")]
+		#[allow(non_camel_case_types)]
+		#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default)]
+		#[repr(transparent)]
+		pub struct $TUnsigned($IUnsigned);
+
+		#[doc = concat!(stringify!($BITS), "-bit signed integer")]
+		#[allow(non_camel_case_types)]
+		#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default)]
+		#[repr(transparent)]
+		pub struct $TSigned($ISigned);
+
+		impl $TUnsigned {
+			/// The size of this integer in bits
+			pub const BITS: u32 = $BITS;
+
+			/// The smallest value of this integer
+			pub const MIN: Self = Self(0);
+
+			/// The largest value of this integer
+			pub const MAX: Self = Self((1 << $BITS) - 1);
+
+			/// Creates this integer from `value`, if it fits
+			#[must_use]
+			#[inline]
+			pub const fn new(value: $IUnsigned) -> Option<Self> {
+				if value <= Self::MAX.0 {
+					Some(Self(value))
+				} else {
+					None
+				}
+			}
+
+			/// Returns the value of this integer
+			#[must_use]
+			#[inline]
+			pub const fn get(self) -> $IUnsigned {
+				self.0
+			}
+		}
+
+		impl $TSigned {
+			/// The size of this integer in bits
+			pub const BITS: u32 = $BITS;
+
+			/// The smallest value of this integer
+			pub const MIN: Self = Self(-(1 << ($BITS - 1)));
+
+			/// The largest value of this integer
+			pub const MAX: Self = Self((1 << ($BITS - 1)) - 1);
+
+			/// Creates this integer from `value`, if it fits
+			#[must_use]
+			#[inline]
+			pub const fn new(value: $ISigned) -> Option<Self> {
+				if Self::MIN.0 <= value && value <= Self::MAX.0 {
+					Some(Self(value))
+				} else {
+					None
+				}
+			}
+
+			/// Returns the value of this integer
+			#[must_use]
+			#[inline]
+			pub const fn get(self) -> $ISigned {
+				self.0
+			}
+		}
+
+		impl Const<$TUnsigned> {
+			/// Returns the raw bits of `value`
+			pub(crate) const fn to_raw(value: $TUnsigned) -> u128 {
+				Const::<$IUnsigned>::to_raw(value.0)
+			}
+
+			/// Creates an integer from the lower bits of `raw`
+			pub(crate) const fn from_raw(raw: u128) -> $TUnsigned {
+				$TUnsigned(Const::<$IUnsigned>::from_raw(raw & raw::mask($BITS)))
+			}
+		}
+
+		impl Const<$TSigned> {
+			/// Returns the raw bits of `value`
+			pub(crate) const fn to_raw(value: $TSigned) -> u128 {
+				Const::<$ISigned>::to_raw(value.0) & raw::mask($BITS)
+			}
+
+			/// Creates an integer from the lower bits of `raw`
+			pub(crate) const fn from_raw(raw: u128) -> $TSigned {
+				// Note: We keep the inner integer sign extended, so it holds the same value
+				$TSigned(Const::<$ISigned>::from_raw(raw::sign_extend(raw, $BITS)))
+			}
+		}
+
+		impl_signed_const! { [] $TUnsigned : -$TSigned : +$TUnsigned }
+		impl_signed_const! { [] $TSigned : -$TSigned : +$TUnsigned }
+
+		impl Raw for $TUnsigned {
+			const BITS: u32 = $BITS;
+			const SIGNED: bool = false;
+
+			#[inline]
+			fn to_raw(self) -> u128 {
+				Const::<$TUnsigned>::to_raw(self)
+			}
+
+			#[inline]
+			fn from_raw(raw: u128) -> Self {
+				Const::<$TUnsigned>::from_raw(raw)
+			}
+		}
+
+		impl Raw for $TSigned {
+			const BITS: u32 = $BITS;
+			const SIGNED: bool = true;
+
+			#[inline]
+			fn to_raw(self) -> u128 {
+				Const::<$TSigned>::to_raw(self)
+			}
+
+			#[inline]
+			fn from_raw(raw: u128) -> Self {
+				Const::<$TSigned>::from_raw(raw)
+			}
+		}
+
+		impl Signed for $TSigned {
+			type Signed = $TSigned;
+			type Unsigned = $TUnsigned;
+
+			#[inline]
+			fn as_unsigned(self) -> Self::Unsigned {
+				Const::<$TSigned>::as_unsigned(self)
+			}
+
+			#[inline]
+			fn as_signed(self) -> Self::Signed {
+				Const::<$TSigned>::as_signed(self)
+			}
+
+			#[inline]
+			fn abs_unsigned(self) -> Self::Unsigned {
+				Const::<$TSigned>::abs_unsigned(self)
+			}
+		}
+
+		impl Signed for $TUnsigned {
+			type Signed = $TSigned;
+			type Unsigned = $TUnsigned;
+
+			#[inline]
+			fn as_unsigned(self) -> Self::Unsigned {
+				Const::<$TUnsigned>::as_unsigned(self)
+			}
+
+			#[inline]
+			fn as_signed(self) -> Self::Signed {
+				Const::<$TUnsigned>::as_signed(self)
+			}
+
+			#[inline]
+			fn abs_unsigned(self) -> Self::Unsigned {
+				Const::<$TUnsigned>::abs_unsigned(self)
+			}
+		}
+	};
+}
+
+odd_int! { 24 : -i24(i32) : +u24(u32) }
+odd_int! { 40 : -i40(i64) : +u40(u64) }
+odd_int! { 48 : -i48(i64) : +u48(u64) }
+odd_int! { 56 : -i56(i64) : +u56(u64) }
+
+// Check that they all implement `Signed` / `IsSigned` / `IsUnsigned`
+static_assertions::assert_impl_all! { i24: crate::Signed, crate::IsSigned   }
+static_assertions::assert_impl_all! { i40: crate::Signed, crate::IsSigned   }
+static_assertions::assert_impl_all! { i48: crate::Signed, crate::IsSigned   }
+static_assertions::assert_impl_all! { i56: crate::Signed, crate::IsSigned   }
+static_assertions::assert_impl_all! { u24: crate::Signed, crate::IsUnsigned }
+static_assertions::assert_impl_all! { u40: crate::Signed, crate::IsUnsigned }
+static_assertions::assert_impl_all! { u48: crate::Signed, crate::IsUnsigned }
+static_assertions::assert_impl_all! { u56: crate::Signed, crate::IsUnsigned }
+
+// Check that all associated types are correct
+static_assertions::assert_type_eq_all! { <i24 as Signed>::Signed, <u24 as Signed>::Signed, i24 }
+static_assertions::assert_type_eq_all! { <i40 as Signed>::Signed, <u40 as Signed>::Signed, i40 }
+static_assertions::assert_type_eq_all! { <i48 as Signed>::Signed, <u48 as Signed>::Signed, i48 }
+static_assertions::assert_type_eq_all! { <i56 as Signed>::Signed, <u56 as Signed>::Signed, i56 }
+static_assertions::assert_type_eq_all! { <i24 as Signed>::Unsigned, <u24 as Signed>::Unsigned, u24 }
+static_assertions::assert_type_eq_all! { <i40 as Signed>::Unsigned, <u40 as Signed>::Unsigned, u40 }
+static_assertions::assert_type_eq_all! { <i48 as Signed>::Unsigned, <u48 as Signed>::Unsigned, u48 }
+static_assertions::assert_type_eq_all! { <i56 as Signed>::Unsigned, <u56 as Signed>::Unsigned, u56 }
+
+// Check that they have the same size as their inner integer
+static_assertions::assert_eq_size! { u24, i24, u32 }
+static_assertions::assert_eq_size! { u40, i40, u64 }
+static_assertions::assert_eq_size! { u48, i48, u64 }
+static_assertions::assert_eq_size! { u56, i56, u64 }
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use super::*;
+	use crate::{Sign, SignError, SignedExt};
+
+	#[test]
+	#[rustfmt::skip]
+	fn min_max() {
+		assert_eq!(u24::MIN.get(), 0);
+		assert_eq!(u24::MAX.get(), 0x00ff_ffff);
+		assert_eq!(i24::MIN.get(), -0x0080_0000);
+		assert_eq!(i24::MAX.get(),  0x007f_ffff);
+		assert_eq!(u56::MAX.get(), 0x00ff_ffff_ffff_ffff);
+		assert_eq!(i56::MIN.get(), -0x0080_0000_0000_0000);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn new() {
+		assert_eq!(u24::new(0x00ff_ffff), Some(u24::MAX));
+		assert_eq!(u24::new(0x0100_0000), None);
+		assert_eq!(i24::new(-0x0080_0000), Some(i24::MIN));
+		assert_eq!(i24::new(-0x0080_0001), None);
+		assert_eq!(i24::new( 0x0080_0000), None);
+		assert_eq!(u40::new(0x0100_0000_0000), None);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn signed() {
+		assert_eq!(i24::MIN.as_unsigned(), u24::new(0x0080_0000).unwrap());
+		assert_eq!(i24::new(-1).unwrap().as_unsigned(), u24::MAX);
+		assert_eq!(u24::MAX.as_signed(), i24::new(-1).unwrap());
+		assert_eq!(u48::MAX.as_signed(), i48::new(-1).unwrap());
+		assert_eq!(i24::MIN.abs_unsigned(), u24::new(0x0080_0000).unwrap());
+		assert_eq!(i24::new(-5).unwrap().abs_unsigned(), u24::new(5).unwrap());
+		assert_eq!(u24::MAX.abs_unsigned(), u24::MAX);
+		assert_eq!(i24::MIN.sign(), Sign::Negative);
+		assert_eq!(i24::MIN.signum(), i24::new(-1).unwrap());
+		assert_eq!(u24::MIN.sign(), Sign::Zero);
+		assert_eq!(u24::MAX.signum(), u24::new(1).unwrap());
+		assert!(i40::new(-1).unwrap().is_negative());
+		assert!(u40::MAX.is_positive());
+		assert!(!u40::MAX.is_negative());
+		assert_eq!(u24::MAX.try_to_signed(), Err(SignError));
+		assert_eq!(u24::MAX.saturating_to_signed(), i24::MAX);
+		assert_eq!(u24::MAX.overflowing_to_signed(), (i24::new(-1).unwrap(), true));
+		assert_eq!(u48::new(5).unwrap().try_to_signed(), Ok(i48::new(5).unwrap()));
+		assert_eq!(i24::MIN.try_to_unsigned(), Err(SignError));
+		assert_eq!(i24::MIN.saturating_to_unsigned(), u24::MIN);
+		assert_eq!(i24::MAX.overflowing_to_unsigned(), (u24::new(0x007f_ffff).unwrap(), false));
+		assert_eq!(i24::try_from_sign_magnitude(true, i24::MIN.abs_unsigned()), Ok(i24::MIN));
+		assert_eq!(i24::try_from_sign_magnitude(false, i24::MIN.abs_unsigned()), Err(SignError));
+		assert_eq!(i40::saturating_from_sign_magnitude(true, u40::MAX), i40::MIN);
+		assert_eq!(i56::wrapping_from_sign_magnitude(false, u56::MAX), i56::new(-1).unwrap());
+		assert_eq!(u24::try_from_sign_magnitude(true, u24::MAX), Err(SignError));
+		assert_eq!(i24::MIN.zigzag_encode(), u24::MAX);
+		assert_eq!(i40::new(-3).unwrap().zigzag_encode(), u40::new(5).unwrap());
+		assert_eq!(i48::zigzag_decode(u48::MAX), i48::MIN);
+		assert_eq!(i56::zigzag_decode(u56::new(4).unwrap()), i56::new(2).unwrap());
+	}
+}
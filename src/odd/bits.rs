@@ -0,0 +1,265 @@
+//! Extension from and truncation to an arbitrary bit width for odd-width integers
+
+// Imports
+use super::{i24, i40, i48, i56, u24, u40, u48, u56};
+use crate::{raw, Const, ExtendBits, TruncateBits};
+
+/// Macro to help implement [`ExtendBits`] and [`TruncateBits`]
+macro_rules! impl_bits {
+	($( $T:ty ),+ $(,)?) => {
+		$(
+			impl Const<$T> {
+				/// Sign extends the lower `bits` bits of `value`
+				///
+				/// This is the `const` version of [`ExtendBits::sign_extend_from`].
+				#[must_use]
+				#[inline]
+				pub const fn sign_extend_from(value: $T, bits: u32) -> $T {
+					match bits {
+						_ if bits >= <$T>::BITS => value,
+						_ => Const::<$T>::from_raw(raw::sign_extend(Const::<$T>::to_raw(value), bits)),
+					}
+				}
+
+				/// Zero extends the lower `bits` bits of `value`
+				///
+				/// This is the `const` version of [`ExtendBits::zero_extend_from`].
+				#[must_use]
+				#[inline]
+				pub const fn zero_extend_from(value: $T, bits: u32) -> $T {
+					match bits {
+						_ if bits >= <$T>::BITS => value,
+						_ => Const::<$T>::from_raw(Const::<$T>::to_raw(value) & raw::mask(bits)),
+					}
+				}
+
+				/// Sign extends the lower `bits` bits of `value`, if no bits above them are set
+				///
+				/// This is the `const` version of [`ExtendBits::checked_sign_extend_from`].
+				#[must_use]
+				#[inline]
+				pub const fn checked_sign_extend_from(value: $T, bits: u32) -> Option<$T> {
+					match Const::<$T>::checked_zero_extend_from(value, bits) {
+						Some(value) => Some(Const::<$T>::sign_extend_from(value, bits)),
+						None => None,
+					}
+				}
+
+				/// Zero extends the lower `bits` bits of `value`, if no bits above them are set
+				///
+				/// This is the `const` version of [`ExtendBits::checked_zero_extend_from`].
+				#[must_use]
+				#[inline]
+				pub const fn checked_zero_extend_from(value: $T, bits: u32) -> Option<$T> {
+					let extended = Const::<$T>::zero_extend_from(value, bits);
+					if extended.0 == value.0 {
+						Some(extended)
+					} else {
+						None
+					}
+				}
+
+				/// Truncates `value` to it's lower `bits` bits, zero extending them
+				///
+				/// This is the `const` version of [`TruncateBits::truncate_to`].
+				#[must_use]
+				#[inline]
+				pub const fn truncate_to(value: $T, bits: u32) -> $T {
+					Const::<$T>::zero_extend_from(value, bits)
+				}
+
+				/// Truncates `value` to it's lower `bits` bits, sign extending them
+				///
+				/// This is the `const` version of [`TruncateBits::sign_truncate_to`].
+				#[must_use]
+				#[inline]
+				pub const fn sign_truncate_to(value: $T, bits: u32) -> $T {
+					Const::<$T>::sign_extend_from(value, bits)
+				}
+
+				/// Truncates `value` to it's lower `bits` bits, if it fits in them as unsigned
+				///
+				/// This is the `const` version of [`TruncateBits::checked_truncate_to`].
+				#[must_use]
+				#[inline]
+				pub const fn checked_truncate_to(value: $T, bits: u32) -> Option<$T> {
+					// Note: Negative values don't fit an unsigned field, even one as wide as the integer
+					let truncated = Const::<$T>::truncate_to(value, bits);
+					if truncated.0 == value.0 && !Const::<$T>::is_negative(value) {
+						Some(truncated)
+					} else {
+						None
+					}
+				}
+
+				/// Truncates `value` to it's lower `bits` bits, if it fits in them as signed
+				///
+				/// This is the `const` version of [`TruncateBits::checked_sign_truncate_to`].
+				#[must_use]
+				#[inline]
+				pub const fn checked_sign_truncate_to(value: $T, bits: u32) -> Option<$T> {
+					let truncated = Const::<$T>::sign_truncate_to(value, bits);
+					if truncated.0 == value.0 {
+						Some(truncated)
+					} else {
+						None
+					}
+				}
+
+				/// Truncates `value` to it's lower `bits` bits, clamping it to their unsigned range
+				///
+				/// This is the `const` version of [`TruncateBits::saturating_truncate_to`].
+				#[must_use]
+				#[inline]
+				pub const fn saturating_truncate_to(value: $T, bits: u32) -> $T {
+					match bits {
+						_ if Const::<$T>::is_negative(value) => Const::<$T>::from_raw(0),
+						_ if bits >= <$T>::BITS => value,
+						_ => {
+							let raw = Const::<$T>::to_raw(value);
+							let max = raw::mask(bits);
+							Const::<$T>::from_raw(if raw > max { max } else { raw })
+						},
+					}
+				}
+
+				/// Truncates `value` to it's lower `bits` bits, clamping it to their signed range
+				///
+				/// This is the `const` version of [`TruncateBits::saturating_sign_truncate_to`].
+				#[must_use]
+				#[inline]
+				#[allow(clippy::as_conversions)]
+				pub const fn saturating_sign_truncate_to(value: $T, bits: u32) -> $T {
+					match bits {
+						0 => Const::<$T>::from_raw(0),
+						_ if bits >= <$T>::BITS => value,
+						_ => {
+							// Note: Our width is always smaller than `i128`'s, so these can't overflow.
+							// Note: Casting between signedness is a no-op.
+							let value = raw::sign_extend(Const::<$T>::to_raw(value), <$T>::BITS) as i128;
+							let min = -(1 << (bits - 1));
+							let max = (1 << (bits - 1)) - 1;
+							let value = if value < min {
+								min
+							} else if value > max {
+								max
+							} else {
+								value
+							};
+							Const::<$T>::from_raw(value as u128)
+						},
+					}
+				}
+			}
+
+			impl ExtendBits for $T {
+				#[inline]
+				fn sign_extend_from(self, bits: u32) -> Self {
+					Const::<$T>::sign_extend_from(self, bits)
+				}
+
+				#[inline]
+				fn zero_extend_from(self, bits: u32) -> Self {
+					Const::<$T>::zero_extend_from(self, bits)
+				}
+
+				#[inline]
+				fn checked_sign_extend_from(self, bits: u32) -> Option<Self> {
+					Const::<$T>::checked_sign_extend_from(self, bits)
+				}
+
+				#[inline]
+				fn checked_zero_extend_from(self, bits: u32) -> Option<Self> {
+					Const::<$T>::checked_zero_extend_from(self, bits)
+				}
+			}
+
+			impl TruncateBits for $T {
+				#[inline]
+				fn truncate_to(self, bits: u32) -> Self {
+					Const::<$T>::truncate_to(self, bits)
+				}
+
+				#[inline]
+				fn sign_truncate_to(self, bits: u32) -> Self {
+					Const::<$T>::sign_truncate_to(self, bits)
+				}
+
+				#[inline]
+				fn checked_truncate_to(self, bits: u32) -> Option<Self> {
+					Const::<$T>::checked_truncate_to(self, bits)
+				}
+
+				#[inline]
+				fn checked_sign_truncate_to(self, bits: u32) -> Option<Self> {
+					Const::<$T>::checked_sign_truncate_to(self, bits)
+				}
+
+				#[inline]
+				fn saturating_truncate_to(self, bits: u32) -> Self {
+					Const::<$T>::saturating_truncate_to(self, bits)
+				}
+
+				#[inline]
+				fn saturating_sign_truncate_to(self, bits: u32) -> Self {
+					Const::<$T>::saturating_sign_truncate_to(self, bits)
+				}
+			}
+		)+
+	};
+}
+
+impl_bits! { u24, u40, u48, u56 }
+impl_bits! { i24, i40, i48, i56 }
+
+// Check that all impls exist
+static_assertions::assert_impl_all! { i24: ExtendBits, TruncateBits }
+static_assertions::assert_impl_all! { i40: ExtendBits, TruncateBits }
+static_assertions::assert_impl_all! { i48: ExtendBits, TruncateBits }
+static_assertions::assert_impl_all! { i56: ExtendBits, TruncateBits }
+static_assertions::assert_impl_all! { u24: ExtendBits, TruncateBits }
+static_assertions::assert_impl_all! { u40: ExtendBits, TruncateBits }
+static_assertions::assert_impl_all! { u48: ExtendBits, TruncateBits }
+static_assertions::assert_impl_all! { u56: ExtendBits, TruncateBits }
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use super::*;
+
+	#[test]
+	#[rustfmt::skip]
+	fn extend_from() {
+		assert_eq!(u24::new(0x0fff).unwrap().sign_extend_from(12), u24::MAX);
+		assert_eq!(u24::new(0x07ff).unwrap().sign_extend_from(12), u24::new(0x07ff).unwrap());
+		assert_eq!(i24::new(0x0800).unwrap().sign_extend_from(12), i24::new(-0x0800).unwrap());
+		assert_eq!(i24::new(-1).unwrap().zero_extend_from(12), i24::new(0x0fff).unwrap());
+		assert_eq!(i24::MIN.sign_extend_from(24), i24::MIN);
+		assert_eq!(u24::MAX.sign_extend_from(0), u24::MIN);
+		assert_eq!(u24::new(0x1fff).unwrap().checked_zero_extend_from(12), None);
+		assert_eq!(u48::new(0x0800).unwrap().checked_sign_extend_from(12), Some(u48::MAX << 11));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn truncate_to() {
+		assert_eq!(u24::MAX.truncate_to(12), u24::new(0x0fff).unwrap());
+		assert_eq!(i24::new(0x0800).unwrap().sign_truncate_to(12), i24::new(-0x0800).unwrap());
+		assert_eq!(u24::MAX.checked_truncate_to(12), None);
+		assert_eq!(i24::new(-0x0800).unwrap().checked_sign_truncate_to(12), i24::new(-0x0800));
+		assert_eq!(u24::MAX.saturating_truncate_to(12), u24::new(0x0fff).unwrap());
+		assert_eq!(u24::MAX.saturating_truncate_to( 0), u24::MIN);
+		assert_eq!(i24::new(-1).unwrap().saturating_truncate_to( 8), i24::new(0).unwrap());
+		assert_eq!(i24::new(-1).unwrap().saturating_truncate_to(24), i24::new(0).unwrap());
+		assert_eq!(i24::new(-1).unwrap().saturating_truncate_to(40), i24::new(0).unwrap());
+		assert_eq!(i24::MAX.saturating_truncate_to(24), i24::MAX);
+		assert_eq!(i24::new(-1).unwrap().checked_truncate_to( 8), None);
+		assert_eq!(i24::new(-1).unwrap().checked_truncate_to(24), None);
+		assert_eq!(i24::new(-1).unwrap().checked_truncate_to(40), None);
+		assert_eq!(i24::MAX.checked_truncate_to(24), Some(i24::MAX));
+		assert_eq!(i24::MIN.saturating_sign_truncate_to(12), i24::new(-0x0800).unwrap());
+		assert_eq!(i24::MAX.saturating_sign_truncate_to(12), i24::new( 0x07ff).unwrap());
+		assert_eq!(u24::MAX.saturating_sign_truncate_to(12), u24::MAX);
+		assert_eq!(i56::MIN.saturating_sign_truncate_to( 0), i56::new(0).unwrap());
+	}
+}
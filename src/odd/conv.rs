@@ -0,0 +1,536 @@
+//! Conversions between odd-width integers and other integers
+//!
+//! These work the same as between primitives, with every integer
+//! extending into all larger integers and truncating into all smaller ones,
+//! as well as into itself, through the blanket identity implementations.
+
+// Imports
+use super::{i24, i40, i48, i56, u24, u40, u48, u56};
+use crate::{
+	raw::{self, Raw},
+	Const, Extend, SaturatingTruncate, SignExtend, Truncate, TruncateError, TryTruncate, ZeroExtend,
+};
+
+/// Macro to help implement the conversions to the same type
+///
+/// Note: The traits themselves are implemented by their blanket implementations, so
+///       we only implement their `const` versions, and them for `&'a T`.
+macro_rules! impl_identity {
+	($( $T:ty ),+ $(,)?) => {
+		$(
+			impl Const<$T> {
+				/// Zero extends `value` into the same type, returning it
+				///
+				/// This is the `const` version of [`ZeroExtend::zero_extend`].
+				#[must_use]
+				#[inline]
+				pub const fn zero_extend(value: $T) -> $T {
+					value
+				}
+
+				/// Sign extends `value` into the same type, returning it
+				///
+				/// This is the `const` version of [`SignExtend::sign_extend`].
+				#[must_use]
+				#[inline]
+				pub const fn sign_extend(value: $T) -> $T {
+					value
+				}
+
+				/// Extends `value` into the same type, returning it
+				///
+				/// This is the `const` version of [`Extend::extend`].
+				#[must_use]
+				#[inline]
+				pub const fn extend(value: $T) -> $T {
+					value
+				}
+
+				/// Truncates `value` into the same type, returning it
+				///
+				/// This is the `const` version of [`Truncate::truncate`].
+				#[must_use]
+				#[inline]
+				pub const fn truncate(value: $T) -> $T {
+					value
+				}
+
+				/// Truncates `value` into the same type, which always succeeds
+				///
+				/// This is the `const` version of [`TryTruncate::try_truncate`].
+				///
+				/// # Errors
+				/// Never returns an error.
+				#[inline]
+				pub const fn try_truncate(value: $T) -> Result<$T, TruncateError> {
+					Ok(value)
+				}
+
+				/// Truncates `value` into the same type, which never loses information
+				///
+				/// This is the `const` version of [`TryTruncate::overflowing_truncate`].
+				#[must_use]
+				#[inline]
+				pub const fn overflowing_truncate(value: $T) -> ($T, bool) {
+					(value, false)
+				}
+
+				/// Truncates `value` into the same type, returning it
+				///
+				/// This is the `const` version of [`SaturatingTruncate::saturating_truncate`].
+				#[must_use]
+				#[inline]
+				pub const fn saturating_truncate(value: $T) -> $T {
+					value
+				}
+			}
+
+			impl<'a> ZeroExtend<$T> for &'a $T {
+				#[inline]
+				fn zero_extend(self) -> $T {
+					*self
+				}
+			}
+
+			impl<'a> SignExtend<$T> for &'a $T {
+				#[inline]
+				fn sign_extend(self) -> $T {
+					*self
+				}
+			}
+
+			impl<'a> Extend<$T> for &'a $T {
+				#[inline]
+				fn extend(self) -> $T {
+					*self
+				}
+			}
+
+			impl<'a> Truncate<$T> for &'a $T {
+				#[inline]
+				fn truncate(self) -> $T {
+					*self
+				}
+			}
+
+			impl<'a> TryTruncate<$T> for &'a $T {
+				#[inline]
+				fn try_truncate(self) -> Result<$T, TruncateError> {
+					Ok(*self)
+				}
+
+				#[inline]
+				fn overflowing_truncate(self) -> ($T, bool) {
+					(*self, false)
+				}
+			}
+
+			impl<'a> SaturatingTruncate<$T> for &'a $T {
+				#[inline]
+				fn saturating_truncate(self) -> $T {
+					*self
+				}
+			}
+		)+
+	};
+}
+
+/// Macro to help implement [`ZeroExtend`], [`SignExtend`] and [`Extend`]
+///
+/// Note: We also implement these for `&'a T`, by copying the underlying type, like with primitives.
+macro_rules! impl_extend {
+	($T:ty => $( $U:ty ),+ $(,)?) => {
+		$(
+			// Make sure `U` is bigger or equal to `T` so we don't truncate the integer
+			::static_assertions::const_assert!(<$U as raw::Raw>::BITS >= <$T as raw::Raw>::BITS);
+
+			impl Const<$T, $U> {
+				/// Zero extends `value`
+				///
+				/// This is the `const` version of [`ZeroExtend::zero_extend`].
+				#[must_use]
+				#[inline]
+				pub const fn zero_extend(value: $T) -> $U {
+					Const::<$U>::from_raw(Const::<$T>::to_raw(value))
+				}
+
+				/// Sign extends `value`
+				///
+				/// This is the `const` version of [`SignExtend::sign_extend`].
+				#[must_use]
+				#[inline]
+				pub const fn sign_extend(value: $T) -> $U {
+					Const::<$U>::from_raw(raw::sign_extend(Const::<$T>::to_raw(value), <$T as Raw>::BITS))
+				}
+
+				/// Extends `value`
+				///
+				/// This is the `const` version of [`Extend::extend`].
+				#[must_use]
+				#[inline]
+				pub const fn extend(value: $T) -> $U {
+					Const::<$U>::from_raw(raw::extend_raw::<$T>(Const::<$T>::to_raw(value)))
+				}
+			}
+
+			impl ZeroExtend<$U> for $T {
+				#[inline]
+				fn zero_extend(self) -> $U {
+					Const::<$T, $U>::zero_extend(self)
+				}
+			}
+
+			impl SignExtend<$U> for $T {
+				#[inline]
+				fn sign_extend(self) -> $U {
+					Const::<$T, $U>::sign_extend(self)
+				}
+			}
+
+			impl Extend<$U> for $T {
+				#[inline]
+				fn extend(self) -> $U {
+					Const::<$T, $U>::extend(self)
+				}
+			}
+
+			impl<'a> ZeroExtend<$U> for &'a $T {
+				#[inline]
+				fn zero_extend(self) -> $U {
+					<$T as ZeroExtend<$U>>::zero_extend(*self)
+				}
+			}
+
+			impl<'a> SignExtend<$U> for &'a $T {
+				#[inline]
+				fn sign_extend(self) -> $U {
+					<$T as SignExtend<$U>>::sign_extend(*self)
+				}
+			}
+
+			impl<'a> Extend<$U> for &'a $T {
+				#[inline]
+				fn extend(self) -> $U {
+					<$T as Extend<$U>>::extend(*self)
+				}
+			}
+		)+
+	};
+}
+
+/// Macro to help implement [`Truncate`], [`TryTruncate`] and [`SaturatingTruncate`]
+///
+/// Note: We also implement these for `&'a T`, by copying the underlying type, like with primitives.
+macro_rules! impl_truncate {
+	($T:ty => $( $U:ty ),+ $(,)?) => {
+		$(
+			// Make sure `U` is smaller or equal to `T` so we don't extend the integer
+			::static_assertions::const_assert!(<$U as raw::Raw>::BITS <= <$T as raw::Raw>::BITS);
+
+			impl Const<$T, $U> {
+				/// Truncates `value`
+				///
+				/// This is the `const` version of [`Truncate::truncate`].
+				#[must_use]
+				#[inline]
+				pub const fn truncate(value: $T) -> $U {
+					Const::<$U>::from_raw(Const::<$T>::to_raw(value))
+				}
+
+				/// Truncates `value`, if it fits
+				///
+				/// This is the `const` version of [`TryTruncate::try_truncate`].
+				///
+				/// # Errors
+				/// Returns [`TruncateError`] if the value doesn't fit.
+				#[inline]
+				pub const fn try_truncate(value: $T) -> Result<$U, TruncateError> {
+					if raw::fits_raw::<$T, $U>(Const::<$T>::to_raw(value)) {
+						Ok(Const::<$T, $U>::truncate(value))
+					} else {
+						Err(TruncateError)
+					}
+				}
+
+				/// Truncates `value`, returning if any information was lost
+				///
+				/// This is the `const` version of [`TryTruncate::overflowing_truncate`].
+				#[must_use]
+				#[inline]
+				pub const fn overflowing_truncate(value: $T) -> ($U, bool) {
+					let truncated = Const::<$T, $U>::truncate(value);
+					let overflowed = !raw::fits_raw::<$T, $U>(Const::<$T>::to_raw(value));
+					(truncated, overflowed)
+				}
+
+				/// Truncates `value`, clamping it to the range of the smaller integer
+				///
+				/// This is the `const` version of [`SaturatingTruncate::saturating_truncate`].
+				#[must_use]
+				#[inline]
+				pub const fn saturating_truncate(value: $T) -> $U {
+					Const::<$U>::from_raw(raw::saturate_raw::<$T, $U>(Const::<$T>::to_raw(value)))
+				}
+			}
+
+			impl Truncate<$U> for $T {
+				#[inline]
+				fn truncate(self) -> $U {
+					Const::<$T, $U>::truncate(self)
+				}
+			}
+
+			impl TryTruncate<$U> for $T {
+				#[inline]
+				fn try_truncate(self) -> Result<$U, TruncateError> {
+					Const::<$T, $U>::try_truncate(self)
+				}
+
+				#[inline]
+				fn overflowing_truncate(self) -> ($U, bool) {
+					Const::<$T, $U>::overflowing_truncate(self)
+				}
+			}
+
+			impl SaturatingTruncate<$U> for $T {
+				#[inline]
+				fn saturating_truncate(self) -> $U {
+					Const::<$T, $U>::saturating_truncate(self)
+				}
+			}
+
+			impl<'a> Truncate<$U> for &'a $T {
+				#[inline]
+				fn truncate(self) -> $U {
+					<$T as Truncate<$U>>::truncate(*self)
+				}
+			}
+
+			impl<'a> TryTruncate<$U> for &'a $T {
+				#[inline]
+				fn try_truncate(self) -> Result<$U, TruncateError> {
+					<$T as TryTruncate<$U>>::try_truncate(*self)
+				}
+
+				#[inline]
+				fn overflowing_truncate(self) -> ($U, bool) {
+					<$T as TryTruncate<$U>>::overflowing_truncate(*self)
+				}
+			}
+
+			impl<'a> SaturatingTruncate<$U> for &'a $T {
+				#[inline]
+				fn saturating_truncate(self) -> $U {
+					<$T as SaturatingTruncate<$U>>::saturating_truncate(*self)
+				}
+			}
+		)+
+	};
+}
+
+// Identity
+impl_identity! { u24, u40, u48, u56 }
+impl_identity! { i24, i40, i48, i56 }
+
+// Extension
+
+// Unsigned
+impl_extend! { u8   => u24, u40, u48, u56, i24, i40, i48, i56 }
+impl_extend! { u16  => u24, u40, u48, u56, i24, i40, i48, i56 }
+impl_extend! { u24  => u32, u40, u48, u56, u64, u128, i32, i40, i48, i56, i64, i128 }
+impl_extend! { u32  => u40, u48, u56, i40, i48, i56 }
+impl_extend! { u40  => u48, u56, u64, u128, i48, i56, i64, i128 }
+impl_extend! { u48  => u56, u64, u128, i56, i64, i128 }
+impl_extend! { u56  => u64, u128, i64, i128 }
+
+// Signed
+impl_extend! { i8   => u24, u40, u48, u56, i24, i40, i48, i56 }
+impl_extend! { i16  => u24, u40, u48, u56, i24, i40, i48, i56 }
+impl_extend! { i24  => u32, u40, u48, u56, u64, u128, i32, i40, i48, i56, i64, i128 }
+impl_extend! { i32  => u40, u48, u56, i40, i48, i56 }
+impl_extend! { i40  => u48, u56, u64, u128, i48, i56, i64, i128 }
+impl_extend! { i48  => u56, u64, u128, i56, i64, i128 }
+impl_extend! { i56  => u64, u128, i64, i128 }
+
+// Pointer-sized
+#[cfg(target_pointer_width = "16")]
+impl_extend! { usize => u24, u40, u48, u56, i24, i40, i48, i56 }
+#[cfg(target_pointer_width = "16")]
+impl_extend! { isize => u24, u40, u48, u56, i24, i40, i48, i56 }
+#[cfg(target_pointer_width = "32")]
+impl_extend! { usize => u40, u48, u56, i40, i48, i56 }
+#[cfg(target_pointer_width = "32")]
+impl_extend! { isize => u40, u48, u56, i40, i48, i56 }
+#[cfg(target_pointer_width = "32")]
+impl_extend! { u24   => usize, isize }
+#[cfg(target_pointer_width = "32")]
+impl_extend! { i24   => usize, isize }
+#[cfg(target_pointer_width = "64")]
+impl_extend! { u24   => usize, isize }
+#[cfg(target_pointer_width = "64")]
+impl_extend! { u40   => usize, isize }
+#[cfg(target_pointer_width = "64")]
+impl_extend! { u48   => usize, isize }
+#[cfg(target_pointer_width = "64")]
+impl_extend! { u56   => usize, isize }
+#[cfg(target_pointer_width = "64")]
+impl_extend! { i24   => usize, isize }
+#[cfg(target_pointer_width = "64")]
+impl_extend! { i40   => usize, isize }
+#[cfg(target_pointer_width = "64")]
+impl_extend! { i48   => usize, isize }
+#[cfg(target_pointer_width = "64")]
+impl_extend! { i56   => usize, isize }
+
+// Truncation
+
+// Unsigned
+impl_truncate! { u24  => u8, u16, i8, i16 }
+impl_truncate! { u32  => u24, i24 }
+impl_truncate! { u40  => u8, u16, u24, u32, i8, i16, i24, i32 }
+impl_truncate! { u48  => u8, u16, u24, u32, u40, i8, i16, i24, i32, i40 }
+impl_truncate! { u56  => u8, u16, u24, u32, u40, u48, i8, i16, i24, i32, i40, i48 }
+impl_truncate! { u64  => u24, u40, u48, u56, i24, i40, i48, i56 }
+impl_truncate! { u128 => u24, u40, u48, u56, i24, i40, i48, i56 }
+
+// Signed
+impl_truncate! { i24  => u8, u16, i8, i16 }
+impl_truncate! { i32  => u24, i24 }
+impl_truncate! { i40  => u8, u16, u24, u32, i8, i16, i24, i32 }
+impl_truncate! { i48  => u8, u16, u24, u32, u40, i8, i16, i24, i32, i40 }
+impl_truncate! { i56  => u8, u16, u24, u32, u40, u48, i8, i16, i24, i32, i40, i48 }
+impl_truncate! { i64  => u24, u40, u48, u56, i24, i40, i48, i56 }
+impl_truncate! { i128 => u24, u40, u48, u56, i24, i40, i48, i56 }
+
+// Pointer-sized
+#[cfg(target_pointer_width = "16")]
+impl_truncate! { u24   => usize, isize }
+#[cfg(target_pointer_width = "16")]
+impl_truncate! { u40   => usize, isize }
+#[cfg(target_pointer_width = "16")]
+impl_truncate! { u48   => usize, isize }
+#[cfg(target_pointer_width = "16")]
+impl_truncate! { u56   => usize, isize }
+#[cfg(target_pointer_width = "16")]
+impl_truncate! { i24   => usize, isize }
+#[cfg(target_pointer_width = "16")]
+impl_truncate! { i40   => usize, isize }
+#[cfg(target_pointer_width = "16")]
+impl_truncate! { i48   => usize, isize }
+#[cfg(target_pointer_width = "16")]
+impl_truncate! { i56   => usize, isize }
+#[cfg(target_pointer_width = "32")]
+impl_truncate! { usize => u24, i24 }
+#[cfg(target_pointer_width = "32")]
+impl_truncate! { isize => u24, i24 }
+#[cfg(target_pointer_width = "32")]
+impl_truncate! { u40   => usize, isize }
+#[cfg(target_pointer_width = "32")]
+impl_truncate! { u48   => usize, isize }
+#[cfg(target_pointer_width = "32")]
+impl_truncate! { u56   => usize, isize }
+#[cfg(target_pointer_width = "32")]
+impl_truncate! { i40   => usize, isize }
+#[cfg(target_pointer_width = "32")]
+impl_truncate! { i48   => usize, isize }
+#[cfg(target_pointer_width = "32")]
+impl_truncate! { i56   => usize, isize }
+#[cfg(target_pointer_width = "64")]
+impl_truncate! { usize => u24, u40, u48, u56, i24, i40, i48, i56 }
+#[cfg(target_pointer_width = "64")]
+impl_truncate! { isize => u24, u40, u48, u56, i24, i40, i48, i56 }
+
+// Check that some of the impls exist
+static_assertions::assert_impl_all! { u24: ZeroExtend<u32>, SignExtend<u32>, Extend<u32>, ZeroExtend<i32>, ZeroExtend<u48>, ZeroExtend<u128> }
+static_assertions::assert_impl_all! { i24: ZeroExtend<i32>, SignExtend<i32>, Extend<i32>, SignExtend<u32>, SignExtend<i48>, SignExtend<i128> }
+static_assertions::assert_impl_all! { u32: Truncate<u24>, TryTruncate<u24>, SaturatingTruncate<u24>, Truncate<i24> }
+static_assertions::assert_impl_all! { u48: Truncate<u24>, Truncate<u40>, Truncate<u32>, Truncate<u8>, ZeroExtend<u56>, ZeroExtend<u64> }
+static_assertions::assert_impl_all! { u8 : ZeroExtend<u24>, SignExtend<i24>, ZeroExtend<u56>, SignExtend<i56> }
+static_assertions::assert_not_impl_any! { u24: ZeroExtend<i24>, ZeroExtend<u16>, Truncate<i24>, Truncate<u32> }
+static_assertions::assert_not_impl_any! { u32: ZeroExtend<u24>, Truncate<u40> }
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use super::*;
+	use crate::{Extended, SaturatingTruncated, SignExtended, Truncated, TryTruncated, ZeroExtended};
+
+	#[test]
+	#[rustfmt::skip]
+	fn extend() {
+		assert_eq!(u24::MAX.zero_extended::<u32>(), 0x00ff_ffff);
+		assert_eq!(u24::MAX.sign_extended::<u32>(), u32::MAX);
+		assert_eq!(u24::MAX.extended::<i32>(), 0x00ff_ffff);
+		assert_eq!(i24::MIN.extended::<i64>(), -0x0080_0000);
+		assert_eq!(i24::MIN.zero_extended::<i64>(), 0x0080_0000);
+		assert_eq!(i24::MIN.extended::<i48>(), i48::new(-0x0080_0000).unwrap());
+		assert_eq!((-1i8).extended::<i24>(), i24::new(-1).unwrap());
+		assert_eq!((-1i8).zero_extended::<u40>(), u40::new(0xff).unwrap());
+		assert_eq!(u16::MAX.sign_extended::<i56>(), i56::new(-1).unwrap());
+		assert_eq!((&u48::MAX).zero_extended::<u64>(), 0xffff_ffff_ffff);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn truncate() {
+		assert_eq!(0x1234_5678u32.truncated::<u24>(), u24::new(0x0034_5678).unwrap());
+		assert_eq!(0x1280_0000u32.truncated::<i24>(), i24::MIN);
+		assert_eq!(u48::MAX.truncated::<u24>(), u24::MAX);
+		assert_eq!(i40::new(-2).unwrap().truncated::<u8>(), 0xfe);
+		assert_eq!(i40::new(-2).unwrap().truncated::<i32>(), -2);
+		assert_eq!((&u56::MAX).truncated::<u16>(), u16::MAX);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn try_truncate() {
+		assert_eq!(0x00ff_ffffu32.try_truncated::<u24>(), Ok(u24::MAX));
+		assert_eq!(0x0100_0000u32.try_truncated::<u24>(), Err(TruncateError));
+		assert_eq!(0x007f_ffffu32.try_truncated::<i24>(), Ok(i24::MAX));
+		assert_eq!(0x0080_0000u32.try_truncated::<i24>(), Err(TruncateError));
+		assert_eq!((-0x0080_0000i32).try_truncated::<i24>(), Ok(i24::MIN));
+		assert_eq!((-1i32).try_truncated::<u24>(), Err(TruncateError));
+		assert_eq!(i24::new(-1).unwrap().try_truncated::<u8>(), Err(TruncateError));
+		assert_eq!(i24::new(-1).unwrap().try_truncated::<i8>(), Ok(-1));
+		assert_eq!(u24::MAX.overflowing_truncated::<u16>(), (u16::MAX, true));
+		assert_eq!(u48::MAX.overflowing_truncated::<u40>(), (u40::MAX, true));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn saturating_truncate() {
+		assert_eq!(u32::MAX.saturating_truncated::<u24>(), u24::MAX);
+		assert_eq!(u32::MAX.saturating_truncated::<i24>(), i24::MAX);
+		assert_eq!(i32::MIN.saturating_truncated::<i24>(), i24::MIN);
+		assert_eq!(i32::MIN.saturating_truncated::<u24>(), u24::MIN);
+		assert_eq!(i48::MIN.saturating_truncated::<i8>(), i8::MIN);
+		assert_eq!(i48::new(5).unwrap().saturating_truncated::<u24>(), u24::new(5).unwrap());
+	}
+
+	#[test]
+	#[cfg(target_pointer_width = "64")]
+	fn pointer_sized() {
+		assert_eq!(u48::MAX.zero_extended::<usize>(), 0xffff_ffff_ffff);
+		assert_eq!(i24::MIN.extended::<isize>(), -0x0080_0000);
+		assert_eq!(usize::MAX.truncated::<u40>(), u40::MAX);
+		assert_eq!(isize::MIN.saturating_truncated::<i56>(), i56::MIN);
+	}
+
+	#[test]
+	#[cfg(target_pointer_width = "32")]
+	fn pointer_sized() {
+		assert_eq!(u24::MAX.zero_extended::<usize>(), 0x00ff_ffff);
+		assert_eq!(i24::MIN.extended::<isize>(), -0x0080_0000);
+		assert_eq!(usize::MAX.truncated::<u24>(), u24::MAX);
+		assert_eq!(u48::MAX.saturating_truncated::<usize>(), usize::MAX);
+	}
+
+	#[test]
+	#[cfg(target_pointer_width = "16")]
+	fn pointer_sized() {
+		assert_eq!(u24::MAX.truncated::<usize>(), usize::MAX);
+		assert_eq!(i24::MIN.saturating_truncated::<isize>(), isize::MIN);
+		assert_eq!(usize::MAX.zero_extended::<u24>(), u24::new(0xffff).unwrap());
+		assert_eq!((-1isize).extended::<i40>(), i40::new(-1).unwrap());
+	}
+}
@@ -0,0 +1,94 @@
+//! Formatting for odd-width integers
+
+// Imports
+use super::{i24, i40, i48, i56, u24, u40, u48, u56};
+use crate::Signed;
+use core::fmt;
+
+/// Macro to help implement formatting
+///
+/// Note: Signed integers are formatted as unsigned for hexadecimal, octal and binary,
+///       like primitives, so only the bits of the integer are shown.
+macro_rules! impl_fmt {
+	(- $TSigned:ty : + $TUnsigned:ty) => {
+		impl_fmt! { @inner $TSigned, fmt::Debug, fmt::Display }
+		impl_fmt! { @inner $TUnsigned, fmt::Debug, fmt::Display, fmt::LowerHex, fmt::UpperHex, fmt::Octal, fmt::Binary }
+		impl_fmt! { @unsigned $TSigned, fmt::LowerHex, fmt::UpperHex, fmt::Octal, fmt::Binary }
+	};
+
+	(@inner $T:ty, $( $Trait:path ),+) => {
+		$(
+			impl $Trait for $T {
+				#[inline]
+				fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+					<_ as $Trait>::fmt(&self.0, f)
+				}
+			}
+		)+
+	};
+
+	(@unsigned $T:ty, $( $Trait:path ),+) => {
+		$(
+			impl $Trait for $T {
+				#[inline]
+				fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+					<_ as $Trait>::fmt(&self.as_unsigned(), f)
+				}
+			}
+		)+
+	};
+}
+
+impl_fmt! { -i24 : +u24 }
+impl_fmt! { -i40 : +u40 }
+impl_fmt! { -i48 : +u48 }
+impl_fmt! { -i56 : +u56 }
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use super::*;
+	use core::fmt::Write;
+
+	/// Buffer to format into, as we don't have `alloc`
+	struct Buffer {
+		bytes: [u8; 64],
+		len: usize,
+	}
+
+	impl Buffer {
+		/// Formats `args` into a new buffer
+		fn format(args: fmt::Arguments) -> Self {
+			let mut buffer = Self { bytes: [0; 64], len: 0 };
+			buffer.write_fmt(args).expect("Unable to format");
+			buffer
+		}
+
+		/// Returns the formatted string
+		fn as_str(&self) -> &str {
+			core::str::from_utf8(&self.bytes[..self.len]).expect("Formatted string wasn't utf-8")
+		}
+	}
+
+	impl Write for Buffer {
+		fn write_str(&mut self, s: &str) -> fmt::Result {
+			let bytes = self.bytes.get_mut(self.len..self.len + s.len()).ok_or(fmt::Error)?;
+			bytes.copy_from_slice(s.as_bytes());
+			self.len += s.len();
+			Ok(())
+		}
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn format() {
+		assert_eq!(Buffer::format(format_args!("{}"    , u24::MAX)).as_str(), "16777215");
+		assert_eq!(Buffer::format(format_args!("{:?}"  , i24::MIN)).as_str(), "-8388608");
+		assert_eq!(Buffer::format(format_args!("{:x}"  , u24::MAX)).as_str(), "ffffff");
+		assert_eq!(Buffer::format(format_args!("{:X}"  , i24::new(-1).unwrap())).as_str(), "FFFFFF");
+		assert_eq!(Buffer::format(format_args!("{:o}"  , i24::new(-1).unwrap())).as_str(), "77777777");
+		assert_eq!(Buffer::format(format_args!("{:#b}" , u40::new(5).unwrap())).as_str(), "0b101");
+		assert_eq!(Buffer::format(format_args!("{:08x}", i48::new(-2).unwrap())).as_str(), "fffffffffffe");
+		assert_eq!(Buffer::format(format_args!("{:>6}" , i56::new(-42).unwrap())).as_str(), "   -42");
+	}
+}
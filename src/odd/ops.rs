@@ -0,0 +1,404 @@
+//! Arithmetic for odd-width integers
+//!
+//! Operators behave the same as with primitives: They panic on overflow
+//! when debug assertions are enabled, and otherwise wrap around.
+
+// Imports
+use super::{i24, i40, i48, i56, u24, u40, u48, u56};
+use crate::raw::Raw;
+use core::ops::{
+	Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign, Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl,
+	ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+};
+
+/// Macro to help implement the arithmetic methods common to signed and unsigned integers
+macro_rules! impl_arith {
+	($T:ty : $Inner:ty) => {
+		impl $T {
+			/// Wraps `value` into this integer, returning if it didn't fit
+			#[inline]
+			fn overflowing_from(value: $Inner, overflow: bool) -> (Self, bool) {
+				let wrapped = <$T>::from_raw(value.to_raw());
+				(wrapped, overflow || wrapped.0 != value)
+			}
+
+			/// Calculates `self + rhs`, returning if an overflow occurred
+			#[must_use]
+			#[inline]
+			pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+				let (value, overflow) = self.0.overflowing_add(rhs.0);
+				Self::overflowing_from(value, overflow)
+			}
+
+			/// Calculates `self - rhs`, returning if an overflow occurred
+			#[must_use]
+			#[inline]
+			pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+				let (value, overflow) = self.0.overflowing_sub(rhs.0);
+				Self::overflowing_from(value, overflow)
+			}
+
+			/// Calculates `self * rhs`, returning if an overflow occurred
+			#[must_use]
+			#[inline]
+			pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+				let (value, overflow) = self.0.overflowing_mul(rhs.0);
+				Self::overflowing_from(value, overflow)
+			}
+
+			/// Calculates `self + rhs`, wrapping around on overflow
+			#[must_use]
+			#[inline]
+			pub fn wrapping_add(self, rhs: Self) -> Self {
+				self.overflowing_add(rhs).0
+			}
+
+			/// Calculates `self - rhs`, wrapping around on overflow
+			#[must_use]
+			#[inline]
+			pub fn wrapping_sub(self, rhs: Self) -> Self {
+				self.overflowing_sub(rhs).0
+			}
+
+			/// Calculates `self * rhs`, wrapping around on overflow
+			#[must_use]
+			#[inline]
+			pub fn wrapping_mul(self, rhs: Self) -> Self {
+				self.overflowing_mul(rhs).0
+			}
+
+			/// Calculates `self + rhs`, returning `None` on overflow
+			#[must_use]
+			#[inline]
+			pub fn checked_add(self, rhs: Self) -> Option<Self> {
+				match self.overflowing_add(rhs) {
+					(value, false) => Some(value),
+					(_, true) => None,
+				}
+			}
+
+			/// Calculates `self - rhs`, returning `None` on overflow
+			#[must_use]
+			#[inline]
+			pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+				match self.overflowing_sub(rhs) {
+					(value, false) => Some(value),
+					(_, true) => None,
+				}
+			}
+
+			/// Calculates `self * rhs`, returning `None` on overflow
+			#[must_use]
+			#[inline]
+			pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+				match self.overflowing_mul(rhs) {
+					(value, false) => Some(value),
+					(_, true) => None,
+				}
+			}
+		}
+
+		impl_arith! { @op $T, Add, add, AddAssign, add_assign, overflowing_add, "attempt to add with overflow" }
+		impl_arith! { @op $T, Sub, sub, SubAssign, sub_assign, overflowing_sub, "attempt to subtract with overflow" }
+		impl_arith! { @op $T, Mul, mul, MulAssign, mul_assign, overflowing_mul, "attempt to multiply with overflow" }
+
+		impl_arith! { @bit $T, BitAnd, bitand, BitAndAssign, bitand_assign, & }
+		impl_arith! { @bit $T, BitOr , bitor , BitOrAssign , bitor_assign , | }
+		impl_arith! { @bit $T, BitXor, bitxor, BitXorAssign, bitxor_assign, ^ }
+
+		impl Not for $T {
+			type Output = Self;
+
+			#[inline]
+			fn not(self) -> Self::Output {
+				<$T>::from_raw(!self.to_raw())
+			}
+		}
+
+		impl Shl<u32> for $T {
+			type Output = Self;
+
+			#[inline]
+			fn shl(self, rhs: u32) -> Self::Output {
+				if cfg!(debug_assertions) && rhs >= Self::BITS {
+					panic!("attempt to shift left with overflow");
+				}
+
+				<$T>::from_raw(self.to_raw() << (rhs % Self::BITS))
+			}
+		}
+
+		impl Shr<u32> for $T {
+			type Output = Self;
+
+			#[inline]
+			fn shr(self, rhs: u32) -> Self::Output {
+				if cfg!(debug_assertions) && rhs >= Self::BITS {
+					panic!("attempt to shift right with overflow");
+				}
+
+				// Note: For signed integers, this shift is arithmetic, so the sign is kept
+				Self(self.0 >> (rhs % Self::BITS))
+			}
+		}
+
+		impl ShlAssign<u32> for $T {
+			#[inline]
+			fn shl_assign(&mut self, rhs: u32) {
+				*self = *self << rhs;
+			}
+		}
+
+		impl ShrAssign<u32> for $T {
+			#[inline]
+			fn shr_assign(&mut self, rhs: u32) {
+				*self = *self >> rhs;
+			}
+		}
+
+		impl DivAssign for $T {
+			#[inline]
+			fn div_assign(&mut self, rhs: Self) {
+				*self = *self / rhs;
+			}
+		}
+
+		impl RemAssign for $T {
+			#[inline]
+			fn rem_assign(&mut self, rhs: Self) {
+				*self = *self % rhs;
+			}
+		}
+	};
+
+	(@op $T:ty, $Trait:ident, $fn:ident, $TraitAssign:ident, $fn_assign:ident, $overflowing:ident, $msg:literal) => {
+		impl $Trait for $T {
+			type Output = Self;
+
+			#[inline]
+			fn $fn(self, rhs: Self) -> Self::Output {
+				let (value, overflow) = self.$overflowing(rhs);
+				if cfg!(debug_assertions) && overflow {
+					panic!($msg);
+				}
+
+				value
+			}
+		}
+
+		impl $TraitAssign for $T {
+			#[inline]
+			fn $fn_assign(&mut self, rhs: Self) {
+				*self = $Trait::$fn(*self, rhs);
+			}
+		}
+	};
+
+	(@bit $T:ty, $Trait:ident, $fn:ident, $TraitAssign:ident, $fn_assign:ident, $op:tt) => {
+		impl $Trait for $T {
+			type Output = Self;
+
+			#[inline]
+			fn $fn(self, rhs: Self) -> Self::Output {
+				// Note: Both integers are in range, so the result will also be
+				Self(self.0 $op rhs.0)
+			}
+		}
+
+		impl $TraitAssign for $T {
+			#[inline]
+			fn $fn_assign(&mut self, rhs: Self) {
+				*self = $Trait::$fn(*self, rhs);
+			}
+		}
+	};
+}
+
+/// Macro to help implement the arithmetic of unsigned integers
+macro_rules! impl_arith_unsigned {
+	($T:ty : $Inner:ty) => {
+		impl_arith! { $T : $Inner }
+
+		impl Div for $T {
+			type Output = Self;
+
+			#[inline]
+			fn div(self, rhs: Self) -> Self::Output {
+				Self(self.0 / rhs.0)
+			}
+		}
+
+		impl Rem for $T {
+			type Output = Self;
+
+			#[inline]
+			fn rem(self, rhs: Self) -> Self::Output {
+				Self(self.0 % rhs.0)
+			}
+		}
+	};
+}
+
+/// Macro to help implement the arithmetic of signed integers
+macro_rules! impl_arith_signed {
+	($T:ty : $Inner:ty) => {
+		impl_arith! { $T : $Inner }
+
+		impl $T {
+			/// Calculates `-self`, returning if an overflow occurred
+			#[must_use]
+			#[inline]
+			pub fn overflowing_neg(self) -> (Self, bool) {
+				Self(0).overflowing_sub(self)
+			}
+
+			/// Calculates `-self`, wrapping around on overflow
+			#[must_use]
+			#[inline]
+			pub fn wrapping_neg(self) -> Self {
+				self.overflowing_neg().0
+			}
+
+			/// Calculates `-self`, returning `None` on overflow
+			#[must_use]
+			#[inline]
+			pub fn checked_neg(self) -> Option<Self> {
+				match self.overflowing_neg() {
+					(value, false) => Some(value),
+					(_, true) => None,
+				}
+			}
+		}
+
+		impl Neg for $T {
+			type Output = Self;
+
+			#[inline]
+			fn neg(self) -> Self::Output {
+				let (value, overflow) = self.overflowing_neg();
+				if cfg!(debug_assertions) && overflow {
+					panic!("attempt to negate with overflow");
+				}
+
+				value
+			}
+		}
+
+		impl Div for $T {
+			type Output = Self;
+
+			#[inline]
+			fn div(self, rhs: Self) -> Self::Output {
+				// Note: Like primitives, this overflow always panics
+				if self == Self::MIN && rhs.0 == -1 {
+					panic!("attempt to divide with overflow");
+				}
+
+				Self(self.0 / rhs.0)
+			}
+		}
+
+		impl Rem for $T {
+			type Output = Self;
+
+			#[inline]
+			fn rem(self, rhs: Self) -> Self::Output {
+				// Note: Like primitives, this overflow always panics
+				if self == Self::MIN && rhs.0 == -1 {
+					panic!("attempt to calculate the remainder with overflow");
+				}
+
+				Self(self.0 % rhs.0)
+			}
+		}
+	};
+}
+
+impl_arith_unsigned! { u24 : u32 }
+impl_arith_unsigned! { u40 : u64 }
+impl_arith_unsigned! { u48 : u64 }
+impl_arith_unsigned! { u56 : u64 }
+impl_arith_signed! { i24 : i32 }
+impl_arith_signed! { i40 : i64 }
+impl_arith_signed! { i48 : i64 }
+impl_arith_signed! { i56 : i64 }
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use super::*;
+
+	#[test]
+	#[rustfmt::skip]
+	fn overflowing() {
+		assert_eq!(u24::MAX.overflowing_add(u24::new(1).unwrap()), (u24::MIN, true));
+		assert_eq!(u24::MIN.overflowing_sub(u24::new(1).unwrap()), (u24::MAX, true));
+		assert_eq!(u24::MAX.overflowing_mul(u24::MAX), (u24::new(1).unwrap(), true));
+		assert_eq!(u56::MAX.overflowing_mul(u56::MAX), (u56::new(1).unwrap(), true));
+		assert_eq!(i24::MAX.overflowing_add(i24::new(1).unwrap()), (i24::MIN, true));
+		assert_eq!(i24::MIN.overflowing_neg(), (i24::MIN, true));
+		assert_eq!(i56::MIN.overflowing_mul(i56::new(-1).unwrap()), (i56::MIN, true));
+		assert_eq!(u40::new(2).unwrap().overflowing_mul(u40::new(3).unwrap()), (u40::new(6).unwrap(), false));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn checked() {
+		assert_eq!(u24::MAX.checked_add(u24::new(1).unwrap()), None);
+		assert_eq!(u24::MAX.checked_sub(u24::new(1).unwrap()), u24::new(0x00ff_fffe));
+		assert_eq!(i48::MIN.checked_neg(), None);
+		assert_eq!(i48::MAX.checked_neg(), i48::new(-0x7fff_ffff_ffff));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn operators() {
+		let a = i24::new(-100).unwrap();
+		let b = i24::new(7).unwrap();
+		assert_eq!( a + b, i24::new(-93).unwrap());
+		assert_eq!( a - b, i24::new(-107).unwrap());
+		assert_eq!( a * b, i24::new(-700).unwrap());
+		assert_eq!( a / b, i24::new(-14).unwrap());
+		assert_eq!( a % b, i24::new(-2).unwrap());
+		assert_eq!(-a    , i24::new(100).unwrap());
+		assert_eq!(!a    , i24::new(99).unwrap());
+		assert_eq!( a & b, i24::new(4).unwrap());
+		assert_eq!( a | b, i24::new(-97).unwrap());
+		assert_eq!( a ^ b, i24::new(-101).unwrap());
+		assert_eq!( a >> 2, i24::new(-25).unwrap());
+		assert_eq!( a << 2, i24::new(-400).unwrap());
+		assert_eq!(i24::MAX << 1, i24::new(-2).unwrap());
+		assert_eq!(!u24::MIN, u24::MAX);
+		assert_eq!(u24::MAX << 4, u24::new(0x00ff_fff0).unwrap());
+		assert_eq!(u24::MAX >> 4, u24::new(0x000f_ffff).unwrap());
+	}
+
+	#[test]
+	fn assign() {
+		let mut value = u48::new(10).unwrap();
+		value += u48::new(5).unwrap();
+		value *= u48::new(3).unwrap();
+		value -= u48::new(1).unwrap();
+		value /= u48::new(2).unwrap();
+		value %= u48::new(5).unwrap();
+		value <<= 3;
+		value >>= 1;
+		value |= u48::new(1).unwrap();
+		value &= u48::new(3).unwrap();
+		value ^= u48::new(2).unwrap();
+		assert_eq!(value, u48::new(3).unwrap());
+	}
+
+	#[test]
+	#[should_panic = "attempt to add with overflow"]
+	#[cfg(debug_assertions)]
+	fn add_overflow() {
+		let _ = u24::MAX + u24::new(1).unwrap();
+	}
+
+	#[test]
+	#[should_panic = "attempt to divide with overflow"]
+	fn div_overflow() {
+		let _ = i24::MIN / i24::new(-1).unwrap();
+	}
+}
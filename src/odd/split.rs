@@ -0,0 +1,245 @@
+//! Splitting and joining odd-width integers
+
+// Imports
+use super::{i24, i40, i48, i56, u24, u40, u48, u56};
+use crate::{consts, raw::Raw, Const, Join, JoinFrom, Split, SplitInto, SplitUnits};
+
+/// Macro to help implement `Split` / `Join`
+macro_rules! impl_split_join {
+	($T:ty => $Hi:ty : $Lo:ty) => {
+		// Make sure that `T` is made up of `Lo` and `Hi`
+		::static_assertions::const_assert_eq!(<$T as Raw>::BITS, <$Lo as Raw>::BITS + <$Hi as Raw>::BITS);
+
+		impl Const<$T> {
+			/// Returns the high part of `value`
+			///
+			/// This is the `const` version of [`Split::hi`].
+			#[must_use]
+			#[inline]
+			pub const fn hi(value: $T) -> $Hi {
+				Const::<$Hi>::from_raw(Const::<$T>::to_raw(value) >> <$Lo as Raw>::BITS)
+			}
+
+			/// Returns the low part of `value`
+			///
+			/// This is the `const` version of [`Split::lo`].
+			#[must_use]
+			#[inline]
+			pub const fn lo(value: $T) -> $Lo {
+				Const::<$Lo>::from_raw(Const::<$T>::to_raw(value))
+			}
+
+			/// Returns the low and high part of `value`
+			///
+			/// This is the `const` version of [`Split::lo_hi`].
+			#[must_use]
+			#[inline]
+			pub const fn lo_hi(value: $T) -> ($Lo, $Hi) {
+				(Const::<$T>::lo(value), Const::<$T>::hi(value))
+			}
+
+			/// Joins the low and high part of an integer
+			///
+			/// This is the `const` version of [`Join::join`].
+			#[must_use]
+			#[inline]
+			pub const fn join(lo: $Lo, hi: $Hi) -> $T {
+				Const::<$T>::from_raw(Const::<$Lo>::to_raw(lo) | Const::<$Hi>::to_raw(hi) << <$Lo as Raw>::BITS)
+			}
+		}
+
+		impl Split for $T {
+			type Hi = $Hi;
+			type Lo = $Lo;
+
+			#[inline]
+			fn lo(self) -> Self::Lo {
+				Const::<$T>::lo(self)
+			}
+
+			#[inline]
+			fn hi(self) -> Self::Hi {
+				Const::<$T>::hi(self)
+			}
+
+			#[inline]
+			fn lo_hi(self) -> (Self::Lo, Self::Hi) {
+				Const::<$T>::lo_hi(self)
+			}
+		}
+
+		impl Join for $T {
+			#[inline]
+			fn join(lo: <Self as Split>::Lo, hi: <Self as Split>::Hi) -> Self {
+				Const::<$T>::join(lo, hi)
+			}
+		}
+	};
+}
+
+/// Macro to help implement `SplitInto` / `JoinFrom` / `SplitUnits`
+macro_rules! impl_split_into_join_from {
+	($T:ty => $( [$U:ty; $N:literal] ),+ $(,)?) => {
+		$(
+			// Make sure that `T` is made up of `N` `U`s
+			::static_assertions::const_assert_eq!(<$T as Raw>::BITS, <$U as Raw>::BITS * $N);
+
+			impl Const<$T, [$U; $N]> {
+				/// Splits `value` into it's parts, from least to most significant
+				///
+				/// This is the `const` version of [`SplitInto::split_into`].
+				#[must_use]
+				#[inline]
+				pub const fn split_into(value: $T) -> [$U; $N] {
+					let mut parts = [Const::<$U>::from_raw(0); $N];
+					let mut idx = 0;
+					let mut shift = 0;
+					while idx < $N {
+						parts[idx] = Const::<$U>::from_raw(Const::<$T>::to_raw(value) >> shift);
+						idx += 1;
+						shift += <$U as Raw>::BITS;
+					}
+					parts
+				}
+
+				/// Joins the parts of an integer, from least to most significant
+				///
+				/// This is the `const` version of [`JoinFrom::join_from`].
+				#[must_use]
+				#[inline]
+				pub const fn join_from(parts: [$U; $N]) -> $T {
+					let mut raw = 0;
+					let mut idx = 0;
+					let mut shift = 0;
+					while idx < $N {
+						raw |= Const::<$U>::to_raw(parts[idx]) << shift;
+						idx += 1;
+						shift += <$U as Raw>::BITS;
+					}
+					Const::<$T>::from_raw(raw)
+				}
+			}
+
+			impl Const<$T, $U> {
+				/// Splits `value` into units of `U`, least significant first
+				///
+				/// This is the `const` version of [`SplitEndian::split_le`](crate::SplitEndian::split_le).
+				#[must_use]
+				#[inline]
+				pub const fn split_le(value: $T) -> [$U; $N] {
+					Const::<$T, [$U; $N]>::split_into(value)
+				}
+
+				/// Splits `value` into units of `U`, most significant first
+				///
+				/// This is the `const` version of [`SplitEndian::split_be`](crate::SplitEndian::split_be).
+				#[must_use]
+				#[inline]
+				pub const fn split_be(value: $T) -> [$U; $N] {
+					consts::reverse_units!(Const::<$T, $U>::split_le(value))
+				}
+
+				/// Joins units of `U`, least significant first
+				///
+				/// This is the `const` version of [`SplitEndian::join_le`](crate::SplitEndian::join_le).
+				#[must_use]
+				#[inline]
+				pub const fn join_le(parts: [$U; $N]) -> $T {
+					Const::<$T, [$U; $N]>::join_from(parts)
+				}
+
+				/// Joins units of `U`, most significant first
+				///
+				/// This is the `const` version of [`SplitEndian::join_be`](crate::SplitEndian::join_be).
+				#[must_use]
+				#[inline]
+				pub const fn join_be(parts: [$U; $N]) -> $T {
+					Const::<$T, $U>::join_le(consts::reverse_units!(parts))
+				}
+			}
+
+			impl SplitInto<[$U; $N]> for $T {
+				#[inline]
+				fn split_into(self) -> [$U; $N] {
+					Const::<$T, [$U; $N]>::split_into(self)
+				}
+			}
+
+			impl JoinFrom<[$U; $N]> for $T {
+				#[inline]
+				fn join_from(parts: [$U; $N]) -> Self {
+					Const::<$T, [$U; $N]>::join_from(parts)
+				}
+			}
+
+			impl SplitUnits<$U> for $T {
+				type Parts = [$U; $N];
+
+				#[inline]
+				fn split_units(self) -> Self::Parts {
+					<$T as SplitInto<Self::Parts>>::split_into(self)
+				}
+
+				#[inline]
+				fn join_units(parts: Self::Parts) -> Self {
+					<$T as JoinFrom<Self::Parts>>::join_from(parts)
+				}
+			}
+		)+
+	};
+}
+
+// Halves
+impl_split_join! { u48 => u24 : u24 }
+impl_split_join! { i48 => i24 : u24 }
+
+// Units
+impl_split_into_join_from! { u24 => [u8; 3] }
+impl_split_into_join_from! { u40 => [u8; 5] }
+impl_split_into_join_from! { u48 => [u8; 6], [u16; 3], [u24; 2] }
+impl_split_into_join_from! { u56 => [u8; 7] }
+impl_split_into_join_from! { i24 => [u8; 3] }
+impl_split_into_join_from! { i40 => [u8; 5] }
+impl_split_into_join_from! { i48 => [u8; 6], [u16; 3], [u24; 2] }
+impl_split_into_join_from! { i56 => [u8; 7] }
+
+// Check that all associated types are correct
+static_assertions::assert_type_eq_all! { <u48 as Split>::Lo, <u48 as Split>::Hi, u24 }
+static_assertions::assert_type_eq_all! { <i48 as Split>::Lo, u24 }
+static_assertions::assert_type_eq_all! { <i48 as Split>::Hi, i24 }
+static_assertions::assert_not_impl_any! { u24: Split }
+static_assertions::assert_not_impl_any! { u40: Split }
+static_assertions::assert_not_impl_any! { u56: Split }
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use super::*;
+	use crate::SplitEndian;
+
+	#[test]
+	#[rustfmt::skip]
+	fn split_join() {
+		let value = u48::new(0x1234_5678_9abc).unwrap();
+		assert_eq!(value.lo_hi(), (u24::new(0x0078_9abc).unwrap(), u24::new(0x0012_3456).unwrap()));
+		assert_eq!(u48::join(value.lo(), value.hi()), value);
+
+		let value = i48::new(-0x1234_5678_9abc).unwrap();
+		assert_eq!(value.lo_hi(), (u24::new(0x0087_6544).unwrap(), i24::new(-0x0012_3457).unwrap()));
+		assert_eq!(i48::join(value.lo(), value.hi()), value);
+		assert_eq!(i48::MIN.lo_hi(), (u24::MIN, i24::MIN));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn split_units() {
+		let value = u48::new(0x1234_5678_9abc).unwrap();
+		assert_eq!(value.split_le::<u8 >(), [0xbc, 0x9a, 0x78, 0x56, 0x34, 0x12]);
+		assert_eq!(value.split_be::<u16>(), [0x1234, 0x5678, 0x9abc]);
+		assert_eq!(value.split_be::<u24>(), [u24::new(0x0012_3456).unwrap(), u24::new(0x0078_9abc).unwrap()]);
+		assert_eq!(i24::new(-2).unwrap().split_le::<u8>(), [0xfe, 0xff, 0xff]);
+		assert_eq!(i24::join_le::<u8>([0xfe, 0xff, 0xff]), i24::new(-2).unwrap());
+		assert_eq!(u56::join_be::<u8>([0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd]), u56::new(0x01_2345_6789_abcd).unwrap());
+		assert_eq!(i40::join_le::<u8>(i40::MIN.split_le::<u8>()), i40::MIN);
+	}
+}
@@ -0,0 +1,235 @@
+//! Raw bit representation of integers
+//!
+//! This module provides a common representation for all integers of the crate,
+//! including those whose width isn't a primitive one, so conversions between
+//! them may be written once, instead of once per primitive.
+
+// Imports
+use crate::{Const, Sign};
+
+/// Integers that may be represented by their raw bits
+///
+/// The bits are stored in the lower [`Raw::BITS`] bits of an `u128`, with every
+/// bit above them set to `0`.
+///
+/// Every [`Signed`](crate::Signed) type implementing this trait, along with it's variants,
+/// implements [`SignedExt`](crate::SignedExt) through it.
+pub trait Raw: Copy + PartialEq {
+	/// Number of bits of this integer
+	const BITS: u32;
+
+	/// If this integer is signed
+	const SIGNED: bool;
+
+	/// Returns the raw bits of this integer
+	fn to_raw(self) -> u128;
+
+	/// Creates this integer from the lower [`Raw::BITS`] bits of `raw`
+	///
+	/// Any bits above them are ignored.
+	fn from_raw(raw: u128) -> Self;
+}
+
+/// Macro to help implement [`Raw`] for primitives
+macro_rules! impl_raw {
+	($( $T:ty ),+ $(,)?) => {
+		$(
+			impl Const<$T> {
+				/// Returns the raw bits of `value`
+				#[allow(clippy::as_conversions)]
+				pub(crate) const fn to_raw(value: $T) -> u128 {
+					// Note: Casting between signedness is a no-op.
+					// Note: Casting from a smaller to larger unsigned integer will zero-extend.
+					value as <$T as crate::Signed>::Unsigned as u128
+				}
+
+				/// Creates an integer from the lower bits of `raw`
+				#[allow(clippy::as_conversions)]
+				pub(crate) const fn from_raw(raw: u128) -> $T {
+					// Note: Casting from a larger to smaller integer will truncate.
+					raw as $T
+				}
+			}
+
+			impl Raw for $T {
+				const BITS: u32 = <$T>::BITS;
+				const SIGNED: bool = <$T>::MIN != 0;
+
+				#[inline]
+				fn to_raw(self) -> u128 {
+					Const::<$T>::to_raw(self)
+				}
+
+				#[inline]
+				fn from_raw(raw: u128) -> Self {
+					Const::<$T>::from_raw(raw)
+				}
+			}
+		)+
+	};
+}
+
+impl_raw! { u8, u16, u32, u64, u128, usize }
+impl_raw! { i8, i16, i32, i64, i128, isize }
+
+/// Returns a mask with the lower `bits` bits set
+pub const fn mask(bits: u32) -> u128 {
+	match bits {
+		0 => 0,
+		_ => u128::MAX >> (u128::BITS - bits),
+	}
+}
+
+/// Sign extends the lower `bits` bits of `raw` to all of it's bits
+pub const fn sign_extend(raw: u128, bits: u32) -> u128 {
+	match bits {
+		0 => 0,
+		_ if raw & (1 << (bits - 1)) != 0 => raw | !mask(bits),
+		_ => raw & mask(bits),
+	}
+}
+
+/// Returns if the raw bits `raw` of a `T` are negative
+pub const fn is_negative_raw<T: Raw>(raw: u128) -> bool {
+	T::SIGNED && raw & (1 << (T::BITS - 1)) != 0
+}
+
+/// Returns the sign of the raw bits `raw` of a `T`
+pub const fn sign_raw<T: Raw>(raw: u128) -> Sign {
+	match raw {
+		_ if is_negative_raw::<T>(raw) => Sign::Negative,
+		0 => Sign::Zero,
+		_ => Sign::Positive,
+	}
+}
+
+/// Returns the raw bits of `-1`, `0` or `1`, depending on the sign of the raw bits `raw` of a `T`
+pub const fn signum_raw<T: Raw>(raw: u128) -> u128 {
+	match sign_raw::<T>(raw) {
+		Sign::Negative => mask(T::BITS),
+		Sign::Zero => 0,
+		Sign::Positive => 1,
+	}
+}
+
+/// Returns the raw bits of the magnitude of the raw bits `raw` of a `T`
+pub const fn abs_raw<T: Raw>(raw: u128) -> u128 {
+	if is_negative_raw::<T>(raw) {
+		raw.wrapping_neg() & mask(T::BITS)
+	} else {
+		raw
+	}
+}
+
+/// Returns the raw bits of the minimum value of `T`
+pub const fn min_raw<T: Raw>() -> u128 {
+	if T::SIGNED {
+		1 << (T::BITS - 1)
+	} else {
+		0
+	}
+}
+
+/// Returns the raw bits of the maximum value of `T`
+pub const fn max_raw<T: Raw>() -> u128 {
+	if T::SIGNED {
+		mask(T::BITS - 1)
+	} else {
+		mask(T::BITS)
+	}
+}
+
+/// Extends the raw bits `raw` of a `T` to all of it's bits, using the signedness of `T`
+pub const fn extend_raw<T: Raw>(raw: u128) -> u128 {
+	if T::SIGNED {
+		sign_extend(raw, T::BITS)
+	} else {
+		raw
+	}
+}
+
+/// Returns if the value of the raw bits `raw` of a `T` fits in `U`
+pub const fn fits_raw<T: Raw, U: Raw>(raw: u128) -> bool {
+	if is_negative_raw::<T>(raw) {
+		// Note: Negative values fit if truncating and sign extending them back doesn't change them
+		U::SIGNED && sign_extend(raw & mask(U::BITS), U::BITS) & mask(T::BITS) == raw
+	} else {
+		raw <= max_raw::<U>()
+	}
+}
+
+/// Clamps the raw bits `raw` of a `T` to the range of `U`
+pub const fn saturate_raw<T: Raw, U: Raw>(raw: u128) -> u128 {
+	if fits_raw::<T, U>(raw) {
+		extend_raw::<T>(raw)
+	} else if is_negative_raw::<T>(raw) {
+		min_raw::<U>()
+	} else {
+		max_raw::<U>()
+	}
+}
+
+/// Returns if the value with sign `negative` and magnitude `raw` fits in `T`
+pub const fn fits_sign_magnitude_raw<T: Raw>(negative: bool, raw: u128) -> bool {
+	// Note: The raw bits of `T::MIN` are also it's magnitude, for both signed and unsigned integers
+	if negative {
+		raw <= min_raw::<T>()
+	} else {
+		raw <= max_raw::<T>()
+	}
+}
+
+/// Returns the raw bits of the value with sign `negative` and magnitude `raw`, wrapping around
+pub const fn wrapping_from_sign_magnitude_raw(negative: bool, raw: u128) -> u128 {
+	if negative {
+		raw.wrapping_neg()
+	} else {
+		raw
+	}
+}
+
+/// Returns the raw bits of the value with sign `negative` and magnitude `raw`, clamping it to the range of `T`
+pub const fn saturating_from_sign_magnitude_raw<T: Raw>(negative: bool, raw: u128) -> u128 {
+	if fits_sign_magnitude_raw::<T>(negative, raw) {
+		wrapping_from_sign_magnitude_raw(negative, raw)
+	} else if negative {
+		min_raw::<T>()
+	} else {
+		max_raw::<T>()
+	}
+}
+
+/// zigzag encodes the raw bits `raw` of a `T`
+pub const fn zigzag_encode_raw<T: Raw>(raw: u128) -> u128 {
+	// Note: Negative values have all their bits flipped, after making room for the sign in the lowest bit
+	let sign = if is_negative_raw::<T>(raw) { mask(T::BITS) } else { 0 };
+	((raw << 1) ^ sign) & mask(T::BITS)
+}
+
+/// zigzag decodes the raw bits `raw`
+///
+/// The result must be truncated to the width of the encoded integer.
+pub const fn zigzag_decode_raw(raw: u128) -> u128 {
+	(raw >> 1) ^ (raw & 1).wrapping_neg()
+}
+
+/// Returns if `value` is negative
+pub fn is_negative<T: Raw>(value: T) -> bool {
+	is_negative_raw::<T>(value.to_raw())
+}
+
+/// Zero extends or truncates `value` into `U`
+pub fn zero_cast<T: Raw, U: Raw>(value: T) -> U {
+	U::from_raw(value.to_raw())
+}
+
+/// Extends or truncates `value` into `U`, if it's value fits
+pub fn try_cast<T: Raw, U: Raw>(value: T) -> Option<U> {
+	let raw = value.to_raw();
+	fits_raw::<T, U>(raw).then(|| U::from_raw(extend_raw::<T>(raw)))
+}
+
+/// Extends or truncates `value` into `U`, clamping it to `U`'s range
+pub fn saturating_cast<T: Raw, U: Raw>(value: T) -> U {
+	U::from_raw(saturate_raw::<T, U>(value.to_raw()))
+}
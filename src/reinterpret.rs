@@ -0,0 +1,279 @@
+//! Reinterpretation of references
+//!
+//! This module provides [`ReinterpretSign`], which reinterprets references to integers,
+//! and to arrays, slices and [`Cell`]s of them, as their other signedness, without copying.
+//!
+//! This is possible as the signed and unsigned variants of each primitive integer have the
+//! same size and alignment, and every bit pattern is valid for both of them.
+
+// Imports
+use core::{cell::Cell, slice};
+
+/// Sealed trait, so [`ReinterpretSign`] may only be implemented in this module
+mod sealed {
+	/// Types that may be reinterpreted
+	pub trait Sealed {}
+}
+
+/// Types that may be reinterpreted as their signed and unsigned variants behind a reference
+///
+/// This trait is sealed, as reinterpreting is only sound for types with the same layout as
+/// their variants, so it's only implemented for primitive integers and arrays, slices and
+/// [`Cell`]s of them.
+pub trait ReinterpretSign: sealed::Sealed {
+	/// Signed variant of this type
+	type Signed: ?Sized;
+
+	/// Unsigned variant of this type
+	type Unsigned: ?Sized;
+
+	/// Reinterprets this value as signed
+	fn as_signed_ref(&self) -> &Self::Signed;
+
+	/// Reinterprets this value as unsigned
+	fn as_unsigned_ref(&self) -> &Self::Unsigned;
+
+	/// Reinterprets this value as signed, mutably
+	fn as_signed_mut(&mut self) -> &mut Self::Signed;
+
+	/// Reinterprets this value as unsigned, mutably
+	fn as_unsigned_mut(&mut self) -> &mut Self::Unsigned;
+}
+
+/// Macro to help implement [`ReinterpretSign`] for primitives
+macro_rules! impl_reinterpret_sign {
+	($T:ty : - $TSigned:ty : + $TUnsigned:ty) => {
+		impl sealed::Sealed for $T {}
+
+		impl ReinterpretSign for $T {
+			type Signed = $TSigned;
+			type Unsigned = $TUnsigned;
+
+			#[inline]
+			fn as_signed_ref(&self) -> &Self::Signed {
+				// SAFETY: Both variants have the same layout and accept any bit pattern
+				unsafe { &*(self as *const Self).cast::<$TSigned>() }
+			}
+
+			#[inline]
+			fn as_unsigned_ref(&self) -> &Self::Unsigned {
+				// SAFETY: Both variants have the same layout and accept any bit pattern
+				unsafe { &*(self as *const Self).cast::<$TUnsigned>() }
+			}
+
+			#[inline]
+			fn as_signed_mut(&mut self) -> &mut Self::Signed {
+				// SAFETY: Both variants have the same layout and accept any bit pattern
+				unsafe { &mut *(self as *mut Self).cast::<$TSigned>() }
+			}
+
+			#[inline]
+			fn as_unsigned_mut(&mut self) -> &mut Self::Unsigned {
+				// SAFETY: Both variants have the same layout and accept any bit pattern
+				unsafe { &mut *(self as *mut Self).cast::<$TUnsigned>() }
+			}
+		}
+	};
+
+	($( - $TSigned:ty : + $TUnsigned:ty ),+ $(,)?) => {
+		$(
+			impl_reinterpret_sign! { $TSigned   : - $TSigned : + $TUnsigned }
+			impl_reinterpret_sign! { $TUnsigned : - $TSigned : + $TUnsigned }
+		)+
+	};
+}
+
+impl_reinterpret_sign! {
+	- i8    : + u8,
+	- i16   : + u16,
+	- i32   : + u32,
+	- i64   : + u64,
+	- i128  : + u128,
+	- isize : + usize,
+}
+
+impl<T: sealed::Sealed> sealed::Sealed for [T] {}
+
+impl<T> ReinterpretSign for [T]
+where
+	T: ReinterpretSign,
+	T::Signed: Sized,
+	T::Unsigned: Sized,
+{
+	type Signed = [T::Signed];
+	type Unsigned = [T::Unsigned];
+
+	#[inline]
+	fn as_signed_ref(&self) -> &Self::Signed {
+		// SAFETY: `T` and it's variant have the same layout, so the slices do too
+		unsafe { slice::from_raw_parts(self.as_ptr().cast::<T::Signed>(), self.len()) }
+	}
+
+	#[inline]
+	fn as_unsigned_ref(&self) -> &Self::Unsigned {
+		// SAFETY: `T` and it's variant have the same layout, so the slices do too
+		unsafe { slice::from_raw_parts(self.as_ptr().cast::<T::Unsigned>(), self.len()) }
+	}
+
+	#[inline]
+	fn as_signed_mut(&mut self) -> &mut Self::Signed {
+		// SAFETY: `T` and it's variant have the same layout, so the slices do too
+		unsafe { slice::from_raw_parts_mut(self.as_mut_ptr().cast::<T::Signed>(), self.len()) }
+	}
+
+	#[inline]
+	fn as_unsigned_mut(&mut self) -> &mut Self::Unsigned {
+		// SAFETY: `T` and it's variant have the same layout, so the slices do too
+		unsafe { slice::from_raw_parts_mut(self.as_mut_ptr().cast::<T::Unsigned>(), self.len()) }
+	}
+}
+
+impl<T: sealed::Sealed, const N: usize> sealed::Sealed for [T; N] {}
+
+impl<T, const N: usize> ReinterpretSign for [T; N]
+where
+	T: ReinterpretSign,
+	T::Signed: Sized,
+	T::Unsigned: Sized,
+{
+	type Signed = [T::Signed; N];
+	type Unsigned = [T::Unsigned; N];
+
+	#[inline]
+	fn as_signed_ref(&self) -> &Self::Signed {
+		// SAFETY: `T` and it's variant have the same layout, so the arrays do too
+		unsafe { &*(self as *const Self).cast::<[T::Signed; N]>() }
+	}
+
+	#[inline]
+	fn as_unsigned_ref(&self) -> &Self::Unsigned {
+		// SAFETY: `T` and it's variant have the same layout, so the arrays do too
+		unsafe { &*(self as *const Self).cast::<[T::Unsigned; N]>() }
+	}
+
+	#[inline]
+	fn as_signed_mut(&mut self) -> &mut Self::Signed {
+		// SAFETY: `T` and it's variant have the same layout, so the arrays do too
+		unsafe { &mut *(self as *mut Self).cast::<[T::Signed; N]>() }
+	}
+
+	#[inline]
+	fn as_unsigned_mut(&mut self) -> &mut Self::Unsigned {
+		// SAFETY: `T` and it's variant have the same layout, so the arrays do too
+		unsafe { &mut *(self as *mut Self).cast::<[T::Unsigned; N]>() }
+	}
+}
+
+impl<T: sealed::Sealed> sealed::Sealed for Cell<T> {}
+
+impl<T> ReinterpretSign for Cell<T>
+where
+	T: ReinterpretSign,
+	T::Signed: Sized,
+	T::Unsigned: Sized,
+{
+	type Signed = Cell<T::Signed>;
+	type Unsigned = Cell<T::Unsigned>;
+
+	#[inline]
+	fn as_signed_ref(&self) -> &Self::Signed {
+		// SAFETY: `Cell` has the same layout as `T`, and `T` the same as it's variant.
+		//         As `Cell` isn't `Sync`, all writes through either reference happen on this thread.
+		unsafe { &*(self as *const Self).cast::<Cell<T::Signed>>() }
+	}
+
+	#[inline]
+	fn as_unsigned_ref(&self) -> &Self::Unsigned {
+		// SAFETY: `Cell` has the same layout as `T`, and `T` the same as it's variant.
+		//         As `Cell` isn't `Sync`, all writes through either reference happen on this thread.
+		unsafe { &*(self as *const Self).cast::<Cell<T::Unsigned>>() }
+	}
+
+	#[inline]
+	fn as_signed_mut(&mut self) -> &mut Self::Signed {
+		// SAFETY: `Cell` has the same layout as `T`, and `T` the same as it's variant
+		unsafe { &mut *(self as *mut Self).cast::<Cell<T::Signed>>() }
+	}
+
+	#[inline]
+	fn as_unsigned_mut(&mut self) -> &mut Self::Unsigned {
+		// SAFETY: `Cell` has the same layout as `T`, and `T` the same as it's variant
+		unsafe { &mut *(self as *mut Self).cast::<Cell<T::Unsigned>>() }
+	}
+}
+
+// Check that the variants have the same layout
+static_assertions::assert_eq_size!(i8, u8);
+static_assertions::assert_eq_size!(i16, u16);
+static_assertions::assert_eq_size!(i32, u32);
+static_assertions::assert_eq_size!(i64, u64);
+static_assertions::assert_eq_size!(i128, u128);
+static_assertions::assert_eq_size!(isize, usize);
+static_assertions::assert_eq_align!(i8, u8);
+static_assertions::assert_eq_align!(i16, u16);
+static_assertions::assert_eq_align!(i32, u32);
+static_assertions::assert_eq_align!(i64, u64);
+static_assertions::assert_eq_align!(i128, u128);
+static_assertions::assert_eq_align!(isize, usize);
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use super::*;
+
+	#[test]
+	#[rustfmt::skip]
+	fn primitive() {
+		assert_eq!(*0xffu8.as_signed_ref(), -1);
+		assert_eq!(*(-1i32).as_unsigned_ref(), u32::MAX);
+		assert_eq!(*5u64.as_unsigned_ref(), 5);
+
+		let mut value = 0u16;
+		*value.as_signed_mut() = -2;
+		assert_eq!(value, 0xfffe);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn slice() {
+		let bytes: &[u8] = &[0x00, 0x7f, 0x80, 0xff];
+		assert_eq!(bytes.as_signed_ref(), [0, 127, -128, -1]);
+		assert_eq!(bytes.as_signed_ref().as_unsigned_ref(), bytes);
+		assert_eq!(bytes.as_signed_ref().as_ptr().cast::<u8>(), bytes.as_ptr());
+
+		let mut values = [1u32, 2, 3];
+		values[..].as_signed_mut().iter_mut().for_each(|value| *value = -*value);
+		assert_eq!(values, [u32::MAX, u32::MAX - 1, u32::MAX - 2]);
+
+		let empty: &[i128] = &[];
+		assert_eq!(empty.as_unsigned_ref(), []);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn array() {
+		let mut values = [[0xffu8; 2]; 2];
+		assert_eq!(*values.as_signed_ref(), [[-1; 2]; 2]);
+		values.as_signed_mut()[1][0] = -2;
+		assert_eq!(values, [[0xff, 0xff], [0xfe, 0xff]]);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn cell() {
+		let cell = Cell::new(0u16);
+		let signed = cell.as_signed_ref();
+		signed.set(-1);
+		assert_eq!(cell.get(), u16::MAX);
+		cell.set(0x8000);
+		assert_eq!(signed.get(), i16::MIN);
+
+		let mut cell = Cell::new(-1isize);
+		*cell.as_unsigned_mut().get_mut() = 1;
+		assert_eq!(cell.get(), 1);
+
+		let cells = [Cell::new(0u8), Cell::new(1)];
+		cells[..].as_signed_ref()[0].set(-1);
+		assert_eq!(cells[0].get(), 0xff);
+	}
+}
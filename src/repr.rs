@@ -0,0 +1,41 @@
+//! Representations of signed integers
+//!
+//! Signed integers in this crate use two's complement. This module converts
+//! them to and from the bit patterns of other representations:
+//!
+//! - [`OnesComplement`]: Negative values have all bits of their magnitude flipped.
+//! - [`SignMagnitude`]: Negative values have their most significant bit set, followed by their magnitude.
+//!
+//! Both representations have a negative zero and can't represent the two's
+//! complement minimum, as their range is symmetric.
+
+// Modules
+pub mod ones;
+pub mod sign_magnitude;
+
+// Exports
+pub use ones::{internet_checksum, OnesComplement, OnesComplementAdd};
+pub use sign_magnitude::SignMagnitude;
+
+// Imports
+use core::fmt;
+
+/// Error for when converting between representations fails
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum ReprError {
+	/// The value is the two's complement minimum, which the representation can't hold
+	Overflow,
+
+	/// The bits are negative zero, which was rejected
+	NegativeZero,
+}
+
+impl fmt::Display for ReprError {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Overflow => f.write_str("value out of range of the representation"),
+			Self::NegativeZero => f.write_str("bits are negative zero"),
+		}
+	}
+}
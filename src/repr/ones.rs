@@ -0,0 +1,276 @@
+//! Ones' complement representation
+//!
+//! Negative values are stored with all bits of their magnitude flipped, so
+//! all bits set is negative zero.
+
+// Imports
+use super::ReprError;
+use crate::{Const, Join, Signed};
+
+/// Conversion to and from ones' complement bits
+pub trait OnesComplement: Signed + Sized {
+	/// The bits of negative zero
+	const NEGATIVE_ZERO: Self::Unsigned;
+
+	/// Returns the ones' complement bits of this value
+	///
+	/// # Errors
+	/// Returns [`ReprError::Overflow`] if this value is the minimum, which ones' complement can't represent.
+	fn to_ones_complement_bits(self) -> Result<Self::Unsigned, ReprError>;
+
+	/// Creates a value from it's ones' complement bits
+	///
+	/// Negative zero is read as `0`.
+	fn from_ones_complement_bits(bits: Self::Unsigned) -> Self;
+
+	/// Creates a value from it's ones' complement bits, rejecting negative zero
+	///
+	/// # Errors
+	/// Returns [`ReprError::NegativeZero`] if `bits` are negative zero.
+	fn try_from_ones_complement_bits(bits: Self::Unsigned) -> Result<Self, ReprError>;
+}
+
+/// Ones' complement addition
+pub trait OnesComplementAdd: Sized {
+	/// Adds `rhs` to this value, adding any carry out of the most significant bit back into the least significant one
+	#[must_use]
+	fn ones_complement_add(self, rhs: Self) -> Self;
+}
+
+/// Macro to help implement [`OnesComplement`]
+macro_rules! impl_ones_complement {
+	($( - $TSigned:ty : + $TUnsigned:ty ),+ $(,)?) => {
+		$(
+			impl Const<$TSigned> {
+				/// Returns the ones' complement bits of `value`
+				///
+				/// This is the `const` version of [`OnesComplement::to_ones_complement_bits`].
+				///
+				/// # Errors
+				/// Returns [`ReprError::Overflow`] if `value` is the minimum.
+				#[inline]
+				pub const fn to_ones_complement_bits(value: $TSigned) -> Result<$TUnsigned, ReprError> {
+					match value {
+						<$TSigned>::MIN => Err(ReprError::Overflow),
+						_ if value < 0 => Ok(!Const::<$TSigned>::abs_unsigned(value)),
+						_ => Ok(Const::<$TSigned>::as_unsigned(value)),
+					}
+				}
+
+				/// Creates an integer from it's ones' complement bits
+				///
+				/// This is the `const` version of [`OnesComplement::from_ones_complement_bits`].
+				#[must_use]
+				#[inline]
+				pub const fn from_ones_complement_bits(bits: $TUnsigned) -> $TSigned {
+					// Note: Negative zero has a magnitude of `0`, so it's read as `0`
+					match Const::<$TSigned>::is_negative(Const::<$TUnsigned>::as_signed(bits)) {
+						true => Const::<$TSigned>::wrapping_from_sign_magnitude(true, !bits),
+						false => Const::<$TUnsigned>::as_signed(bits),
+					}
+				}
+
+				/// Creates an integer from it's ones' complement bits, rejecting negative zero
+				///
+				/// This is the `const` version of [`OnesComplement::try_from_ones_complement_bits`].
+				///
+				/// # Errors
+				/// Returns [`ReprError::NegativeZero`] if `bits` are negative zero.
+				#[inline]
+				pub const fn try_from_ones_complement_bits(bits: $TUnsigned) -> Result<$TSigned, ReprError> {
+					match bits {
+						<$TUnsigned>::MAX => Err(ReprError::NegativeZero),
+						_ => Ok(Const::<$TSigned>::from_ones_complement_bits(bits)),
+					}
+				}
+			}
+
+			impl OnesComplement for $TSigned {
+				const NEGATIVE_ZERO: $TUnsigned = <$TUnsigned>::MAX;
+
+				#[inline]
+				fn to_ones_complement_bits(self) -> Result<Self::Unsigned, ReprError> {
+					Const::<$TSigned>::to_ones_complement_bits(self)
+				}
+
+				#[inline]
+				fn from_ones_complement_bits(bits: Self::Unsigned) -> Self {
+					Const::<$TSigned>::from_ones_complement_bits(bits)
+				}
+
+				#[inline]
+				fn try_from_ones_complement_bits(bits: Self::Unsigned) -> Result<Self, ReprError> {
+					Const::<$TSigned>::try_from_ones_complement_bits(bits)
+				}
+			}
+		)+
+	};
+}
+
+impl_ones_complement! {
+	- i8    : + u8,
+	- i16   : + u16,
+	- i32   : + u32,
+	- i64   : + u64,
+	- i128  : + u128,
+	- isize : + usize,
+}
+
+/// Macro to help implement [`OnesComplementAdd`]
+///
+/// The sum is computed in `$Wide`, which is then split to add the carry in it's high part back into the low part.
+/// Types without a wider type compute it with an overflowing addition instead.
+macro_rules! impl_ones_complement_add {
+	($( $T:ty $( => $Wide:ty )? ),+ $(,)?) => {
+		$(
+			impl Const<$T> {
+				/// Adds `lhs` and `rhs`, adding any carry back into the least significant bit
+				///
+				/// This is the `const` version of [`OnesComplementAdd::ones_complement_add`].
+				#[must_use]
+				#[inline]
+				pub const fn ones_complement_add(lhs: $T, rhs: $T) -> $T {
+					impl_ones_complement_add! { @add lhs, rhs, $T $( => $Wide )? }
+				}
+			}
+
+			impl OnesComplementAdd for $T {
+				#[inline]
+				fn ones_complement_add(self, rhs: Self) -> Self {
+					Const::<$T>::ones_complement_add(self, rhs)
+				}
+			}
+		)+
+	};
+
+	(@add $lhs:ident, $rhs:ident, $T:ty => $Wide:ty) => {{
+		// Note: The carry is at most `1`, and when it's set the low part
+		//       is at most `MAX - 1`, so adding it back can't overflow.
+		let sum = Const::<$T, $Wide>::zero_extend($lhs) + Const::<$T, $Wide>::zero_extend($rhs);
+		let (lo, carry) = Const::<$Wide>::lo_hi(sum);
+		lo + carry
+	}};
+
+	(@add $lhs:ident, $rhs:ident, $T:ty) => {{
+		// Note: When the addition overflows, the wrapped sum is at
+		//       most `MAX - 1`, so adding the carry back can't overflow.
+		match $lhs.overflowing_add($rhs) {
+			(sum, true) => sum + 1,
+			(sum, false) => sum,
+		}
+	}};
+}
+
+impl_ones_complement_add! {
+	u8  => u16,
+	u16 => u32,
+	u32 => u64,
+	u64 => u128,
+	u128,
+	usize,
+}
+
+/// Computes the Internet checksum of `bytes`, as described in RFC 1071
+///
+/// The bytes are summed as big endian 16-bit words with ones' complement addition,
+/// padding an odd last byte with zero, and the sum is then complemented.
+#[must_use]
+#[inline]
+pub fn internet_checksum(bytes: &[u8]) -> u16 {
+	let words = bytes.chunks_exact(2);
+	let last = match *words.remainder() {
+		[hi] => u16::join(0, hi),
+		_ => 0,
+	};
+	let sum = words.fold(last, |sum: u16, word| sum.ones_complement_add(u16::join(word[1], word[0])));
+
+	!sum
+}
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use super::*;
+
+	#[test]
+	#[rustfmt::skip]
+	fn to_ones_complement_bits() {
+		assert_eq!(0i8      .to_ones_complement_bits(), Ok(0x00));
+		assert_eq!((-1i8)   .to_ones_complement_bits(), Ok(0xfe));
+		assert_eq!(i8::MAX  .to_ones_complement_bits(), Ok(0x7f));
+		assert_eq!((-i8::MAX).to_ones_complement_bits(), Ok(0x80));
+		assert_eq!(i8::MIN  .to_ones_complement_bits(), Err(ReprError::Overflow));
+		assert_eq!((-1i16)  .to_ones_complement_bits(), Ok(0xfffe));
+		assert_eq!((-2i32)  .to_ones_complement_bits(), Ok(0xffff_fffd));
+		assert_eq!(i64::MIN .to_ones_complement_bits(), Err(ReprError::Overflow));
+		assert_eq!((-1i128) .to_ones_complement_bits(), Ok(u128::MAX - 1));
+		assert_eq!((-1isize).to_ones_complement_bits(), Ok(usize::MAX - 1));
+		assert_eq!(isize::MIN.to_ones_complement_bits(), Err(ReprError::Overflow));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn from_ones_complement_bits() {
+		assert_eq!(i8   ::from_ones_complement_bits(0x00), 0);
+		assert_eq!(i8   ::from_ones_complement_bits(0xff), 0);
+		assert_eq!(i8   ::from_ones_complement_bits(0xfe), -1);
+		assert_eq!(i8   ::from_ones_complement_bits(0x80), -i8::MAX);
+		assert_eq!(i16  ::from_ones_complement_bits(0x7fff), i16::MAX);
+		assert_eq!(i32  ::from_ones_complement_bits(0xffff_fffd), -2);
+		assert_eq!(i64  ::from_ones_complement_bits(u64::MAX), 0);
+		assert_eq!(i128 ::from_ones_complement_bits(1 << 127), -i128::MAX);
+		assert_eq!(isize::from_ones_complement_bits(usize::MAX - 1), -1);
+		assert_eq!(i8   ::try_from_ones_complement_bits(0xff), Err(ReprError::NegativeZero));
+		assert_eq!(i8   ::try_from_ones_complement_bits(0x00), Ok(0));
+		assert_eq!(isize::try_from_ones_complement_bits(isize::NEGATIVE_ZERO), Err(ReprError::NegativeZero));
+	}
+
+	#[test]
+	fn ones_complement_exhaustive_16() {
+		for value in (i16::MIN + 1)..=i16::MAX {
+			let bits = value.to_ones_complement_bits().unwrap();
+			assert_eq!(i16::from_ones_complement_bits(bits), value);
+			assert_eq!(i16::try_from_ones_complement_bits(bits), Ok(value));
+		}
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn ones_complement_add() {
+		assert_eq!(0x01u8               .ones_complement_add(0x02), 0x03);
+		assert_eq!(0xffu8               .ones_complement_add(0x01), 0x01);
+		assert_eq!(0xffffu16            .ones_complement_add(0xffff), 0xffff);
+		assert_eq!(0x8000u16            .ones_complement_add(0x8000), 0x0001);
+		assert_eq!(0xffff_fffeu32       .ones_complement_add(0x0000_0003), 0x0000_0002);
+		assert_eq!(u64::MAX             .ones_complement_add(u64::MAX), u64::MAX);
+		assert_eq!(u128::MAX            .ones_complement_add(1), 1);
+		assert_eq!((1u128 << 127)       .ones_complement_add(1 << 127), 1);
+		assert_eq!(usize::MAX           .ones_complement_add(usize::MAX), usize::MAX);
+		assert_eq!((usize::MAX - 1)     .ones_complement_add(2), 1);
+	}
+
+	#[test]
+	fn ones_complement_add_matches_signed() {
+		// Note: Ones' complement addition is addition of the values the bits represent, with negative zero as `0`
+		for lhs in i8::MIN + 1..=i8::MAX {
+			for rhs in i8::MIN + 1..=i8::MAX {
+				if let Some(sum) = lhs.checked_add(rhs).filter(|&sum| sum != i8::MIN) {
+					let bits = lhs
+						.to_ones_complement_bits()
+						.unwrap()
+						.ones_complement_add(rhs.to_ones_complement_bits().unwrap());
+					assert_eq!(i8::from_ones_complement_bits(bits), sum);
+				}
+			}
+		}
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn internet_checksum() {
+		// Note: Example from RFC 1071, section 3
+		assert_eq!(super::internet_checksum(&[0x00, 0x01, 0xf2, 0x03, 0xf4, 0xf5, 0xf6, 0xf7]), 0x220d);
+		assert_eq!(super::internet_checksum(&[0x00, 0x01, 0xf2]), !0xf201);
+		assert_eq!(super::internet_checksum(&[]), 0xffff);
+	}
+}
@@ -0,0 +1,156 @@
+//! Sign-magnitude representation
+//!
+//! Negative values are stored with their most significant bit set, followed
+//! by their magnitude, so only the most significant bit set is negative zero.
+
+// Imports
+use super::ReprError;
+use crate::{Const, Signed};
+
+/// Conversion to and from sign-magnitude bits
+pub trait SignMagnitude: Signed + Sized {
+	/// The bits of negative zero
+	const NEGATIVE_ZERO: Self::Unsigned;
+
+	/// Returns the sign-magnitude bits of this value
+	///
+	/// # Errors
+	/// Returns [`ReprError::Overflow`] if this value is the minimum, which sign-magnitude can't represent.
+	fn to_sign_magnitude_bits(self) -> Result<Self::Unsigned, ReprError>;
+
+	/// Creates a value from it's sign-magnitude bits
+	///
+	/// Negative zero is read as `0`.
+	fn from_sign_magnitude_bits(bits: Self::Unsigned) -> Self;
+
+	/// Creates a value from it's sign-magnitude bits, rejecting negative zero
+	///
+	/// # Errors
+	/// Returns [`ReprError::NegativeZero`] if `bits` are negative zero.
+	fn try_from_sign_magnitude_bits(bits: Self::Unsigned) -> Result<Self, ReprError>;
+}
+
+/// Macro to help implement [`SignMagnitude`]
+macro_rules! impl_sign_magnitude {
+	($( - $TSigned:ty : + $TUnsigned:ty ),+ $(,)?) => {
+		$(
+			impl Const<$TSigned> {
+				/// Returns the sign-magnitude bits of `value`
+				///
+				/// This is the `const` version of [`SignMagnitude::to_sign_magnitude_bits`].
+				///
+				/// # Errors
+				/// Returns [`ReprError::Overflow`] if `value` is the minimum.
+				#[inline]
+				pub const fn to_sign_magnitude_bits(value: $TSigned) -> Result<$TUnsigned, ReprError> {
+					match value {
+						<$TSigned>::MIN => Err(ReprError::Overflow),
+						_ if value < 0 => Ok(Const::<$TSigned>::abs_unsigned(value) | <$TSigned as SignMagnitude>::NEGATIVE_ZERO),
+						_ => Ok(Const::<$TSigned>::as_unsigned(value)),
+					}
+				}
+
+				/// Creates an integer from it's sign-magnitude bits
+				///
+				/// This is the `const` version of [`SignMagnitude::from_sign_magnitude_bits`].
+				#[must_use]
+				#[inline]
+				pub const fn from_sign_magnitude_bits(bits: $TUnsigned) -> $TSigned {
+					// Note: Negative zero has a magnitude of `0`, so it's read as `0`
+					let sign = <$TSigned as SignMagnitude>::NEGATIVE_ZERO;
+					Const::<$TSigned>::wrapping_from_sign_magnitude(bits & sign != 0, bits & !sign)
+				}
+
+				/// Creates an integer from it's sign-magnitude bits, rejecting negative zero
+				///
+				/// This is the `const` version of [`SignMagnitude::try_from_sign_magnitude_bits`].
+				///
+				/// # Errors
+				/// Returns [`ReprError::NegativeZero`] if `bits` are negative zero.
+				#[inline]
+				pub const fn try_from_sign_magnitude_bits(bits: $TUnsigned) -> Result<$TSigned, ReprError> {
+					match bits {
+						<$TSigned as SignMagnitude>::NEGATIVE_ZERO => Err(ReprError::NegativeZero),
+						_ => Ok(Const::<$TSigned>::from_sign_magnitude_bits(bits)),
+					}
+				}
+			}
+
+			impl SignMagnitude for $TSigned {
+				const NEGATIVE_ZERO: $TUnsigned = !(<$TUnsigned>::MAX >> 1);
+
+				#[inline]
+				fn to_sign_magnitude_bits(self) -> Result<Self::Unsigned, ReprError> {
+					Const::<$TSigned>::to_sign_magnitude_bits(self)
+				}
+
+				#[inline]
+				fn from_sign_magnitude_bits(bits: Self::Unsigned) -> Self {
+					Const::<$TSigned>::from_sign_magnitude_bits(bits)
+				}
+
+				#[inline]
+				fn try_from_sign_magnitude_bits(bits: Self::Unsigned) -> Result<Self, ReprError> {
+					Const::<$TSigned>::try_from_sign_magnitude_bits(bits)
+				}
+			}
+		)+
+	};
+}
+
+impl_sign_magnitude! {
+	- i8    : + u8,
+	- i16   : + u16,
+	- i32   : + u32,
+	- i64   : + u64,
+	- i128  : + u128,
+	- isize : + usize,
+}
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use super::*;
+
+	#[test]
+	#[rustfmt::skip]
+	fn to_sign_magnitude_bits() {
+		assert_eq!(0i8       .to_sign_magnitude_bits(), Ok(0x00));
+		assert_eq!((-1i8)    .to_sign_magnitude_bits(), Ok(0x81));
+		assert_eq!(i8::MAX   .to_sign_magnitude_bits(), Ok(0x7f));
+		assert_eq!((-i8::MAX).to_sign_magnitude_bits(), Ok(0xff));
+		assert_eq!(i8::MIN   .to_sign_magnitude_bits(), Err(ReprError::Overflow));
+		assert_eq!((-1i16)   .to_sign_magnitude_bits(), Ok(0x8001));
+		assert_eq!((-2i32)   .to_sign_magnitude_bits(), Ok(0x8000_0002));
+		assert_eq!(i64::MIN  .to_sign_magnitude_bits(), Err(ReprError::Overflow));
+		assert_eq!((-1i128)  .to_sign_magnitude_bits(), Ok((1 << 127) | 1));
+		assert_eq!((-1isize) .to_sign_magnitude_bits(), Ok(isize::NEGATIVE_ZERO | 1));
+		assert_eq!(isize::MIN.to_sign_magnitude_bits(), Err(ReprError::Overflow));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn from_sign_magnitude_bits() {
+		assert_eq!(i8   ::from_sign_magnitude_bits(0x00), 0);
+		assert_eq!(i8   ::from_sign_magnitude_bits(0x80), 0);
+		assert_eq!(i8   ::from_sign_magnitude_bits(0x81), -1);
+		assert_eq!(i8   ::from_sign_magnitude_bits(0xff), -i8::MAX);
+		assert_eq!(i16  ::from_sign_magnitude_bits(0x7fff), i16::MAX);
+		assert_eq!(i32  ::from_sign_magnitude_bits(0x8000_0002), -2);
+		assert_eq!(i64  ::from_sign_magnitude_bits(1 << 63), 0);
+		assert_eq!(i128 ::from_sign_magnitude_bits(u128::MAX), -i128::MAX);
+		assert_eq!(isize::from_sign_magnitude_bits(isize::NEGATIVE_ZERO | 1), -1);
+		assert_eq!(i8   ::try_from_sign_magnitude_bits(0x80), Err(ReprError::NegativeZero));
+		assert_eq!(i8   ::try_from_sign_magnitude_bits(0x00), Ok(0));
+		assert_eq!(isize::try_from_sign_magnitude_bits(isize::NEGATIVE_ZERO), Err(ReprError::NegativeZero));
+	}
+
+	#[test]
+	fn sign_magnitude_exhaustive_16() {
+		for value in (i16::MIN + 1)..=i16::MAX {
+			let bits = value.to_sign_magnitude_bits().unwrap();
+			assert_eq!(i16::from_sign_magnitude_bits(bits), value);
+			assert_eq!(i16::try_from_sign_magnitude_bits(bits), Ok(value));
+		}
+	}
+}
@@ -3,11 +3,19 @@
 //! This modules focuses on describing types that have both an unsigned and signed variant,
 //! such as `i8` / `u8`.
 
+// Imports
+use crate::{
+	raw::{self, Raw},
+	Const,
+};
+use core::fmt;
+
 /// Types with signed and unsigned variants
 ///
 /// Note that references don't currently implement this trait due to
 /// lack of `GAT`s, which are required to specify that a `&'a u8` may
-/// be cast to a `&'a i8` with the same lifetime.
+/// be cast to a `&'a i8` with the same lifetime. Instead, they may be
+/// reinterpreted with [`ReinterpretSign`](crate::ReinterpretSign).
 pub trait Signed {
 	/// Signed variant of this type
 	type Signed;
@@ -23,8 +31,200 @@ pub trait Signed {
 
 	/// Returns the absolute value of `self` as unsigned.
 	fn abs_unsigned(self) -> Self::Unsigned;
+}
+
+/// Extensions of [`Signed`]
+///
+/// This trait is separate from [`Signed`], so that implementing it only requires it's core methods.
+/// It's implemented for every [`Signed`] type that implements [`Raw`], along with it's variants, which
+/// includes all of the crate's integers and their [`Wrapping`](core::num::Wrapping)s, and for non-zero integers.
+pub trait SignedExt: Signed {
+	/// Returns the sign of this value
+	///
+	/// Unsigned values are never negative.
+	fn sign(self) -> Sign;
+
+	/// Returns if this value is less than zero
+	fn is_negative(self) -> bool;
+
+	/// Returns if this value is greater than zero
+	fn is_positive(self) -> bool;
+
+	/// Returns `-1`, `0` or `1`, depending on the sign of this value
+	///
+	/// Unsigned values only return `0` or `1`.
+	#[must_use]
+	fn signum(self) -> Self;
+
+	/// Converts this value to signed, if it's in range of the signed variant
+	///
+	/// Unlike [`Signed::as_signed`], this preserves the value.
+	///
+	/// # Errors
+	/// Returns [`SignError`] if the value is larger than the signed variant's maximum.
+	fn try_to_signed(self) -> Result<Self::Signed, SignError>;
+
+	/// Converts this value to unsigned, if it isn't negative
+	///
+	/// Unlike [`Signed::as_unsigned`], this preserves the value.
+	///
+	/// # Errors
+	/// Returns [`SignError`] if the value is negative.
+	fn try_to_unsigned(self) -> Result<Self::Unsigned, SignError>;
+
+	/// Converts this value to signed, clamping it to the signed variant's maximum
+	fn saturating_to_signed(self) -> Self::Signed;
+
+	/// Converts this value to unsigned, clamping negative values to the unsigned variant's minimum
+	fn saturating_to_unsigned(self) -> Self::Unsigned;
+
+	/// Reinterprets this value as signed, returning if the value changed
+	fn overflowing_to_signed(self) -> (Self::Signed, bool);
+
+	/// Reinterprets this value as unsigned, returning if the value changed
+	fn overflowing_to_unsigned(self) -> (Self::Unsigned, bool);
+
+	/// Creates a value from it's sign and magnitude, if it's in range of this type
+	///
+	/// This is the inverse of [`Signed::abs_unsigned`], with `negative` being
+	/// [`SignedExt::is_negative`]. A zero magnitude is always zero, regardless of `negative`.
+	///
+	/// # Errors
+	/// Returns [`SignError`] if the value is out of range of this type.
+	fn try_from_sign_magnitude(negative: bool, magnitude: Self::Unsigned) -> Result<Self, SignError>
+	where
+		Self: Sized;
+
+	/// Creates a value from it's sign and magnitude, clamping it to this type's range
+	fn saturating_from_sign_magnitude(negative: bool, magnitude: Self::Unsigned) -> Self;
 
-	// TODO: Maybe add a `fn signal() -> Signal` method? Or maybe two `is_positive` / `is_negative` methods.
+	/// Creates a value from it's sign and magnitude, wrapping around at the boundary of this type
+	fn wrapping_from_sign_magnitude(negative: bool, magnitude: Self::Unsigned) -> Self;
+
+	/// Encodes this value with zigzag encoding
+	///
+	/// Values of small magnitude are mapped to small unsigned values, with
+	/// `0, -1, 1, -2, 2, ...` becoming `0, 1, 2, 3, 4, ...`.
+	///
+	/// Unsigned values are reinterpreted as signed first.
+	fn zigzag_encode(self) -> Self::Unsigned;
+
+	/// Decodes a value encoded with [`SignedExt::zigzag_encode`]
+	fn zigzag_decode(value: Self::Unsigned) -> Self::Signed;
+}
+
+impl<T> SignedExt for T
+where
+	T: Signed + Raw,
+	T::Signed: Raw,
+	T::Unsigned: Raw,
+{
+	#[inline]
+	fn sign(self) -> Sign {
+		raw::sign_raw::<T>(self.to_raw())
+	}
+
+	#[inline]
+	fn is_negative(self) -> bool {
+		raw::is_negative(self)
+	}
+
+	#[inline]
+	fn is_positive(self) -> bool {
+		self.sign() == Sign::Positive
+	}
+
+	#[inline]
+	fn signum(self) -> Self {
+		T::from_raw(raw::signum_raw::<T>(self.to_raw()))
+	}
+
+	#[inline]
+	fn try_to_signed(self) -> Result<Self::Signed, SignError> {
+		raw::try_cast(self).ok_or(SignError)
+	}
+
+	#[inline]
+	fn try_to_unsigned(self) -> Result<Self::Unsigned, SignError> {
+		raw::try_cast(self).ok_or(SignError)
+	}
+
+	#[inline]
+	fn saturating_to_signed(self) -> Self::Signed {
+		raw::saturating_cast(self)
+	}
+
+	#[inline]
+	fn saturating_to_unsigned(self) -> Self::Unsigned {
+		raw::saturating_cast(self)
+	}
+
+	#[inline]
+	fn overflowing_to_signed(self) -> (Self::Signed, bool) {
+		(raw::zero_cast(self), raw::try_cast::<T, T::Signed>(self).is_none())
+	}
+
+	#[inline]
+	fn overflowing_to_unsigned(self) -> (Self::Unsigned, bool) {
+		(raw::zero_cast(self), raw::try_cast::<T, T::Unsigned>(self).is_none())
+	}
+
+	#[inline]
+	fn try_from_sign_magnitude(negative: bool, magnitude: Self::Unsigned) -> Result<Self, SignError> {
+		if raw::fits_sign_magnitude_raw::<T>(negative, magnitude.to_raw()) {
+			Ok(T::wrapping_from_sign_magnitude(negative, magnitude))
+		} else {
+			Err(SignError)
+		}
+	}
+
+	#[inline]
+	fn saturating_from_sign_magnitude(negative: bool, magnitude: Self::Unsigned) -> Self {
+		T::from_raw(raw::saturating_from_sign_magnitude_raw::<T>(negative, magnitude.to_raw()))
+	}
+
+	#[inline]
+	fn wrapping_from_sign_magnitude(negative: bool, magnitude: Self::Unsigned) -> Self {
+		T::from_raw(raw::wrapping_from_sign_magnitude_raw(negative, magnitude.to_raw()))
+	}
+
+	#[inline]
+	fn zigzag_encode(self) -> Self::Unsigned {
+		// Note: Unsigned values have the same raw bits as their signed variant
+		T::Unsigned::from_raw(raw::zigzag_encode_raw::<T::Signed>(self.to_raw()))
+	}
+
+	#[inline]
+	fn zigzag_decode(value: Self::Unsigned) -> Self::Signed {
+		T::Signed::from_raw(raw::zigzag_decode_raw(value.to_raw()))
+	}
+}
+
+/// Error for when a value is out of range of the signed or unsigned variant it's converted to
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct SignError;
+
+impl fmt::Display for SignError {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("value out of range of the signed or unsigned type")
+	}
+}
+
+/// Sign of a value
+///
+/// The signs are ordered the same as the values they describe, with
+/// [`Sign::Negative`] being the smallest.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub enum Sign {
+	/// The value is less than zero
+	Negative,
+
+	/// The value is zero
+	Zero,
+
+	/// The value is greater than zero
+	Positive,
 }
 
 /// All types that are signed
@@ -35,62 +235,334 @@ impl<T: Signed<Signed = T>> IsSigned for T {}
 pub trait IsUnsigned: Signed<Unsigned = Self> {}
 impl<T: Signed<Unsigned = T>> IsUnsigned for T {}
 
+/// Macro to help implement the `const` versions of [`Signed`] and [`SignedExt`] through the raw bits of an integer
+///
+/// The integer and it's variants must have `to_raw` / `from_raw` functions in [`Const`], and arbitrary-width
+/// integers are given with their width, as `[N] UInt<N>`. The `@ext` form only implements the versions of
+/// [`SignedExt`], for integers that implement the versions of [`Signed`] themselves.
+macro_rules! impl_signed_const {
+	([$( $N:ident )?] $T:ty : - $TSigned:ty : + $TUnsigned:ty) => {
+		impl<$( const $N: u32 )?> $crate::Const<$T>
+		$( where $crate::Bits<$N>: $crate::Backing )?
+		{
+			/// Reinterprets `value` as unsigned
+			///
+			/// This is the `const` version of [`Signed::as_unsigned`](crate::Signed::as_unsigned).
+			#[must_use]
+			#[inline]
+			pub const fn as_unsigned(value: $T) -> $TUnsigned {
+				$crate::Const::<$TUnsigned>::from_raw($crate::Const::<$T>::to_raw(value))
+			}
+
+			/// Reinterprets `value` as signed
+			///
+			/// This is the `const` version of [`Signed::as_signed`](crate::Signed::as_signed).
+			#[must_use]
+			#[inline]
+			pub const fn as_signed(value: $T) -> $TSigned {
+				$crate::Const::<$TSigned>::from_raw($crate::Const::<$T>::to_raw(value))
+			}
+
+			/// Returns the absolute value of `value` as unsigned
+			///
+			/// This is the `const` version of [`Signed::abs_unsigned`](crate::Signed::abs_unsigned).
+			#[must_use]
+			#[inline]
+			pub const fn abs_unsigned(value: $T) -> $TUnsigned {
+				$crate::Const::<$TUnsigned>::from_raw($crate::raw::abs_raw::<$T>($crate::Const::<$T>::to_raw(value)))
+			}
+		}
+
+		$crate::sign::impl_signed_const! { @ext [$( $N )?] $T : -$TSigned : +$TUnsigned }
+	};
+
+	(@ext [$( $N:ident )?] $T:ty : - $TSigned:ty : + $TUnsigned:ty) => {
+		impl<$( const $N: u32 )?> $crate::Const<$T>
+		$( where $crate::Bits<$N>: $crate::Backing )?
+		{
+			/// Returns the sign of `value`
+			///
+			/// This is the `const` version of [`SignedExt::sign`](crate::SignedExt::sign).
+			#[must_use]
+			#[inline]
+			pub const fn sign(value: $T) -> $crate::Sign {
+				$crate::raw::sign_raw::<$T>($crate::Const::<$T>::to_raw(value))
+			}
+
+			/// Returns if `value` is less than zero
+			///
+			/// This is the `const` version of [`SignedExt::is_negative`](crate::SignedExt::is_negative).
+			#[must_use]
+			#[inline]
+			pub const fn is_negative(value: $T) -> bool {
+				$crate::raw::is_negative_raw::<$T>($crate::Const::<$T>::to_raw(value))
+			}
+
+			/// Returns if `value` is greater than zero
+			///
+			/// This is the `const` version of [`SignedExt::is_positive`](crate::SignedExt::is_positive).
+			#[must_use]
+			#[inline]
+			pub const fn is_positive(value: $T) -> bool {
+				matches!($crate::Const::<$T>::sign(value), $crate::Sign::Positive)
+			}
+
+			/// Returns `-1`, `0` or `1`, depending on the sign of `value`
+			///
+			/// This is the `const` version of [`SignedExt::signum`](crate::SignedExt::signum).
+			#[must_use]
+			#[inline]
+			pub const fn signum(value: $T) -> $T {
+				$crate::Const::<$T>::from_raw($crate::raw::signum_raw::<$T>($crate::Const::<$T>::to_raw(value)))
+			}
+
+			/// Converts `value` to signed, if it's in range of the signed variant
+			///
+			/// This is the `const` version of [`SignedExt::try_to_signed`](crate::SignedExt::try_to_signed).
+			///
+			/// # Errors
+			/// Returns [`SignError`](crate::SignError) if `value` is larger than the signed variant's maximum.
+			#[inline]
+			pub const fn try_to_signed(value: $T) -> Result<$TSigned, $crate::SignError> {
+				let raw = $crate::Const::<$T>::to_raw(value);
+				if $crate::raw::fits_raw::<$T, $TSigned>(raw) {
+					Ok($crate::Const::<$TSigned>::from_raw(raw))
+				} else {
+					Err($crate::SignError)
+				}
+			}
+
+			/// Converts `value` to unsigned, if it isn't negative
+			///
+			/// This is the `const` version of [`SignedExt::try_to_unsigned`](crate::SignedExt::try_to_unsigned).
+			///
+			/// # Errors
+			/// Returns [`SignError`](crate::SignError) if `value` is negative.
+			#[inline]
+			pub const fn try_to_unsigned(value: $T) -> Result<$TUnsigned, $crate::SignError> {
+				let raw = $crate::Const::<$T>::to_raw(value);
+				if $crate::raw::fits_raw::<$T, $TUnsigned>(raw) {
+					Ok($crate::Const::<$TUnsigned>::from_raw(raw))
+				} else {
+					Err($crate::SignError)
+				}
+			}
+
+			/// Converts `value` to signed, clamping it to the signed variant's maximum
+			///
+			/// This is the `const` version of [`SignedExt::saturating_to_signed`](crate::SignedExt::saturating_to_signed).
+			#[must_use]
+			#[inline]
+			pub const fn saturating_to_signed(value: $T) -> $TSigned {
+				$crate::Const::<$TSigned>::from_raw($crate::raw::saturate_raw::<$T, $TSigned>($crate::Const::<$T>::to_raw(value)))
+			}
+
+			/// Converts `value` to unsigned, clamping negative values to `0`
+			///
+			/// This is the `const` version of [`SignedExt::saturating_to_unsigned`](crate::SignedExt::saturating_to_unsigned).
+			#[must_use]
+			#[inline]
+			pub const fn saturating_to_unsigned(value: $T) -> $TUnsigned {
+				$crate::Const::<$TUnsigned>::from_raw($crate::raw::saturate_raw::<$T, $TUnsigned>($crate::Const::<$T>::to_raw(value)))
+			}
+
+			/// Reinterprets `value` as signed, returning if the value changed
+			///
+			/// This is the `const` version of [`SignedExt::overflowing_to_signed`](crate::SignedExt::overflowing_to_signed).
+			#[must_use]
+			#[inline]
+			pub const fn overflowing_to_signed(value: $T) -> ($TSigned, bool) {
+				let raw = $crate::Const::<$T>::to_raw(value);
+				($crate::Const::<$TSigned>::from_raw(raw), !$crate::raw::fits_raw::<$T, $TSigned>(raw))
+			}
+
+			/// Reinterprets `value` as unsigned, returning if the value changed
+			///
+			/// This is the `const` version of [`SignedExt::overflowing_to_unsigned`](crate::SignedExt::overflowing_to_unsigned).
+			#[must_use]
+			#[inline]
+			pub const fn overflowing_to_unsigned(value: $T) -> ($TUnsigned, bool) {
+				let raw = $crate::Const::<$T>::to_raw(value);
+				($crate::Const::<$TUnsigned>::from_raw(raw), !$crate::raw::fits_raw::<$T, $TUnsigned>(raw))
+			}
+
+			/// Creates an integer from it's sign and magnitude, if it's in range
+			///
+			/// This is the `const` version of [`SignedExt::try_from_sign_magnitude`](crate::SignedExt::try_from_sign_magnitude).
+			///
+			/// # Errors
+			/// Returns [`SignError`](crate::SignError) if the value is out of range of the integer.
+			#[inline]
+			pub const fn try_from_sign_magnitude(negative: bool, magnitude: $TUnsigned) -> Result<$T, $crate::SignError> {
+				if $crate::raw::fits_sign_magnitude_raw::<$T>(negative, $crate::Const::<$TUnsigned>::to_raw(magnitude)) {
+					Ok($crate::Const::<$T>::wrapping_from_sign_magnitude(negative, magnitude))
+				} else {
+					Err($crate::SignError)
+				}
+			}
+
+			/// Creates an integer from it's sign and magnitude, clamping it to the integer's range
+			///
+			/// This is the `const` version of [`SignedExt::saturating_from_sign_magnitude`](crate::SignedExt::saturating_from_sign_magnitude).
+			#[must_use]
+			#[inline]
+			pub const fn saturating_from_sign_magnitude(negative: bool, magnitude: $TUnsigned) -> $T {
+				let raw = $crate::Const::<$TUnsigned>::to_raw(magnitude);
+				$crate::Const::<$T>::from_raw($crate::raw::saturating_from_sign_magnitude_raw::<$T>(negative, raw))
+			}
+
+			/// Creates an integer from it's sign and magnitude, wrapping around at the integer's boundary
+			///
+			/// This is the `const` version of [`SignedExt::wrapping_from_sign_magnitude`](crate::SignedExt::wrapping_from_sign_magnitude).
+			#[must_use]
+			#[inline]
+			pub const fn wrapping_from_sign_magnitude(negative: bool, magnitude: $TUnsigned) -> $T {
+				let raw = $crate::Const::<$TUnsigned>::to_raw(magnitude);
+				$crate::Const::<$T>::from_raw($crate::raw::wrapping_from_sign_magnitude_raw(negative, raw))
+			}
+
+			/// Encodes `value`, reinterpreted as signed, with zigzag encoding
+			///
+			/// This is the `const` version of [`SignedExt::zigzag_encode`](crate::SignedExt::zigzag_encode).
+			#[must_use]
+			#[inline]
+			pub const fn zigzag_encode(value: $T) -> $TUnsigned {
+				$crate::Const::<$TUnsigned>::from_raw($crate::raw::zigzag_encode_raw::<$TSigned>($crate::Const::<$T>::to_raw(value)))
+			}
+
+			/// Decodes `value` from zigzag encoding
+			///
+			/// This is the `const` version of [`SignedExt::zigzag_decode`](crate::SignedExt::zigzag_decode).
+			#[must_use]
+			#[inline]
+			pub const fn zigzag_decode(value: $TUnsigned) -> $TSigned {
+				$crate::Const::<$TSigned>::from_raw($crate::raw::zigzag_decode_raw($crate::Const::<$TUnsigned>::to_raw(value)))
+			}
+		}
+	};
+}
+
+// Note: Exported by path, so it may be used by all modules, regardless of their order.
+pub(crate) use impl_signed_const;
+
 /// Macro to help implement [`Signed`]
 macro_rules! impl_signed {
 	(- $TSigned:ty : + $TUnsigned:ty) => {
 		// Make sure `T` has the same size as it's unsigned variant
 		::static_assertions::assert_eq_size!($TSigned, $TUnsigned);
 
+		impl Const<$TSigned> {
+			/// Reinterprets `value` as unsigned
+			///
+			/// This is the `const` version of [`Signed::as_unsigned`].
+			#[must_use]
+			#[inline]
+			#[allow(clippy::as_conversions)]
+			pub const fn as_unsigned(value: $TSigned) -> $TUnsigned {
+				// Casting between integers of the same size is a no-op
+				value as $TUnsigned
+			}
+
+			/// Reinterprets `value` as signed, returning it
+			///
+			/// This is the `const` version of [`Signed::as_signed`].
+			#[must_use]
+			#[inline]
+			pub const fn as_signed(value: $TSigned) -> $TSigned {
+				value
+			}
+
+			/// Returns the absolute value of `value` as unsigned
+			///
+			/// This is the `const` version of [`Signed::abs_unsigned`].
+			#[must_use]
+			#[inline]
+			pub const fn abs_unsigned(value: $TSigned) -> $TUnsigned {
+				// Note: Branch is optimized by compiler in release mode.
+				if value < 0 {
+					// Note: We don't use `-value.as_unsigned()` because it can panic
+					(!Const::<$TSigned>::as_unsigned(value)).wrapping_add(1)
+				} else {
+					Const::<$TSigned>::as_unsigned(value)
+				}
+			}
+		}
+
+		impl Const<$TUnsigned> {
+			/// Reinterprets `value` as unsigned, returning it
+			///
+			/// This is the `const` version of [`Signed::as_unsigned`].
+			#[must_use]
+			#[inline]
+			pub const fn as_unsigned(value: $TUnsigned) -> $TUnsigned {
+				value
+			}
+
+			/// Reinterprets `value` as signed
+			///
+			/// This is the `const` version of [`Signed::as_signed`].
+			#[must_use]
+			#[inline]
+			#[allow(clippy::as_conversions)]
+			pub const fn as_signed(value: $TUnsigned) -> $TSigned {
+				// Casting between integers of the same size is a no-op
+				value as $TSigned
+			}
+
+			/// Returns the absolute value of `value`, which is itself
+			///
+			/// This is the `const` version of [`Signed::abs_unsigned`].
+			#[must_use]
+			#[inline]
+			pub const fn abs_unsigned(value: $TUnsigned) -> $TUnsigned {
+				// Note: We're already unsigned
+				value
+			}
+		}
+
 		impl Signed for $TSigned {
 			type Signed = $TSigned;
 			type Unsigned = $TUnsigned;
 
 			#[inline]
-			#[allow(clippy::as_conversions)]
 			fn as_unsigned(self) -> Self::Unsigned {
-				// Casting between integers of the same size is a no-op
-				self as $TUnsigned
+				Const::<$TSigned>::as_unsigned(self)
 			}
 
 			#[inline]
 			fn as_signed(self) -> Self::Signed {
-				self
+				Const::<$TSigned>::as_signed(self)
 			}
 
 			#[inline]
 			fn abs_unsigned(self) -> Self::Unsigned {
-				// Note: Branch is optimized by compiler in release mode.
-				if self < 0 {
-					// Note: We don't use `-self.as_unsigned()` because it can panic
-					(!self.as_unsigned()).wrapping_add(1)
-				} else {
-					self.as_unsigned()
-				}
+				Const::<$TSigned>::abs_unsigned(self)
 			}
 		}
 
+		impl_signed_const! { @ext [] $TSigned : -$TSigned : +$TUnsigned }
+
 		impl Signed for $TUnsigned {
 			type Signed = $TSigned;
 			type Unsigned = $TUnsigned;
 
 			#[inline]
 			fn as_unsigned(self) -> Self::Unsigned {
-				self
+				Const::<$TUnsigned>::as_unsigned(self)
 			}
 
 			#[inline]
-			#[allow(clippy::as_conversions)]
 			fn as_signed(self) -> Self::Signed {
-				// Casting between integers of the same size is a no-op
-				self as $TSigned
+				Const::<$TUnsigned>::as_signed(self)
 			}
 
 			#[inline]
 			fn abs_unsigned(self) -> Self::Unsigned {
-				// Note: We're already unsigned
-				self
+				Const::<$TUnsigned>::abs_unsigned(self)
 			}
 		}
+
+		impl_signed_const! { @ext [] $TUnsigned : -$TSigned : +$TUnsigned }
 	};
 }
 
@@ -253,4 +725,306 @@ mod tests {
 		assert_eq!(i128 ::abs_unsigned(i128 ::MIN), u128 ::MAX / 2 + 1);
 		assert_eq!(isize::abs_unsigned(isize::MIN), usize::MAX / 2 + 1);
 	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn sign() {
+		assert_eq!(<i8    as SignedExt>::sign(-1        ), Sign::Negative);
+		assert_eq!(<i16   as SignedExt>::sign(0         ), Sign::Zero);
+		assert_eq!(<i32   as SignedExt>::sign(1         ), Sign::Positive);
+		assert_eq!(<i64   as SignedExt>::sign(i64  ::MIN), Sign::Negative);
+		assert_eq!(<i128  as SignedExt>::sign(i128 ::MAX), Sign::Positive);
+		assert_eq!(<isize as SignedExt>::sign(isize::MIN), Sign::Negative);
+		assert_eq!(<u8    as SignedExt>::sign(0         ), Sign::Zero);
+		assert_eq!(<u16   as SignedExt>::sign(u16  ::MAX), Sign::Positive);
+		assert_eq!(<u32   as SignedExt>::sign(1         ), Sign::Positive);
+		assert_eq!(<u64   as SignedExt>::sign(u64  ::MAX), Sign::Positive);
+		assert_eq!(<u128  as SignedExt>::sign(u128 ::MAX), Sign::Positive);
+		assert_eq!(<usize as SignedExt>::sign(usize::MAX), Sign::Positive);
+		assert!(Sign::Negative < Sign::Zero && Sign::Zero < Sign::Positive);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn is_negative_positive() {
+		assert!( <i8    as SignedExt>::is_negative(-1));
+		assert!(!<i8    as SignedExt>::is_negative( 0));
+		assert!(!<i8    as SignedExt>::is_positive( 0));
+		assert!( <i8    as SignedExt>::is_positive( 1));
+		assert!( <isize as SignedExt>::is_negative(isize::MIN));
+		assert!(!<u8    as SignedExt>::is_negative(u8::MAX));
+		assert!(!<u8    as SignedExt>::is_positive(0));
+		assert!( <u8    as SignedExt>::is_positive(u8::MAX));
+		assert!(!<usize as SignedExt>::is_negative(usize::MAX));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn signum() {
+		assert_eq!(<i8    as SignedExt>::signum(i8   ::MIN), -1);
+		assert_eq!(<i16   as SignedExt>::signum(0        ),  0);
+		assert_eq!(<i32   as SignedExt>::signum(i32  ::MAX),  1);
+		assert_eq!(<i64   as SignedExt>::signum(-5       ), -1);
+		assert_eq!(<i128  as SignedExt>::signum(i128 ::MAX),  1);
+		assert_eq!(<isize as SignedExt>::signum(isize::MIN), -1);
+		assert_eq!(<u8    as SignedExt>::signum(0        ),  0);
+		assert_eq!(<u16   as SignedExt>::signum(u16  ::MAX),  1);
+		assert_eq!(<u32   as SignedExt>::signum(5        ),  1);
+		assert_eq!(<u64   as SignedExt>::signum(0        ),  0);
+		assert_eq!(<u128  as SignedExt>::signum(u128 ::MAX),  1);
+		assert_eq!(<usize as SignedExt>::signum(usize::MAX),  1);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn try_to_signed() {
+		assert_eq!(u8   ::try_to_signed(u8   ::MAX / 2    ), Ok(i8   ::MAX));
+		assert_eq!(u16  ::try_to_signed(u16  ::MAX / 2 + 1), Err(SignError));
+		assert_eq!(u32  ::try_to_signed(u32  ::MAX        ), Err(SignError));
+		assert_eq!(u64  ::try_to_signed(0                 ), Ok(0));
+		assert_eq!(u128 ::try_to_signed(u128 ::MAX / 2    ), Ok(i128 ::MAX));
+		assert_eq!(usize::try_to_signed(usize::MAX / 2 + 1), Err(SignError));
+		assert_eq!(i8   ::try_to_signed(i8   ::MIN        ), Ok(i8   ::MIN));
+		assert_eq!(isize::try_to_signed(isize::MIN        ), Ok(isize::MIN));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn try_to_unsigned() {
+		assert_eq!(i8   ::try_to_unsigned(i8   ::MAX), Ok(u8   ::MAX / 2));
+		assert_eq!(i16  ::try_to_unsigned(-1        ), Err(SignError));
+		assert_eq!(i32  ::try_to_unsigned(i32  ::MIN), Err(SignError));
+		assert_eq!(i64  ::try_to_unsigned(0         ), Ok(0));
+		assert_eq!(i128 ::try_to_unsigned(i128 ::MAX), Ok(u128 ::MAX / 2));
+		assert_eq!(isize::try_to_unsigned(-1        ), Err(SignError));
+		assert_eq!(u8   ::try_to_unsigned(u8   ::MAX), Ok(u8   ::MAX));
+		assert_eq!(usize::try_to_unsigned(usize::MAX), Ok(usize::MAX));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn saturating_to() {
+		assert_eq!(200u8         .saturating_to_signed(), i8::MAX);
+		assert_eq!(100u8         .saturating_to_signed(), 100);
+		assert_eq!(usize::MAX    .saturating_to_signed(), isize::MAX);
+		assert_eq!((-56i8)       .saturating_to_signed(), -56);
+		assert_eq!((-56i8)       .saturating_to_unsigned(), 0);
+		assert_eq!(100i8         .saturating_to_unsigned(), 100);
+		assert_eq!(isize::MIN    .saturating_to_unsigned(), 0);
+		assert_eq!(u128::MAX     .saturating_to_unsigned(), u128::MAX);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn overflowing_to() {
+		assert_eq!(200u8     .overflowing_to_signed(), (-56, true));
+		assert_eq!(100u8     .overflowing_to_signed(), (100, false));
+		assert_eq!(usize::MAX.overflowing_to_signed(), (-1, true));
+		assert_eq!((-56i8)   .overflowing_to_signed(), (-56, false));
+		assert_eq!((-56i8)   .overflowing_to_unsigned(), (200, true));
+		assert_eq!(100i8     .overflowing_to_unsigned(), (100, false));
+		assert_eq!(isize::MIN.overflowing_to_unsigned(), (usize::MAX / 2 + 1, true));
+		assert_eq!(u128::MAX .overflowing_to_unsigned(), (u128::MAX, false));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn try_from_sign_magnitude_round_trip() {
+		assert_eq!(i8   ::try_from_sign_magnitude(true , i8   ::MIN.abs_unsigned()), Ok(i8   ::MIN));
+		assert_eq!(i16  ::try_from_sign_magnitude(true , i16  ::MIN.abs_unsigned()), Ok(i16  ::MIN));
+		assert_eq!(i32  ::try_from_sign_magnitude(true , i32  ::MIN.abs_unsigned()), Ok(i32  ::MIN));
+		assert_eq!(i64  ::try_from_sign_magnitude(true , i64  ::MIN.abs_unsigned()), Ok(i64  ::MIN));
+		assert_eq!(i128 ::try_from_sign_magnitude(true , i128 ::MIN.abs_unsigned()), Ok(i128 ::MIN));
+		assert_eq!(isize::try_from_sign_magnitude(true , isize::MIN.abs_unsigned()), Ok(isize::MIN));
+		assert_eq!(i8   ::try_from_sign_magnitude(false, i8   ::MAX.abs_unsigned()), Ok(i8   ::MAX));
+		assert_eq!(i16  ::try_from_sign_magnitude(false, i16  ::MAX.abs_unsigned()), Ok(i16  ::MAX));
+		assert_eq!(i32  ::try_from_sign_magnitude(false, i32  ::MAX.abs_unsigned()), Ok(i32  ::MAX));
+		assert_eq!(i64  ::try_from_sign_magnitude(false, i64  ::MAX.abs_unsigned()), Ok(i64  ::MAX));
+		assert_eq!(i128 ::try_from_sign_magnitude(false, i128 ::MAX.abs_unsigned()), Ok(i128 ::MAX));
+		assert_eq!(isize::try_from_sign_magnitude(false, isize::MAX.abs_unsigned()), Ok(isize::MAX));
+		assert_eq!(i8   ::try_from_sign_magnitude(true , 1), Ok(-1));
+		assert_eq!(i16  ::try_from_sign_magnitude(true , 1), Ok(-1));
+		assert_eq!(i32  ::try_from_sign_magnitude(true , 1), Ok(-1));
+		assert_eq!(i64  ::try_from_sign_magnitude(true , 1), Ok(-1));
+		assert_eq!(i128 ::try_from_sign_magnitude(true , 1), Ok(-1));
+		assert_eq!(isize::try_from_sign_magnitude(true , 1), Ok(-1));
+		assert_eq!(i8   ::try_from_sign_magnitude(true , 0), Ok(0));
+		assert_eq!(i16  ::try_from_sign_magnitude(true , 0), Ok(0));
+		assert_eq!(i32  ::try_from_sign_magnitude(true , 0), Ok(0));
+		assert_eq!(i64  ::try_from_sign_magnitude(true , 0), Ok(0));
+		assert_eq!(i128 ::try_from_sign_magnitude(true , 0), Ok(0));
+		assert_eq!(isize::try_from_sign_magnitude(true , 0), Ok(0));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn try_from_sign_magnitude_out_of_range() {
+		assert_eq!(i8   ::try_from_sign_magnitude(true , i8   ::MIN.abs_unsigned() + 1), Err(SignError));
+		assert_eq!(i16  ::try_from_sign_magnitude(true , i16  ::MIN.abs_unsigned() + 1), Err(SignError));
+		assert_eq!(i32  ::try_from_sign_magnitude(true , i32  ::MIN.abs_unsigned() + 1), Err(SignError));
+		assert_eq!(i64  ::try_from_sign_magnitude(true , i64  ::MIN.abs_unsigned() + 1), Err(SignError));
+		assert_eq!(i128 ::try_from_sign_magnitude(true , i128 ::MIN.abs_unsigned() + 1), Err(SignError));
+		assert_eq!(isize::try_from_sign_magnitude(true , isize::MIN.abs_unsigned() + 1), Err(SignError));
+		assert_eq!(i8   ::try_from_sign_magnitude(false, i8   ::MIN.abs_unsigned()    ), Err(SignError));
+		assert_eq!(i16  ::try_from_sign_magnitude(false, i16  ::MIN.abs_unsigned()    ), Err(SignError));
+		assert_eq!(i32  ::try_from_sign_magnitude(false, i32  ::MIN.abs_unsigned()    ), Err(SignError));
+		assert_eq!(i64  ::try_from_sign_magnitude(false, i64  ::MIN.abs_unsigned()    ), Err(SignError));
+		assert_eq!(i128 ::try_from_sign_magnitude(false, i128 ::MIN.abs_unsigned()    ), Err(SignError));
+		assert_eq!(isize::try_from_sign_magnitude(false, isize::MIN.abs_unsigned()    ), Err(SignError));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn try_from_sign_magnitude_unsigned() {
+		assert_eq!(u8   ::try_from_sign_magnitude(false, u8   ::MAX), Ok(u8   ::MAX));
+		assert_eq!(u16  ::try_from_sign_magnitude(false, u16  ::MAX), Ok(u16  ::MAX));
+		assert_eq!(u32  ::try_from_sign_magnitude(false, u32  ::MAX), Ok(u32  ::MAX));
+		assert_eq!(u64  ::try_from_sign_magnitude(false, u64  ::MAX), Ok(u64  ::MAX));
+		assert_eq!(u128 ::try_from_sign_magnitude(false, u128 ::MAX), Ok(u128 ::MAX));
+		assert_eq!(usize::try_from_sign_magnitude(false, usize::MAX), Ok(usize::MAX));
+		assert_eq!(u8   ::try_from_sign_magnitude(true , 0        ), Ok(0));
+		assert_eq!(u16  ::try_from_sign_magnitude(true , 0        ), Ok(0));
+		assert_eq!(u32  ::try_from_sign_magnitude(true , 0        ), Ok(0));
+		assert_eq!(u64  ::try_from_sign_magnitude(true , 0        ), Ok(0));
+		assert_eq!(u128 ::try_from_sign_magnitude(true , 0        ), Ok(0));
+		assert_eq!(usize::try_from_sign_magnitude(true , 0        ), Ok(0));
+		assert_eq!(u8   ::try_from_sign_magnitude(true , 1        ), Err(SignError));
+		assert_eq!(u16  ::try_from_sign_magnitude(true , 1        ), Err(SignError));
+		assert_eq!(u32  ::try_from_sign_magnitude(true , 1        ), Err(SignError));
+		assert_eq!(u64  ::try_from_sign_magnitude(true , 1        ), Err(SignError));
+		assert_eq!(u128 ::try_from_sign_magnitude(true , 1        ), Err(SignError));
+		assert_eq!(usize::try_from_sign_magnitude(true , 1        ), Err(SignError));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn saturating_from_sign_magnitude() {
+		assert_eq!(i8   ::saturating_from_sign_magnitude(true , u8   ::MAX), i8   ::MIN);
+		assert_eq!(i16  ::saturating_from_sign_magnitude(true , u16  ::MAX), i16  ::MIN);
+		assert_eq!(i32  ::saturating_from_sign_magnitude(true , u32  ::MAX), i32  ::MIN);
+		assert_eq!(i64  ::saturating_from_sign_magnitude(true , u64  ::MAX), i64  ::MIN);
+		assert_eq!(i128 ::saturating_from_sign_magnitude(true , u128 ::MAX), i128 ::MIN);
+		assert_eq!(isize::saturating_from_sign_magnitude(true , usize::MAX), isize::MIN);
+		assert_eq!(i8   ::saturating_from_sign_magnitude(false, u8   ::MAX), i8   ::MAX);
+		assert_eq!(i16  ::saturating_from_sign_magnitude(false, u16  ::MAX), i16  ::MAX);
+		assert_eq!(i32  ::saturating_from_sign_magnitude(false, u32  ::MAX), i32  ::MAX);
+		assert_eq!(i64  ::saturating_from_sign_magnitude(false, u64  ::MAX), i64  ::MAX);
+		assert_eq!(i128 ::saturating_from_sign_magnitude(false, u128 ::MAX), i128 ::MAX);
+		assert_eq!(isize::saturating_from_sign_magnitude(false, usize::MAX), isize::MAX);
+		assert_eq!(u8   ::saturating_from_sign_magnitude(true , u8   ::MAX), 0);
+		assert_eq!(u16  ::saturating_from_sign_magnitude(true , u16  ::MAX), 0);
+		assert_eq!(u32  ::saturating_from_sign_magnitude(true , u32  ::MAX), 0);
+		assert_eq!(u64  ::saturating_from_sign_magnitude(true , u64  ::MAX), 0);
+		assert_eq!(u128 ::saturating_from_sign_magnitude(true , u128 ::MAX), 0);
+		assert_eq!(usize::saturating_from_sign_magnitude(true , usize::MAX), 0);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn wrapping_from_sign_magnitude() {
+		assert_eq!(i8   ::wrapping_from_sign_magnitude(false, u8   ::MAX), -1);
+		assert_eq!(i16  ::wrapping_from_sign_magnitude(false, u16  ::MAX), -1);
+		assert_eq!(i32  ::wrapping_from_sign_magnitude(false, u32  ::MAX), -1);
+		assert_eq!(i64  ::wrapping_from_sign_magnitude(false, u64  ::MAX), -1);
+		assert_eq!(i128 ::wrapping_from_sign_magnitude(false, u128 ::MAX), -1);
+		assert_eq!(isize::wrapping_from_sign_magnitude(false, usize::MAX), -1);
+		assert_eq!(i8   ::wrapping_from_sign_magnitude(true , i8   ::MIN.abs_unsigned()), i8   ::MIN);
+		assert_eq!(i16  ::wrapping_from_sign_magnitude(true , i16  ::MIN.abs_unsigned()), i16  ::MIN);
+		assert_eq!(i32  ::wrapping_from_sign_magnitude(true , i32  ::MIN.abs_unsigned()), i32  ::MIN);
+		assert_eq!(i64  ::wrapping_from_sign_magnitude(true , i64  ::MIN.abs_unsigned()), i64  ::MIN);
+		assert_eq!(i128 ::wrapping_from_sign_magnitude(true , i128 ::MIN.abs_unsigned()), i128 ::MIN);
+		assert_eq!(isize::wrapping_from_sign_magnitude(true , isize::MIN.abs_unsigned()), isize::MIN);
+		assert_eq!(u8   ::wrapping_from_sign_magnitude(true , 1), u8   ::MAX);
+		assert_eq!(u16  ::wrapping_from_sign_magnitude(true , 1), u16  ::MAX);
+		assert_eq!(u32  ::wrapping_from_sign_magnitude(true , 1), u32  ::MAX);
+		assert_eq!(u64  ::wrapping_from_sign_magnitude(true , 1), u64  ::MAX);
+		assert_eq!(u128 ::wrapping_from_sign_magnitude(true , 1), u128 ::MAX);
+		assert_eq!(usize::wrapping_from_sign_magnitude(true , 1), usize::MAX);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn zigzag_encode() {
+		assert_eq!(i8   ::zigzag_encode(-1), 1);
+		assert_eq!(i16  ::zigzag_encode(-1), 1);
+		assert_eq!(i32  ::zigzag_encode(-1), 1);
+		assert_eq!(i64  ::zigzag_encode(-1), 1);
+		assert_eq!(i128 ::zigzag_encode(-1), 1);
+		assert_eq!(isize::zigzag_encode(-1), 1);
+		assert_eq!(i8   ::zigzag_encode(i8   ::MIN), u8   ::MAX);
+		assert_eq!(i16  ::zigzag_encode(i16  ::MIN), u16  ::MAX);
+		assert_eq!(i32  ::zigzag_encode(i32  ::MIN), u32  ::MAX);
+		assert_eq!(i64  ::zigzag_encode(i64  ::MIN), u64  ::MAX);
+		assert_eq!(i128 ::zigzag_encode(i128 ::MIN), u128 ::MAX);
+		assert_eq!(isize::zigzag_encode(isize::MIN), usize::MAX);
+		assert_eq!(i8   ::zigzag_encode(i8   ::MAX), u8   ::MAX - 1);
+		assert_eq!(i16  ::zigzag_encode(i16  ::MAX), u16  ::MAX - 1);
+		assert_eq!(i32  ::zigzag_encode(i32  ::MAX), u32  ::MAX - 1);
+		assert_eq!(i64  ::zigzag_encode(i64  ::MAX), u64  ::MAX - 1);
+		assert_eq!(i128 ::zigzag_encode(i128 ::MAX), u128 ::MAX - 1);
+		assert_eq!(isize::zigzag_encode(isize::MAX), usize::MAX - 1);
+		assert_eq!(u8   ::zigzag_encode(u8   ::MAX), 1);
+		assert_eq!(u16  ::zigzag_encode(u16  ::MAX), 1);
+		assert_eq!(u32  ::zigzag_encode(u32  ::MAX), 1);
+		assert_eq!(u64  ::zigzag_encode(u64  ::MAX), 1);
+		assert_eq!(u128 ::zigzag_encode(u128 ::MAX), 1);
+		assert_eq!(usize::zigzag_encode(usize::MAX), 1);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn zigzag_decode() {
+		assert_eq!(i8   ::zigzag_decode(1), -1);
+		assert_eq!(i16  ::zigzag_decode(1), -1);
+		assert_eq!(i32  ::zigzag_decode(1), -1);
+		assert_eq!(i64  ::zigzag_decode(1), -1);
+		assert_eq!(i128 ::zigzag_decode(1), -1);
+		assert_eq!(isize::zigzag_decode(1), -1);
+		assert_eq!(i8   ::zigzag_decode(u8   ::MAX), i8   ::MIN);
+		assert_eq!(i16  ::zigzag_decode(u16  ::MAX), i16  ::MIN);
+		assert_eq!(i32  ::zigzag_decode(u32  ::MAX), i32  ::MIN);
+		assert_eq!(i64  ::zigzag_decode(u64  ::MAX), i64  ::MIN);
+		assert_eq!(i128 ::zigzag_decode(u128 ::MAX), i128 ::MIN);
+		assert_eq!(isize::zigzag_decode(usize::MAX), isize::MIN);
+		assert_eq!(u8   ::zigzag_decode(u8   ::MAX - 1), i8   ::MAX);
+		assert_eq!(u16  ::zigzag_decode(u16  ::MAX - 1), i16  ::MAX);
+		assert_eq!(u32  ::zigzag_decode(u32  ::MAX - 1), i32  ::MAX);
+		assert_eq!(u64  ::zigzag_decode(u64  ::MAX - 1), i64  ::MAX);
+		assert_eq!(u128 ::zigzag_decode(u128 ::MAX - 1), i128 ::MAX);
+		assert_eq!(usize::zigzag_decode(usize::MAX - 1), isize::MAX);
+	}
+
+	#[test]
+	fn zigzag_exhaustive_8() {
+		for value in i8::MIN..=i8::MAX {
+			let expected = if value >= 0 { 2 * i32::from(value) } else { -2 * i32::from(value) - 1 };
+			assert_eq!(i32::from(value.zigzag_encode()), expected);
+			assert_eq!(i8::zigzag_decode(value.zigzag_encode()), value);
+			assert_eq!(value.as_unsigned().zigzag_encode(), value.zigzag_encode());
+		}
+	}
+
+	#[test]
+	fn zigzag_exhaustive_16() {
+		for value in i16::MIN..=i16::MAX {
+			let expected = if value >= 0 { 2 * i32::from(value) } else { -2 * i32::from(value) - 1 };
+			assert_eq!(i32::from(value.zigzag_encode()), expected);
+			assert_eq!(i16::zigzag_decode(value.zigzag_encode()), value);
+			assert_eq!(value.as_unsigned().zigzag_encode(), value.zigzag_encode());
+		}
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn signed_ext_generic() {
+		fn sign_of<T: SignedExt>(value: T) -> Sign {
+			value.sign()
+		}
+
+		assert_eq!(sign_of(0u8), Sign::Zero);
+		assert_eq!(sign_of(-1i64), Sign::Negative);
+		assert_eq!(sign_of(crate::i24::MIN), Sign::Negative);
+		assert_eq!(sign_of(crate::UInt::<5>::MAX), Sign::Positive);
+		assert_eq!(sign_of(core::num::Wrapping(-1i8)), Sign::Negative);
+	}
 }
@@ -0,0 +1,227 @@
+//! Slice conversions
+//!
+//! This module provides [`ConvertSlice`], which converts every integer of a slice
+//! into a slice of the same length, with any of the crate's conversions.
+//!
+//! Each conversion is a single loop over both slices, after checking their lengths,
+//! so that the compiler may remove all bounds checks and vectorize it.
+
+// Imports
+use crate::{Extend, SaturatingTruncate, SignExtend, Signed, Truncate, ZeroExtend};
+use core::fmt;
+
+/// Error for when the source and destination slices of a conversion have different lengths
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct LengthError {
+	/// Length of the source slice
+	pub src: usize,
+
+	/// Length of the destination slice
+	pub dst: usize,
+}
+
+impl fmt::Display for LengthError {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "source slice has {} integers, but destination slice has {}", self.src, self.dst)
+	}
+}
+
+/// Converts `src` into `dst` with `f`
+///
+/// Nothing is written to `dst` if the lengths differ.
+#[inline]
+fn convert<T: Copy, U>(src: &[T], dst: &mut [U], f: impl Fn(T) -> U) -> Result<(), LengthError> {
+	if src.len() != dst.len() {
+		return Err(LengthError {
+			src: src.len(),
+			dst: dst.len(),
+		});
+	}
+
+	// Note: With the lengths known to be equal, zipping lets the compiler remove the bounds checks
+	for (dst, &src) in dst.iter_mut().zip(src) {
+		*dst = f(src);
+	}
+
+	Ok(())
+}
+
+/// Conversions of slices of integers
+///
+/// Each method converts every integer of this slice into the integer at the same index of `dst`.
+///
+/// # Errors
+/// Each method returns [`LengthError`] if `dst` doesn't have the same length as this slice,
+/// in which case `dst` is left unchanged.
+pub trait ConvertSlice {
+	/// Type of the integers in this slice
+	type Item: Copy;
+
+	/// Zero extends this slice into `dst`
+	///
+	/// # Errors
+	/// Returns [`LengthError`] if the lengths differ.
+	fn zero_extend_slice<U>(&self, dst: &mut [U]) -> Result<(), LengthError>
+	where
+		Self::Item: ZeroExtend<U>;
+
+	/// Sign extends this slice into `dst`
+	///
+	/// # Errors
+	/// Returns [`LengthError`] if the lengths differ.
+	fn sign_extend_slice<U>(&self, dst: &mut [U]) -> Result<(), LengthError>
+	where
+		Self::Item: SignExtend<U>;
+
+	/// Extends this slice into `dst`
+	///
+	/// # Errors
+	/// Returns [`LengthError`] if the lengths differ.
+	fn extend_slice<U>(&self, dst: &mut [U]) -> Result<(), LengthError>
+	where
+		Self::Item: Extend<U>;
+
+	/// Truncates this slice into `dst`
+	///
+	/// # Errors
+	/// Returns [`LengthError`] if the lengths differ.
+	fn truncate_slice<U>(&self, dst: &mut [U]) -> Result<(), LengthError>
+	where
+		Self::Item: Truncate<U>;
+
+	/// Truncates this slice into `dst`, clamping each integer to the range of `U`
+	///
+	/// # Errors
+	/// Returns [`LengthError`] if the lengths differ.
+	fn saturating_truncate_slice<U>(&self, dst: &mut [U]) -> Result<(), LengthError>
+	where
+		Self::Item: SaturatingTruncate<U>;
+
+	/// Reinterprets this slice as signed into `dst`
+	///
+	/// # Errors
+	/// Returns [`LengthError`] if the lengths differ.
+	fn as_signed_slice_into(&self, dst: &mut [<Self::Item as Signed>::Signed]) -> Result<(), LengthError>
+	where
+		Self::Item: Signed;
+
+	/// Reinterprets this slice as unsigned into `dst`
+	///
+	/// # Errors
+	/// Returns [`LengthError`] if the lengths differ.
+	fn as_unsigned_slice_into(&self, dst: &mut [<Self::Item as Signed>::Unsigned]) -> Result<(), LengthError>
+	where
+		Self::Item: Signed;
+}
+
+impl<T: Copy> ConvertSlice for [T] {
+	type Item = T;
+
+	#[inline]
+	fn zero_extend_slice<U>(&self, dst: &mut [U]) -> Result<(), LengthError>
+	where
+		T: ZeroExtend<U>,
+	{
+		convert(self, dst, T::zero_extend)
+	}
+
+	#[inline]
+	fn sign_extend_slice<U>(&self, dst: &mut [U]) -> Result<(), LengthError>
+	where
+		T: SignExtend<U>,
+	{
+		convert(self, dst, T::sign_extend)
+	}
+
+	#[inline]
+	fn extend_slice<U>(&self, dst: &mut [U]) -> Result<(), LengthError>
+	where
+		T: Extend<U>,
+	{
+		convert(self, dst, T::extend)
+	}
+
+	#[inline]
+	fn truncate_slice<U>(&self, dst: &mut [U]) -> Result<(), LengthError>
+	where
+		T: Truncate<U>,
+	{
+		convert(self, dst, T::truncate)
+	}
+
+	#[inline]
+	fn saturating_truncate_slice<U>(&self, dst: &mut [U]) -> Result<(), LengthError>
+	where
+		T: SaturatingTruncate<U>,
+	{
+		convert(self, dst, T::saturating_truncate)
+	}
+
+	#[inline]
+	fn as_signed_slice_into(&self, dst: &mut [T::Signed]) -> Result<(), LengthError>
+	where
+		T: Signed,
+	{
+		convert(self, dst, T::as_signed)
+	}
+
+	#[inline]
+	fn as_unsigned_slice_into(&self, dst: &mut [T::Unsigned]) -> Result<(), LengthError>
+	where
+		T: Signed,
+	{
+		convert(self, dst, T::as_unsigned)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use super::*;
+	use crate::{i24, u24};
+
+	#[test]
+	#[rustfmt::skip]
+	fn extend_slice() {
+		let mut dst = [0i32; 4];
+		assert_eq!([0x00u8, 0x7f, 0x80, 0xff].extend_slice(&mut dst), Ok(())); assert_eq!(dst, [0x00, 0x7f, 0x80, 0xff]);
+		assert_eq!([0x00u8, 0x7f, 0x80, 0xff].sign_extend_slice(&mut dst), Ok(())); assert_eq!(dst, [0, 0x7f, -0x80, -1]);
+		assert_eq!([0i8, 127, -128, -1].zero_extend_slice(&mut dst), Ok(())); assert_eq!(dst, [0x00, 0x7f, 0x80, 0xff]);
+		assert_eq!([0i8, 127, -128, -1].extend_slice(&mut dst), Ok(())); assert_eq!(dst, [0, 127, -128, -1]);
+
+		let mut dst = [i24::MIN; 2];
+		assert_eq!([-1i16, 1].extend_slice(&mut dst), Ok(())); assert_eq!(dst, [i24::new(-1).unwrap(), i24::new(1).unwrap()]);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn truncate_slice() {
+		let mut dst = [0u32; 3];
+		assert_eq!([0x1_0000_0002u64, u64::MAX, 5].truncate_slice(&mut dst), Ok(())); assert_eq!(dst, [2, u32::MAX, 5]);
+		assert_eq!([0x1_0000_0002u64, u64::MAX, 5].saturating_truncate_slice(&mut dst), Ok(())); assert_eq!(dst, [u32::MAX, u32::MAX, 5]);
+
+		let mut dst = [u24::MIN; 2];
+		assert_eq!([0x1234_5678u32, 1].truncate_slice(&mut dst), Ok(())); assert_eq!(dst, [u24::new(0x34_5678).unwrap(), u24::new(1).unwrap()]);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn as_signed_slice_into() {
+		let mut signed = [0i16; 3];
+		let mut unsigned = [0u16; 3];
+		assert_eq!([0u16, 0x7fff, 0xffff].as_signed_slice_into(&mut signed), Ok(())); assert_eq!(signed, [0, i16::MAX, -1]);
+		assert_eq!(signed.as_unsigned_slice_into(&mut unsigned), Ok(())); assert_eq!(unsigned, [0, 0x7fff, 0xffff]);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn length_mismatch() {
+		let mut dst = [7u32; 2];
+		assert_eq!([1u8, 2, 3].extend_slice(&mut dst), Err(LengthError { src: 3, dst: 2 }));
+		assert_eq!([1u64].truncate_slice(&mut dst), Err(LengthError { src: 1, dst: 2 }));
+		assert_eq!([1i32].as_unsigned_slice_into(&mut dst), Err(LengthError { src: 1, dst: 2 }));
+		assert_eq!(dst, [7, 7]);
+		assert_eq!([0u8; 0].extend_slice(&mut [0u32; 0]), Ok(()));
+	}
+}
@@ -3,14 +3,23 @@
 //! This module provides ways to split bigger integers
 //! into smaller integers and then join them back together.
 
+// Modules
+pub mod array;
+pub mod endian;
+
+// Exports
+pub use array::{JoinFrom, SplitInto};
+pub use endian::{SplitEndian, SplitUnits};
+
 // Imports
-use super::{Truncate, ZeroExtend};
-use core::{
-	mem,
-	ops::{Shl, Shr},
-};
+use crate::Const;
+use core::mem;
 
 /// Splits an integer into it's low and high part
+///
+/// For signed integers, the low part is unsigned, as it holds no sign,
+/// while the high part is signed and holds the sign of the integer,
+/// e.g. `i32` splits into a `u16` low part and an `i16` high part.
 pub trait Split: Sized {
 	/// Output type for higher part
 	type Hi;
@@ -31,7 +40,7 @@ pub trait Split: Sized {
 /// Joins two integers into a larger one.
 pub trait Join: Split {
 	/// Joins two parts of an integer
-	fn join(lo: <Self as Split>::Lo, hi: <Self as Split>::Lo) -> Self;
+	fn join(lo: <Self as Split>::Lo, hi: <Self as Split>::Hi) -> Self;
 }
 
 /// Macro to help implement `Split` / `Join`
@@ -46,32 +55,72 @@ macro_rules! impl_split_join {
 		// Make sure that `T` is made up of `Lo` and `Hi`
 		::static_assertions::assert_eq_size!($T, ($Lo, $Hi));
 
+		impl Const<$T> {
+			/// Returns the high part of `value`
+			///
+			/// This is the `const` version of [`Split::hi`].
+			#[must_use]
+			#[inline]
+			pub const fn hi(value: $T) -> $Hi {
+				// Note: For signed integers, this shift is arithmetic, so the sign is kept
+				Const::<$T, $Hi>::truncate(value >> (8 * mem::size_of::<$Lo>()))
+			}
+
+			/// Returns the low part of `value`
+			///
+			/// This is the `const` version of [`Split::lo`].
+			#[must_use]
+			#[inline]
+			pub const fn lo(value: $T) -> $Lo {
+				Const::<$T, $Lo>::truncate(value)
+			}
+
+			/// Returns the low and high part of `value`
+			///
+			/// This is the `const` version of [`Split::lo_hi`].
+			#[must_use]
+			#[inline]
+			pub const fn lo_hi(value: $T) -> ($Lo, $Hi) {
+				let lo = Const::<$T>::lo(value);
+				let hi = Const::<$T>::hi(value);
+				(lo, hi)
+			}
+
+			/// Joins the low and high part of an integer
+			///
+			/// This is the `const` version of [`Join::join`].
+			#[must_use]
+			#[inline]
+			pub const fn join(lo: $Lo, hi: $Hi) -> $T {
+				// Note: Any bits extended from `hi` are shifted out, so it doesn't matter how we extend it
+				Const::<$Hi, $T>::extend(hi) << (8 * mem::size_of::<$Lo>()) | Const::<$Lo, $T>::zero_extend(lo)
+			}
+		}
+
 		impl Split for $T {
 			type Hi = $Hi;
 			type Lo = $Lo;
 
 			#[inline]
 			fn lo(self) -> Self::Lo {
-				<Self as Truncate<Self::Lo>>::truncate(self)
+				Const::<$T>::lo(self)
 			}
 
 			#[inline]
 			fn hi(self) -> Self::Hi {
-				<Self as Truncate<Self::Lo>>::truncate(self.shr(8 * mem::size_of::<Self::Lo>()))
+				Const::<$T>::hi(self)
 			}
 
 			#[inline]
 			fn lo_hi(self) -> (Self::Lo, Self::Hi) {
-				let lo = self.lo();
-				let hi = self.hi();
-				(lo, hi)
+				Const::<$T>::lo_hi(self)
 			}
 		}
 
 		impl Join for $T {
 			#[inline]
-			fn join(lo: <Self as Split>::Lo, hi: <Self as Split>::Lo) -> Self {
-				<$Hi as ZeroExtend<$T>>::zero_extend(hi).shl(8 * mem::size_of::<Self::Lo>()) | <$Lo as ZeroExtend<$T>>::zero_extend(lo)
+			fn join(lo: <Self as Split>::Lo, hi: <Self as Split>::Hi) -> Self {
+				Const::<$T>::join(lo, hi)
 			}
 		}
 	};
@@ -84,31 +133,68 @@ impl_split_join! { u32  => u16 : u16 }
 impl_split_join! { u16  => u8  : u8  }
 
 // Signed
-// TODO: Confirm these, should they even exist? Should `Lo` be unsigned?
-//impl_split_join! { i128 => i64 : i64 }
-//impl_split_join! { i64  => i32 : i32 }
-//impl_split_join! { i32  => i16 : i16 }
-//impl_split_join! { i16  => i8  : i8  }
+impl_split_join! { i128 => i64 : u64 }
+impl_split_join! { i64  => i32 : u32 }
+impl_split_join! { i32  => i16 : u16 }
+impl_split_join! { i16  => i8  : u8  }
+
+// Pointer-sized
+#[cfg(target_pointer_width = "64")]
+impl_split_join! { usize => u32 : u32 }
+#[cfg(target_pointer_width = "64")]
+impl_split_join! { isize => i32 : u32 }
+#[cfg(target_pointer_width = "32")]
+impl_split_join! { usize => u16 : u16 }
+#[cfg(target_pointer_width = "32")]
+impl_split_join! { isize => i16 : u16 }
+#[cfg(target_pointer_width = "16")]
+impl_split_join! { usize => u8  : u8  }
+#[cfg(target_pointer_width = "16")]
+impl_split_join! { isize => i8  : u8  }
 
 // Check that they all implement `Split` / `Join`
-//static_assertions::assert_impl_all! { i16  : Split, Join }
-//static_assertions::assert_impl_all! { i32  : Split, Join }
-//static_assertions::assert_impl_all! { i64  : Split, Join }
-//static_assertions::assert_impl_all! { i128 : Split, Join }
+static_assertions::assert_impl_all! { i16  : Split, Join }
+static_assertions::assert_impl_all! { i32  : Split, Join }
+static_assertions::assert_impl_all! { i64  : Split, Join }
+static_assertions::assert_impl_all! { i128 : Split, Join }
+static_assertions::assert_impl_all! { isize: Split, Join }
 static_assertions::assert_impl_all! { u16  : Split, Join }
 static_assertions::assert_impl_all! { u32  : Split, Join }
 static_assertions::assert_impl_all! { u64  : Split, Join }
 static_assertions::assert_impl_all! { u128 : Split, Join }
+static_assertions::assert_impl_all! { usize: Split, Join }
 
 // Check that all associated types are correct
-//static_assertions::assert_type_eq_all! { <i16   as Split>::Lo, <i16   as Split>::Hi, i8  }
-//static_assertions::assert_type_eq_all! { <i32   as Split>::Lo, <i32   as Split>::Hi, i16 }
-//static_assertions::assert_type_eq_all! { <i64   as Split>::Lo, <i64   as Split>::Hi, i32 }
-//static_assertions::assert_type_eq_all! { <i128  as Split>::Lo, <i128  as Split>::Hi, i64 }
+static_assertions::assert_type_eq_all! { <i16   as Split>::Lo, u8  }
+static_assertions::assert_type_eq_all! { <i32   as Split>::Lo, u16 }
+static_assertions::assert_type_eq_all! { <i64   as Split>::Lo, u32 }
+static_assertions::assert_type_eq_all! { <i128  as Split>::Lo, u64 }
+static_assertions::assert_type_eq_all! { <i16   as Split>::Hi, i8  }
+static_assertions::assert_type_eq_all! { <i32   as Split>::Hi, i16 }
+static_assertions::assert_type_eq_all! { <i64   as Split>::Hi, i32 }
+static_assertions::assert_type_eq_all! { <i128  as Split>::Hi, i64 }
 static_assertions::assert_type_eq_all! { <u16   as Split>::Lo, <u16   as Split>::Hi, u8  }
 static_assertions::assert_type_eq_all! { <u32   as Split>::Lo, <u32   as Split>::Hi, u16 }
 static_assertions::assert_type_eq_all! { <u64   as Split>::Lo, <u64   as Split>::Hi, u32 }
 static_assertions::assert_type_eq_all! { <u128  as Split>::Lo, <u128  as Split>::Hi, u64 }
+#[cfg(target_pointer_width = "64")]
+static_assertions::assert_type_eq_all! { <usize as Split>::Lo, <usize as Split>::Hi, u32 }
+#[cfg(target_pointer_width = "32")]
+static_assertions::assert_type_eq_all! { <usize as Split>::Lo, <usize as Split>::Hi, u16 }
+#[cfg(target_pointer_width = "16")]
+static_assertions::assert_type_eq_all! { <usize as Split>::Lo, <usize as Split>::Hi, u8  }
+#[cfg(target_pointer_width = "64")]
+static_assertions::assert_type_eq_all! { <isize as Split>::Lo, u32 }
+#[cfg(target_pointer_width = "64")]
+static_assertions::assert_type_eq_all! { <isize as Split>::Hi, i32 }
+#[cfg(target_pointer_width = "32")]
+static_assertions::assert_type_eq_all! { <isize as Split>::Lo, u16 }
+#[cfg(target_pointer_width = "32")]
+static_assertions::assert_type_eq_all! { <isize as Split>::Hi, i16 }
+#[cfg(target_pointer_width = "16")]
+static_assertions::assert_type_eq_all! { <isize as Split>::Lo, u8  }
+#[cfg(target_pointer_width = "16")]
+static_assertions::assert_type_eq_all! { <isize as Split>::Hi, i8  }
 
 #[cfg(test)]
 mod tests {
@@ -167,4 +253,80 @@ mod tests {
 		assert_eq!( u32::lo_hi( u32::from(u16::MAX)), ( u32::lo( u32::from(u16::MAX)),  u32::hi( u32::from(u16::MAX))));
 		assert_eq!( u16::lo_hi( u16::from( u8::MAX)), ( u16::lo( u16::from( u8::MAX)),  u16::hi( u16::from( u8::MAX))));
 	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn split_signed() {
+		assert_eq!(i128::lo_hi(-1), (u64::MAX, -1));
+		assert_eq!( i64::lo_hi(-1), (u32::MAX, -1));
+		assert_eq!( i32::lo_hi(-1), (u16::MAX, -1));
+		assert_eq!( i16::lo_hi(-1), ( u8::MAX, -1));
+		assert_eq!( i32::lo_hi(i32::MIN), (0, i16::MIN));
+		assert_eq!( i32::lo_hi(i32::MAX), (u16::MAX, i16::MAX));
+		assert_eq!( i32::lo_hi(-0x1_0000), (0, -1));
+		assert_eq!( i32::lo_hi(-0x1_2345), (0xdcbb, -2));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn join_signed() {
+		assert_eq!(i128::join(u64::MAX, -1), -1);
+		assert_eq!( i64::join(u32::MAX, -1), -1);
+		assert_eq!( i32::join(u16::MAX, -1), -1);
+		assert_eq!( i16::join( u8::MAX, -1), -1);
+		assert_eq!( i32::join(0, i16::MIN), i32::MIN);
+		assert_eq!( i32::join(0xdcbb, -2), -0x1_2345);
+		assert_eq!( i32::join(0x8000, 0), 0x8000);
+	}
+
+	#[test]
+	fn split_join_signed_roundtrip() {
+		for value in [i32::MIN, i32::MIN + 1, -0x1_2345, -0x1_0000, -0xffff, -0x8000, -1, 0, 1, 0x8000, i32::MAX] {
+			let (lo, hi) = value.lo_hi();
+			assert_eq!(i32::join(lo, hi), value);
+		}
+		for value in [i128::MIN, -1, 0, i128::MAX] {
+			let (lo, hi) = value.lo_hi();
+			assert_eq!(i128::join(lo, hi), value);
+		}
+	}
+
+	#[test]
+	#[cfg(target_pointer_width = "64")]
+	fn split_join_pointer_sized() {
+		assert_eq!(usize::lo_hi(usize::MAX), (u32::MAX, u32::MAX));
+		assert_eq!(usize::lo_hi(usize::MAX >> 32), (u32::MAX, 0));
+		assert_eq!(usize::join(u32::MAX, 0), usize::MAX >> 32);
+		assert_eq!(usize::join(u32::MAX, u32::MAX), usize::MAX);
+		assert_eq!(isize::lo_hi(-1), (u32::MAX, -1));
+		assert_eq!(isize::lo_hi(isize::MIN), (0, i32::MIN));
+		assert_eq!(isize::join(u32::MAX, -1), -1);
+		assert_eq!(isize::join(0, i32::MIN), isize::MIN);
+	}
+
+	#[test]
+	#[cfg(target_pointer_width = "32")]
+	fn split_join_pointer_sized() {
+		assert_eq!(usize::lo_hi(usize::MAX), (u16::MAX, u16::MAX));
+		assert_eq!(usize::lo_hi(usize::MAX >> 16), (u16::MAX, 0));
+		assert_eq!(usize::join(u16::MAX, 0), usize::MAX >> 16);
+		assert_eq!(usize::join(u16::MAX, u16::MAX), usize::MAX);
+		assert_eq!(isize::lo_hi(-1), (u16::MAX, -1));
+		assert_eq!(isize::lo_hi(isize::MIN), (0, i16::MIN));
+		assert_eq!(isize::join(u16::MAX, -1), -1);
+		assert_eq!(isize::join(0, i16::MIN), isize::MIN);
+	}
+
+	#[test]
+	#[cfg(target_pointer_width = "16")]
+	fn split_join_pointer_sized() {
+		assert_eq!(usize::lo_hi(usize::MAX), (u8::MAX, u8::MAX));
+		assert_eq!(usize::lo_hi(usize::MAX >> 8), (u8::MAX, 0));
+		assert_eq!(usize::join(u8::MAX, 0), usize::MAX >> 8);
+		assert_eq!(usize::join(u8::MAX, u8::MAX), usize::MAX);
+		assert_eq!(isize::lo_hi(-1), (u8::MAX, -1));
+		assert_eq!(isize::lo_hi(isize::MIN), (0, i8::MIN));
+		assert_eq!(isize::join(u8::MAX, -1), -1);
+		assert_eq!(isize::join(0, i8::MIN), isize::MIN);
+	}
 }
@@ -0,0 +1,218 @@
+//! Splitting into arrays of smaller integers
+//!
+//! Unlike [`Split`](super::Split), which halves an integer, this module splits
+//! an integer into any number of smaller units, ordered from least to most
+//! significant.
+
+// Imports
+use crate::Const;
+use core::mem;
+
+/// Splits an integer into an array of smaller integers
+///
+/// The parts are ordered from the least significant to the most significant,
+/// e.g. `0x1234_u16` splits into `[0x34, 0x12]`.
+///
+/// For signed integers, all parts are unsigned, as they're simply
+/// the bits of the integer.
+pub trait SplitInto<P>: Sized {
+	/// Splits this integer into it's parts, from least to most significant
+	fn split_into(self) -> P;
+}
+
+/// Joins an array of smaller integers into a larger one
+///
+/// The parts are ordered from the least significant to the most significant,
+/// the same as [`SplitInto`].
+pub trait JoinFrom<P>: SplitInto<P> {
+	/// Joins the parts of an integer, from least to most significant
+	fn join_from(parts: P) -> Self;
+}
+
+/// Macro to help implement `SplitInto` / `JoinFrom`
+///
+/// Note: We don't implement these for references, the same as `Split` / `Join`.
+macro_rules! impl_split_into_join_from {
+	($T:ty => $( [$U:ty; $N:literal] ),+ $(,)?) => {
+		$(
+			// Make sure that `T` is made up of `N` `U`s
+			::static_assertions::assert_eq_size!($T, [$U; $N]);
+
+			impl Const<$T, [$U; $N]> {
+				/// Splits `value` into it's parts, from least to most significant
+				///
+				/// This is the `const` version of [`SplitInto::split_into`].
+				#[must_use]
+				#[inline]
+				pub const fn split_into(value: $T) -> [$U; $N] {
+					let mut parts = [0; $N];
+					let mut idx = 0;
+					while idx < $N {
+						parts[idx] = Const::<$T, $U>::truncate(value >> (8 * mem::size_of::<$U>() * idx));
+						idx += 1;
+					}
+					parts
+				}
+
+				/// Joins the parts of an integer, from least to most significant
+				///
+				/// This is the `const` version of [`JoinFrom::join_from`].
+				#[must_use]
+				#[inline]
+				pub const fn join_from(parts: [$U; $N]) -> $T {
+					let mut value = 0;
+					let mut idx = 0;
+					while idx < $N {
+						value |= Const::<$U, $T>::zero_extend(parts[idx]) << (8 * mem::size_of::<$U>() * idx);
+						idx += 1;
+					}
+					value
+				}
+			}
+
+			impl SplitInto<[$U; $N]> for $T {
+				#[inline]
+				fn split_into(self) -> [$U; $N] {
+					Const::<$T, [$U; $N]>::split_into(self)
+				}
+			}
+
+			impl JoinFrom<[$U; $N]> for $T {
+				#[inline]
+				fn join_from(parts: [$U; $N]) -> Self {
+					Const::<$T, [$U; $N]>::join_from(parts)
+				}
+			}
+		)+
+	};
+}
+
+// Unsigned
+impl_split_into_join_from! { u128 => [u64; 2], [u32; 4], [u16; 8], [u8; 16] }
+impl_split_into_join_from! { u64  =>           [u32; 2], [u16; 4], [u8;  8] }
+impl_split_into_join_from! { u32  =>                     [u16; 2], [u8;  4] }
+impl_split_into_join_from! { u16  =>                               [u8;  2] }
+
+// Signed
+impl_split_into_join_from! { i128 => [u64; 2], [u32; 4], [u16; 8], [u8; 16] }
+impl_split_into_join_from! { i64  =>           [u32; 2], [u16; 4], [u8;  8] }
+impl_split_into_join_from! { i32  =>                     [u16; 2], [u8;  4] }
+impl_split_into_join_from! { i16  =>                               [u8;  2] }
+
+// Pointer-sized
+#[cfg(target_pointer_width = "64")]
+impl_split_into_join_from! { usize => [u32; 2], [u16; 4], [u8; 8] }
+#[cfg(target_pointer_width = "64")]
+impl_split_into_join_from! { isize => [u32; 2], [u16; 4], [u8; 8] }
+#[cfg(target_pointer_width = "32")]
+impl_split_into_join_from! { usize => [u16; 2], [u8; 4] }
+#[cfg(target_pointer_width = "32")]
+impl_split_into_join_from! { isize => [u16; 2], [u8; 4] }
+#[cfg(target_pointer_width = "16")]
+impl_split_into_join_from! { usize => [u8; 2] }
+#[cfg(target_pointer_width = "16")]
+impl_split_into_join_from! { isize => [u8; 2] }
+
+// Check that they all implement `SplitInto` / `JoinFrom`
+static_assertions::assert_impl_all! { u128 : JoinFrom<[u64; 2]>, JoinFrom<[u32; 4]>, JoinFrom<[u16; 8]>, JoinFrom<[u8; 16]> }
+static_assertions::assert_impl_all! { u64  :                     JoinFrom<[u32; 2]>, JoinFrom<[u16; 4]>, JoinFrom<[u8;  8]> }
+static_assertions::assert_impl_all! { u32  :                                         JoinFrom<[u16; 2]>, JoinFrom<[u8;  4]> }
+static_assertions::assert_impl_all! { u16  :                                                             JoinFrom<[u8;  2]> }
+static_assertions::assert_impl_all! { i128 : JoinFrom<[u64; 2]>, JoinFrom<[u32; 4]>, JoinFrom<[u16; 8]>, JoinFrom<[u8; 16]> }
+static_assertions::assert_impl_all! { i64  :                     JoinFrom<[u32; 2]>, JoinFrom<[u16; 4]>, JoinFrom<[u8;  8]> }
+static_assertions::assert_impl_all! { i32  :                                         JoinFrom<[u16; 2]>, JoinFrom<[u8;  4]> }
+static_assertions::assert_impl_all! { i16  :                                                             JoinFrom<[u8;  2]> }
+static_assertions::assert_impl_all! { usize: JoinFrom<[u8; mem::size_of::<usize>()]> }
+static_assertions::assert_impl_all! { isize: JoinFrom<[u8; mem::size_of::<isize>()]> }
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use super::*;
+
+	/// Values to check the roundtrip of for every integer
+	macro_rules! roundtrip_values {
+		($T:ty) => {
+			[
+				<$T>::MIN,
+				<$T>::MIN + 1,
+				<$T>::MAX,
+				<$T>::MAX - 1,
+				0,
+				1,
+				<$T>::MAX / 3,
+				<$T>::MAX / 5 * 3,
+				<$T>::MIN / 7,
+			]
+		};
+	}
+
+	/// Checks that `join(split(x)) == x` for all given unit arrays of `T`
+	macro_rules! check_roundtrip {
+		($T:ty => $( [$U:ty; $N:literal] ),+ $(,)?) => {
+			for value in roundtrip_values!($T) {
+				$(
+					let parts: [$U; $N] = value.split_into();
+					assert_eq!(<$T>::join_from(parts), value);
+				)+
+			}
+		};
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn split_into() {
+		assert_eq!(<u16  as SplitInto<[u8;  2]>>::split_into(0x1234), [0x34, 0x12]);
+		assert_eq!(<u64  as SplitInto<[u8;  8]>>::split_into(0x0123_4567_89ab_cdef), [0xef, 0xcd, 0xab, 0x89, 0x67, 0x45, 0x23, 0x01]);
+		assert_eq!(<u64  as SplitInto<[u16; 4]>>::split_into(0x0123_4567_89ab_cdef), [0xcdef, 0x89ab, 0x4567, 0x0123]);
+		assert_eq!(<u64  as SplitInto<[u32; 2]>>::split_into(0x0123_4567_89ab_cdef), [0x89ab_cdef, 0x0123_4567]);
+		assert_eq!(<u128 as SplitInto<[u32; 4]>>::split_into(u128::MAX), [u32::MAX; 4]);
+		assert_eq!(<i32  as SplitInto<[u8;  4]>>::split_into(-2), [0xfe, 0xff, 0xff, 0xff]);
+		assert_eq!(<i64  as SplitInto<[u16; 4]>>::split_into(i64::MIN), [0, 0, 0, 0x8000]);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn join_from() {
+		assert_eq!(u16::join_from([0x34, 0x12]), 0x1234);
+		assert_eq!(u64::join_from([0xef, 0xcd, 0xab, 0x89, 0x67, 0x45, 0x23, 0x01]), 0x0123_4567_89ab_cdef);
+		assert_eq!(u64::join_from([0xcdef, 0x89ab, 0x4567, 0x0123]), 0x0123_4567_89ab_cdef);
+		assert_eq!(u128::join_from([u32::MAX; 4]), u128::MAX);
+		assert_eq!(i32::join_from([0xfe, 0xff, 0xff, 0xff]), -2);
+		assert_eq!(i64::join_from([0u16, 0, 0, 0x8000]), i64::MIN);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn split_join_roundtrip() {
+		check_roundtrip! { u128 => [u64; 2], [u32; 4], [u16; 8], [u8; 16] }
+		check_roundtrip! { u64  =>           [u32; 2], [u16; 4], [u8;  8] }
+		check_roundtrip! { u32  =>                     [u16; 2], [u8;  4] }
+		check_roundtrip! { u16  =>                               [u8;  2] }
+		check_roundtrip! { i128 => [u64; 2], [u32; 4], [u16; 8], [u8; 16] }
+		check_roundtrip! { i64  =>           [u32; 2], [u16; 4], [u8;  8] }
+		check_roundtrip! { i32  =>                     [u16; 2], [u8;  4] }
+		check_roundtrip! { i16  =>                               [u8;  2] }
+	}
+
+	#[test]
+	#[cfg(target_pointer_width = "64")]
+	fn split_join_roundtrip_pointer_sized() {
+		check_roundtrip! { usize => [u32; 2], [u16; 4], [u8; 8] }
+		check_roundtrip! { isize => [u32; 2], [u16; 4], [u8; 8] }
+	}
+
+	#[test]
+	#[cfg(target_pointer_width = "32")]
+	fn split_join_roundtrip_pointer_sized() {
+		check_roundtrip! { usize => [u16; 2], [u8; 4] }
+		check_roundtrip! { isize => [u16; 2], [u8; 4] }
+	}
+
+	#[test]
+	#[cfg(target_pointer_width = "16")]
+	fn split_join_roundtrip_pointer_sized() {
+		check_roundtrip! { usize => [u8; 2] }
+		check_roundtrip! { isize => [u8; 2] }
+	}
+}
@@ -0,0 +1,245 @@
+//! Endianness-aware splitting and joining
+//!
+//! This module allows splitting an integer into units of any smaller
+//! width, ordered either from least significant (little-endian) or
+//! most significant (big-endian) unit first.
+//!
+//! [`SplitUnits`] and [`SplitEndian`] are built on [`SplitInto`] and [`JoinFrom`], which
+//! split into and join from units ordered least significant first, with the big-endian
+//! order reversing them.
+
+// Imports
+use super::{JoinFrom, SplitInto};
+use crate::{consts, Const};
+use core::mem;
+
+/// Splits an integer into units of `U`
+///
+/// This trait maps each unit `U` to the array of parts the integer splits into,
+/// ordered from least to most significant.
+/// It is mostly used through [`SplitEndian`], which allows choosing the order.
+pub trait SplitUnits<U>: Sized {
+	/// The parts this integer splits into
+	type Parts: AsMut<[U]>;
+
+	/// Splits this integer into it's parts, from least to most significant
+	fn split_units(self) -> Self::Parts;
+
+	/// Joins the parts of an integer, from least to most significant
+	fn join_units(parts: Self::Parts) -> Self;
+}
+
+/// Macro to help implement `SplitUnits`
+macro_rules! impl_split_units {
+	($T:ty => $( $U:ty ),+ $(,)?) => {
+		$(
+			impl Const<$T, $U> {
+				/// Splits `value` into units of `U`, least significant first
+				///
+				/// This is the `const` version of [`SplitEndian::split_le`].
+				#[must_use]
+				#[inline]
+				pub const fn split_le(value: $T) -> [$U; mem::size_of::<$T>() / mem::size_of::<$U>()] {
+					Const::<$T, [$U; mem::size_of::<$T>() / mem::size_of::<$U>()]>::split_into(value)
+				}
+
+				/// Splits `value` into units of `U`, most significant first
+				///
+				/// This is the `const` version of [`SplitEndian::split_be`].
+				#[must_use]
+				#[inline]
+				pub const fn split_be(value: $T) -> [$U; mem::size_of::<$T>() / mem::size_of::<$U>()] {
+					consts::reverse_units!(Const::<$T, $U>::split_le(value))
+				}
+
+				/// Joins units of `U`, least significant first
+				///
+				/// This is the `const` version of [`SplitEndian::join_le`].
+				#[must_use]
+				#[inline]
+				pub const fn join_le(parts: [$U; mem::size_of::<$T>() / mem::size_of::<$U>()]) -> $T {
+					Const::<$T, [$U; mem::size_of::<$T>() / mem::size_of::<$U>()]>::join_from(parts)
+				}
+
+				/// Joins units of `U`, most significant first
+				///
+				/// This is the `const` version of [`SplitEndian::join_be`].
+				#[must_use]
+				#[inline]
+				pub const fn join_be(parts: [$U; mem::size_of::<$T>() / mem::size_of::<$U>()]) -> $T {
+					Const::<$T, $U>::join_le(consts::reverse_units!(parts))
+				}
+			}
+
+			impl SplitUnits<$U> for $T {
+				type Parts = [$U; mem::size_of::<$T>() / mem::size_of::<$U>()];
+
+				#[inline]
+				fn split_units(self) -> Self::Parts {
+					<$T as SplitInto<Self::Parts>>::split_into(self)
+				}
+
+				#[inline]
+				fn join_units(parts: Self::Parts) -> Self {
+					<$T as JoinFrom<Self::Parts>>::join_from(parts)
+				}
+			}
+		)+
+	};
+}
+
+// Unsigned
+impl_split_units! { u128 => u64, u32, u16, u8 }
+impl_split_units! { u64  =>      u32, u16, u8 }
+impl_split_units! { u32  =>           u16, u8 }
+impl_split_units! { u16  =>                u8 }
+
+// Signed
+impl_split_units! { i128 => u64, u32, u16, u8 }
+impl_split_units! { i64  =>      u32, u16, u8 }
+impl_split_units! { i32  =>           u16, u8 }
+impl_split_units! { i16  =>                u8 }
+
+// Pointer-sized
+#[cfg(target_pointer_width = "64")]
+impl_split_units! { usize => u32, u16, u8 }
+#[cfg(target_pointer_width = "64")]
+impl_split_units! { isize => u32, u16, u8 }
+#[cfg(target_pointer_width = "32")]
+impl_split_units! { usize => u16, u8 }
+#[cfg(target_pointer_width = "32")]
+impl_split_units! { isize => u16, u8 }
+#[cfg(target_pointer_width = "16")]
+impl_split_units! { usize => u8 }
+#[cfg(target_pointer_width = "16")]
+impl_split_units! { isize => u8 }
+
+/// Helper trait for splitting and joining with an explicit endianness to be used with turbofish syntax
+pub trait SplitEndian: Sized {
+	/// Splits this integer into units of `U`, least significant first
+	#[inline]
+	fn split_le<U>(self) -> <Self as SplitUnits<U>>::Parts
+	where
+		Self: SplitUnits<U>,
+	{
+		self.split_units()
+	}
+
+	/// Splits this integer into units of `U`, most significant first
+	#[inline]
+	fn split_be<U>(self) -> <Self as SplitUnits<U>>::Parts
+	where
+		Self: SplitUnits<U>,
+	{
+		let mut parts = self.split_units();
+		parts.as_mut().reverse();
+		parts
+	}
+
+	/// Joins units of `U`, least significant first
+	#[inline]
+	fn join_le<U>(parts: <Self as SplitUnits<U>>::Parts) -> Self
+	where
+		Self: SplitUnits<U>,
+	{
+		Self::join_units(parts)
+	}
+
+	/// Joins units of `U`, most significant first
+	#[inline]
+	fn join_be<U>(mut parts: <Self as SplitUnits<U>>::Parts) -> Self
+	where
+		Self: SplitUnits<U>,
+	{
+		parts.as_mut().reverse();
+		Self::join_units(parts)
+	}
+}
+impl<T> SplitEndian for T {}
+
+// Check that all associated types are correct
+static_assertions::assert_type_eq_all! { <u128 as SplitUnits<u64>>::Parts, [u64;  2] }
+static_assertions::assert_type_eq_all! { <u128 as SplitUnits<u32>>::Parts, [u32;  4] }
+static_assertions::assert_type_eq_all! { <u128 as SplitUnits<u16>>::Parts, [u16;  8] }
+static_assertions::assert_type_eq_all! { <u128 as SplitUnits<u8 >>::Parts, [u8 ; 16] }
+static_assertions::assert_type_eq_all! { <u64  as SplitUnits<u32>>::Parts, [u32;  2] }
+static_assertions::assert_type_eq_all! { <u64  as SplitUnits<u16>>::Parts, [u16;  4] }
+static_assertions::assert_type_eq_all! { <u64  as SplitUnits<u8 >>::Parts, [u8 ;  8] }
+static_assertions::assert_type_eq_all! { <u32  as SplitUnits<u16>>::Parts, [u16;  2] }
+static_assertions::assert_type_eq_all! { <u32  as SplitUnits<u8 >>::Parts, [u8 ;  4] }
+static_assertions::assert_type_eq_all! { <u16  as SplitUnits<u8 >>::Parts, [u8 ;  2] }
+static_assertions::assert_type_eq_all! { <i128 as SplitUnits<u64>>::Parts, [u64;  2] }
+static_assertions::assert_type_eq_all! { <i128 as SplitUnits<u32>>::Parts, [u32;  4] }
+static_assertions::assert_type_eq_all! { <i128 as SplitUnits<u16>>::Parts, [u16;  8] }
+static_assertions::assert_type_eq_all! { <i128 as SplitUnits<u8 >>::Parts, [u8 ; 16] }
+static_assertions::assert_type_eq_all! { <i64  as SplitUnits<u32>>::Parts, [u32;  2] }
+static_assertions::assert_type_eq_all! { <i64  as SplitUnits<u16>>::Parts, [u16;  4] }
+static_assertions::assert_type_eq_all! { <i64  as SplitUnits<u8 >>::Parts, [u8 ;  8] }
+static_assertions::assert_type_eq_all! { <i32  as SplitUnits<u16>>::Parts, [u16;  2] }
+static_assertions::assert_type_eq_all! { <i32  as SplitUnits<u8 >>::Parts, [u8 ;  4] }
+static_assertions::assert_type_eq_all! { <i16  as SplitUnits<u8 >>::Parts, [u8 ;  2] }
+static_assertions::assert_type_eq_all! { <usize as SplitUnits<u8>>::Parts, [u8; mem::size_of::<usize>()] }
+static_assertions::assert_type_eq_all! { <isize as SplitUnits<u8>>::Parts, [u8; mem::size_of::<isize>()] }
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use super::*;
+	use crate::{Signed, Split};
+
+	#[test]
+	#[rustfmt::skip]
+	fn split_le() {
+		assert_eq!(0x0123_4567_89ab_cdefu64.split_le::<u8 >(), [0xef, 0xcd, 0xab, 0x89, 0x67, 0x45, 0x23, 0x01]);
+		assert_eq!(0x0123_4567_89ab_cdefu64.split_le::<u16>(), [0xcdef, 0x89ab, 0x4567, 0x0123]);
+		assert_eq!(0x0123_4567_89ab_cdefu64.split_le::<u32>(), [0x89ab_cdef, 0x0123_4567]);
+		assert_eq!(                 (-2i32).split_le::<u16>(), [0xfffe, 0xffff]);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn split_be() {
+		assert_eq!(0x0123_4567_89ab_cdefu64.split_be::<u8 >(), [0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef]);
+		assert_eq!(0x0123_4567_89ab_cdefu64.split_be::<u16>(), [0x0123, 0x4567, 0x89ab, 0xcdef]);
+		assert_eq!(0x0123_4567_89ab_cdefu64.split_be::<u32>(), [0x0123_4567, 0x89ab_cdef]);
+		assert_eq!(                 (-2i32).split_be::<u16>(), [0xffff, 0xfffe]);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn split_be_matches_lo_hi() {
+		let (lo, hi) = 0x0123u16.lo_hi();                                    assert_eq!(0x0123u16.split_be::<u8>(), [hi, lo]);
+		let (lo, hi) = 0x0123_4567u32.lo_hi();                               assert_eq!(0x0123_4567u32.split_be::<u16>(), [hi, lo]);
+		let (lo, hi) = 0x0123_4567_89ab_cdefu64.lo_hi();                     assert_eq!(0x0123_4567_89ab_cdefu64.split_be::<u32>(), [hi, lo]);
+		let (lo, hi) = 0x0123_4567_89ab_cdef_0123_4567_89ab_cdefu128.lo_hi(); assert_eq!(0x0123_4567_89ab_cdef_0123_4567_89ab_cdefu128.split_be::<u64>(), [hi, lo]);
+		let (lo, hi) = (-2i32).lo_hi();                                      assert_eq!((-2i32).split_be::<u16>(), [hi.as_unsigned(), lo]);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn join_le() {
+		assert_eq!(u64::join_le::<u8 >([0xef, 0xcd, 0xab, 0x89, 0x67, 0x45, 0x23, 0x01]), 0x0123_4567_89ab_cdef);
+		assert_eq!(u64::join_le::<u16>([0xcdef, 0x89ab, 0x4567, 0x0123]), 0x0123_4567_89ab_cdef);
+		assert_eq!(u64::join_le::<u32>([0x89ab_cdef, 0x0123_4567]), 0x0123_4567_89ab_cdef);
+		assert_eq!(i32::join_le::<u16>([0xfffe, 0xffff]), -2);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn join_be() {
+		assert_eq!(u64::join_be::<u8 >([0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef]), 0x0123_4567_89ab_cdef);
+		assert_eq!(u64::join_be::<u16>([0x0123, 0x4567, 0x89ab, 0xcdef]), 0x0123_4567_89ab_cdef);
+		assert_eq!(u64::join_be::<u32>([0x0123_4567, 0x89ab_cdef]), 0x0123_4567_89ab_cdef);
+		assert_eq!(i32::join_be::<u16>([0xffff, 0xfffe]), -2);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn split_bytes_matches_std() {
+		assert_eq!(0x0123_4567_89ab_cdef_0123_4567_89ab_cdefu128.split_le::<u8>(), 0x0123_4567_89ab_cdef_0123_4567_89ab_cdefu128.to_le_bytes());
+		assert_eq!(0x0123_4567_89ab_cdef_0123_4567_89ab_cdefu128.split_be::<u8>(), 0x0123_4567_89ab_cdef_0123_4567_89ab_cdefu128.to_be_bytes());
+		assert_eq!(                                   i64::MIN.split_le::<u8>(),                                    i64::MIN.to_le_bytes());
+		assert_eq!(                                   i64::MIN.split_be::<u8>(),                                    i64::MIN.to_be_bytes());
+		assert_eq!(                                 usize::MAX.split_be::<u8>(),                                  usize::MAX.to_be_bytes());
+	}
+}
@@ -1,12 +1,28 @@
 //! Integer truncation
 //!
 //! This module contains the [`Truncate`] trait used for
-//! truncating integers to a smaller integer
+//! truncating integers to a smaller integer, as well as
+//! checked and saturating variants of it.
+
+// Modules
+pub mod bits;
+pub mod checked;
+pub mod saturating;
+
+// Exports
+pub use bits::TruncateBits;
+pub use checked::{TruncateError, TryTruncate, TryTruncated};
+pub use saturating::{SaturatingTruncate, SaturatingTruncated};
 
 // Imports
+use crate::Const;
 use core::mem;
 
 /// Truncates this integer to a lower size
+///
+/// Truncating into an integer of a different signedness is also supported,
+/// in which case the lower bits are kept and reinterpreted as the smaller
+/// integer.
 pub trait Truncate<T>: Sized {
 	/// Performs the truncation
 	fn truncate(self) -> T;
@@ -20,6 +36,37 @@ impl<T> Truncate<T> for T {
 	}
 }
 
+/// Macro to help implement the `const` version of [`Truncate`] to the same type
+///
+/// Note: The trait itself is implemented by the blanket implementation above, so
+///       we only implement it for references here, by copying the underlying type.
+macro_rules! impl_truncate_identity {
+	($( $T:ty ),+ $(,)?) => {
+		$(
+			impl Const<$T> {
+				/// Truncates `value` into the same type, returning it
+				///
+				/// This is the `const` version of [`Truncate::truncate`].
+				#[must_use]
+				#[inline]
+				pub const fn truncate(value: $T) -> $T {
+					value
+				}
+			}
+
+			impl<'a> Truncate<$T> for &'a $T {
+				#[inline]
+				fn truncate(self) -> $T {
+					<$T as Truncate<$T>>::truncate(*self)
+				}
+			}
+		)+
+	};
+}
+
+impl_truncate_identity! { u8, u16, u32, u64, u128, usize }
+impl_truncate_identity! { i8, i16, i32, i64, i128, isize }
+
 /// Macro to help implement `Truncate`
 ///
 /// Note: We don't currently `Truncate<&'b U> for &'a T` due
@@ -28,15 +75,27 @@ impl<T> Truncate<T> for T {
 macro_rules! impl_truncate {
 	($T:ty => $($U:ty),* $(,)?) => {
 		$(
-			// Make sure `T` is larger than `U`, so we don't extend it.
-			::static_assertions::const_assert!(mem::size_of::<$T>() > mem::size_of::<$U>());
+			// Make sure `T` is at least as large as `U`, so we don't extend it.
+			// Note: They may only be the same size when truncating to or from pointer-sized integers.
+			::static_assertions::const_assert!(mem::size_of::<$T>() >= mem::size_of::<$U>());
 
-			impl Truncate<$U> for $T {
+			impl Const<$T, $U> {
+				/// Truncates `value`
+				///
+				/// This is the `const` version of [`Truncate::truncate`].
+				#[must_use]
 				#[inline]
 				#[allow(clippy::as_conversions)]
-				fn truncate(self) -> $U {
+				pub const fn truncate(value: $T) -> $U {
 					// Casting from a larger to a smaller integer truncates
-					self as $U
+					value as $U
+				}
+			}
+
+			impl Truncate<$U> for $T {
+				#[inline]
+				fn truncate(self) -> $U {
+					Const::<$T, $U>::truncate(self)
 				}
 			}
 
@@ -62,6 +121,51 @@ impl_truncate! { i64  =>      i32, i16, i8 }
 impl_truncate! { i32  =>           i16, i8 }
 impl_truncate! { i16  =>                i8 }
 
+// Unsigned to signed
+impl_truncate! { u128 => i64, i32, i16, i8 }
+impl_truncate! { u64  =>      i32, i16, i8 }
+impl_truncate! { u32  =>           i16, i8 }
+impl_truncate! { u16  =>                i8 }
+
+// Signed to unsigned
+impl_truncate! { i128 => u64, u32, u16, u8 }
+impl_truncate! { i64  =>      u32, u16, u8 }
+impl_truncate! { i32  =>           u16, u8 }
+impl_truncate! { i16  =>                u8 }
+
+// Pointer-sized
+// Note: These only exist where `U` is smaller than `T`, or of the same size and signedness, for the current pointer width.
+impl_truncate! { u64   => usize }
+impl_truncate! { u128  => usize, isize }
+impl_truncate! { usize => u8, u16, i8 }
+impl_truncate! { i64   => isize }
+impl_truncate! { i128  => usize, isize }
+impl_truncate! { isize => u8, i8, i16 }
+#[cfg(any(target_pointer_width = "16", target_pointer_width = "32"))]
+impl_truncate! { u32   => usize }
+#[cfg(any(target_pointer_width = "16", target_pointer_width = "32"))]
+impl_truncate! { u64   => isize }
+#[cfg(any(target_pointer_width = "16", target_pointer_width = "32"))]
+impl_truncate! { i32   => isize }
+#[cfg(any(target_pointer_width = "16", target_pointer_width = "32"))]
+impl_truncate! { i64   => usize }
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl_truncate! { usize => u32, i16 }
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl_truncate! { isize => u16, i32 }
+#[cfg(target_pointer_width = "16")]
+impl_truncate! { u16   => usize }
+#[cfg(target_pointer_width = "16")]
+impl_truncate! { u32   => isize }
+#[cfg(target_pointer_width = "16")]
+impl_truncate! { i16   => isize }
+#[cfg(target_pointer_width = "16")]
+impl_truncate! { i32   => usize }
+#[cfg(target_pointer_width = "64")]
+impl_truncate! { usize => u64, i32 }
+#[cfg(target_pointer_width = "64")]
+impl_truncate! { isize => u32, i64 }
+
 /// Helper trait for [`Truncate`] to be used with turbofish syntax
 pub trait Truncated {
 	/// Truncates this type
@@ -87,6 +191,54 @@ static_assertions::assert_impl_all! { u32  :                                Trun
 static_assertions::assert_impl_all! { u16  :                                               Truncate<u16>, Truncate<u8> }
 static_assertions::assert_impl_all! { u8   :                                                              Truncate<u8> }
 
+// Check that all mixed-signedness `Truncate` impls exist
+static_assertions::assert_impl_all! { i128 :                 Truncate<u64>, Truncate<u32>, Truncate<u16>, Truncate<u8> }
+static_assertions::assert_impl_all! { i64  :                                Truncate<u32>, Truncate<u16>, Truncate<u8> }
+static_assertions::assert_impl_all! { i32  :                                               Truncate<u16>, Truncate<u8> }
+static_assertions::assert_impl_all! { i16  :                                                              Truncate<u8> }
+static_assertions::assert_impl_all! { u128 :                 Truncate<i64>, Truncate<i32>, Truncate<i16>, Truncate<i8> }
+static_assertions::assert_impl_all! { u64  :                                Truncate<i32>, Truncate<i16>, Truncate<i8> }
+static_assertions::assert_impl_all! { u32  :                                               Truncate<i16>, Truncate<i8> }
+static_assertions::assert_impl_all! { u16  :                                                              Truncate<i8> }
+
+// Check that integers of the same size, but different signedness, don't implement `Truncate`
+static_assertions::assert_not_impl_any! { i8   : Truncate<u8> }
+static_assertions::assert_not_impl_any! { i16  : Truncate<u16> }
+static_assertions::assert_not_impl_any! { i32  : Truncate<u32> }
+static_assertions::assert_not_impl_any! { i64  : Truncate<u64> }
+static_assertions::assert_not_impl_any! { i128 : Truncate<u128> }
+static_assertions::assert_not_impl_any! { u8   : Truncate<i8> }
+static_assertions::assert_not_impl_any! { u16  : Truncate<i16> }
+static_assertions::assert_not_impl_any! { u32  : Truncate<i32> }
+static_assertions::assert_not_impl_any! { u64  : Truncate<i64> }
+static_assertions::assert_not_impl_any! { u128 : Truncate<i128> }
+
+// Check that all pointer-sized `Truncate` impls exist for each pointer width
+#[cfg(target_pointer_width = "16")]
+static_assertions::assert_impl_all! { usize : Truncate<usize>, Truncate<u8>, Truncate<u16>, Truncate<i8> }
+#[cfg(target_pointer_width = "16")]
+static_assertions::assert_not_impl_any! { usize : Truncate<u32>, Truncate<u64>, Truncate<u128>, Truncate<i16>, Truncate<i32>, Truncate<i64>, Truncate<i128>, Truncate<isize> }
+#[cfg(target_pointer_width = "16")]
+static_assertions::assert_impl_all! { isize : Truncate<isize>, Truncate<u8>, Truncate<i8>, Truncate<i16> }
+#[cfg(target_pointer_width = "16")]
+static_assertions::assert_not_impl_any! { isize : Truncate<u16>, Truncate<u32>, Truncate<u64>, Truncate<u128>, Truncate<usize>, Truncate<i32>, Truncate<i64>, Truncate<i128> }
+#[cfg(target_pointer_width = "32")]
+static_assertions::assert_impl_all! { usize : Truncate<usize>, Truncate<u8>, Truncate<u16>, Truncate<u32>, Truncate<i8>, Truncate<i16> }
+#[cfg(target_pointer_width = "32")]
+static_assertions::assert_not_impl_any! { usize : Truncate<u64>, Truncate<u128>, Truncate<i32>, Truncate<i64>, Truncate<i128>, Truncate<isize> }
+#[cfg(target_pointer_width = "32")]
+static_assertions::assert_impl_all! { isize : Truncate<isize>, Truncate<u8>, Truncate<u16>, Truncate<i8>, Truncate<i16>, Truncate<i32> }
+#[cfg(target_pointer_width = "32")]
+static_assertions::assert_not_impl_any! { isize : Truncate<u32>, Truncate<u64>, Truncate<u128>, Truncate<usize>, Truncate<i64>, Truncate<i128> }
+#[cfg(target_pointer_width = "64")]
+static_assertions::assert_impl_all! { usize : Truncate<usize>, Truncate<u8>, Truncate<u16>, Truncate<u32>, Truncate<u64>, Truncate<i8>, Truncate<i16>, Truncate<i32> }
+#[cfg(target_pointer_width = "64")]
+static_assertions::assert_not_impl_any! { usize : Truncate<u128>, Truncate<i64>, Truncate<i128>, Truncate<isize> }
+#[cfg(target_pointer_width = "64")]
+static_assertions::assert_impl_all! { isize : Truncate<isize>, Truncate<u8>, Truncate<u16>, Truncate<u32>, Truncate<i8>, Truncate<i16>, Truncate<i32>, Truncate<i64> }
+#[cfg(target_pointer_width = "64")]
+static_assertions::assert_not_impl_any! { isize : Truncate<u64>, Truncate<u128>, Truncate<usize>, Truncate<i128> }
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -130,4 +282,56 @@ mod tests {
 		assert_eq!( i16::truncated::<  i8>(-1), -1);
 		assert_eq!(  i8::truncated::<  i8>(-1), -1);
 	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn truncate_mixed() {
+		assert_eq!(u128::truncated::< i64>(u128::MAX), -1);
+		assert_eq!( u64::truncated::< i32>( u64::MAX), -1);
+		assert_eq!( u32::truncated::< i16>( u32::MAX), -1);
+		assert_eq!( u16::truncated::<  i8>( u16::MAX), -1);
+		assert_eq!(u128::truncated::<  i8>(0x17f), 0x7f);
+
+		assert_eq!(i128::truncated::< u64>(-1), u64::MAX);
+		assert_eq!( i64::truncated::< u32>(-1), u32::MAX);
+		assert_eq!( i32::truncated::< u16>(-1), u16::MAX);
+		assert_eq!( i16::truncated::<  u8>(-1),  u8::MAX);
+		assert_eq!(i128::truncated::<  u8>(0x1ff), u8::MAX);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	#[cfg(target_pointer_width = "64")]
+	fn truncate_pointer_sized() {
+		assert_eq!(usize::truncated::<u32>(usize::MAX), u32::MAX);
+		assert_eq!(usize::truncated::<u64>(usize::MAX), u64::MAX);
+		assert_eq!(isize::truncated::<i32>(-1), -1);
+		assert_eq!(u64::truncated::<usize>(u64::MAX), usize::MAX);
+		assert_eq!(u128::truncated::<usize>(u128::MAX), usize::MAX);
+		assert_eq!(i128::truncated::<usize>(-1), usize::MAX);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	#[cfg(target_pointer_width = "32")]
+	fn truncate_pointer_sized() {
+		assert_eq!(usize::truncated::<u16>(usize::MAX), u16::MAX);
+		assert_eq!(usize::truncated::<u32>(usize::MAX), u32::MAX);
+		assert_eq!(isize::truncated::<i16>(-1), -1);
+		assert_eq!(u32::truncated::<usize>(u32::MAX), usize::MAX);
+		assert_eq!(u64::truncated::<usize>(u64::MAX), usize::MAX);
+		assert_eq!(i64::truncated::<usize>(-1), usize::MAX);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	#[cfg(target_pointer_width = "16")]
+	fn truncate_pointer_sized() {
+		assert_eq!(usize::truncated::<u8>(usize::MAX), u8::MAX);
+		assert_eq!(usize::truncated::<u16>(usize::MAX), u16::MAX);
+		assert_eq!(isize::truncated::<i8>(-1), -1);
+		assert_eq!(u16::truncated::<usize>(u16::MAX), usize::MAX);
+		assert_eq!(u32::truncated::<usize>(u32::MAX), usize::MAX);
+		assert_eq!(i32::truncated::<usize>(-1), usize::MAX);
+	}
 }
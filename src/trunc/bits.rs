@@ -0,0 +1,352 @@
+//! Truncation to an arbitrary bit width
+
+// Imports
+use crate::{Const, ExtendBits, Signed, Truncate};
+
+/// Truncation to an arbitrary bit width
+///
+/// This trait serves to truncate integers to a field of `bits` bits that
+/// isn't necessarily a primitive width, such as an 11-bit immediate.
+///
+/// The result is kept in the lower `bits` bits, either zero extended, in
+/// which case `self` is treated as unsigned, or sign extended, in which case
+/// `self` is treated as signed, the same way [`ExtendBits`] does.
+///
+/// An empty field (`bits == 0`) can only hold `0`, while a field at least as
+/// wide as the integer holds any value.
+pub trait TruncateBits: ExtendBits {
+	/// Truncates this integer to it's lower `bits` bits, zero extending them
+	#[must_use]
+	fn truncate_to(self, bits: u32) -> Self;
+
+	/// Truncates this integer to it's lower `bits` bits, sign extending them
+	#[must_use]
+	fn sign_truncate_to(self, bits: u32) -> Self;
+
+	/// Truncates this integer to it's lower `bits` bits, if it fits in them as unsigned
+	fn checked_truncate_to(self, bits: u32) -> Option<Self>;
+
+	/// Truncates this integer to it's lower `bits` bits, if it fits in them as signed
+	fn checked_sign_truncate_to(self, bits: u32) -> Option<Self>;
+
+	/// Truncates this integer to it's lower `bits` bits, clamping it to their unsigned range
+	///
+	/// Negative values are clamped to `0`, like [`SaturatingTruncate`](crate::SaturatingTruncate) does.
+	#[must_use]
+	fn saturating_truncate_to(self, bits: u32) -> Self;
+
+	/// Truncates this integer to it's lower `bits` bits, clamping it to their signed range
+	#[must_use]
+	fn saturating_sign_truncate_to(self, bits: u32) -> Self;
+
+	/// Truncates this integer to it's lower `BITS` bits, zero extending them
+	#[must_use]
+	#[inline]
+	fn truncate_bits<const BITS: u32>(self) -> Self {
+		self.truncate_to(BITS)
+	}
+
+	/// Truncates this integer to it's lower `BITS` bits, sign extending them
+	#[must_use]
+	#[inline]
+	fn sign_truncate_bits<const BITS: u32>(self) -> Self {
+		self.sign_truncate_to(BITS)
+	}
+
+	/// Truncates this integer to it's lower `BITS` bits, if it fits in them as unsigned
+	#[inline]
+	fn checked_truncate_bits<const BITS: u32>(self) -> Option<Self> {
+		self.checked_truncate_to(BITS)
+	}
+
+	/// Truncates this integer to it's lower `BITS` bits, if it fits in them as signed
+	#[inline]
+	fn checked_sign_truncate_bits<const BITS: u32>(self) -> Option<Self> {
+		self.checked_sign_truncate_to(BITS)
+	}
+
+	/// Truncates this integer to it's lower `BITS` bits, clamping it to their unsigned range
+	#[must_use]
+	#[inline]
+	fn saturating_truncate_bits<const BITS: u32>(self) -> Self {
+		self.saturating_truncate_to(BITS)
+	}
+
+	/// Truncates this integer to it's lower `BITS` bits, clamping it to their signed range
+	#[must_use]
+	#[inline]
+	fn saturating_sign_truncate_bits<const BITS: u32>(self) -> Self {
+		self.saturating_sign_truncate_to(BITS)
+	}
+
+	/// Truncates this integer to it's lower `bits` bits, zero extending them, into `U`
+	///
+	/// If `bits` is larger than `U`, the upper bits of the field are lost.
+	#[inline]
+	fn truncated_to<U>(self, bits: u32) -> U
+	where
+		Self: Truncate<U>,
+	{
+		self.truncate_to(bits).truncate()
+	}
+
+	/// Truncates this integer to it's lower `bits` bits, sign extending them, into `U`
+	///
+	/// If `bits` is larger than `U`, the upper bits of the field are lost.
+	#[inline]
+	fn sign_truncated_to<U>(self, bits: u32) -> U
+	where
+		Self: Truncate<U>,
+	{
+		// Note: The sign extended bits are kept by the truncation, as long as `bits` fits `U`
+		self.sign_truncate_to(bits).truncate()
+	}
+}
+
+/// Macro to help implement [`TruncateBits`]
+macro_rules! impl_truncate_bits {
+	($( $T:ty ),+ $(,)?) => {
+		$(
+			impl Const<$T> {
+				/// Truncates `value` to it's lower `bits` bits, zero extending them
+				///
+				/// This is the `const` version of [`TruncateBits::truncate_to`].
+				#[must_use]
+				#[inline]
+				pub const fn truncate_to(value: $T, bits: u32) -> $T {
+					// Note: Zero extending from `bits` masks out everything above them
+					Const::<$T>::zero_extend_from(value, bits)
+				}
+
+				/// Truncates `value` to it's lower `bits` bits, sign extending them
+				///
+				/// This is the `const` version of [`TruncateBits::sign_truncate_to`].
+				#[must_use]
+				#[inline]
+				pub const fn sign_truncate_to(value: $T, bits: u32) -> $T {
+					// Note: Sign extending from `bits` overwrites everything above them
+					Const::<$T>::sign_extend_from(value, bits)
+				}
+
+				/// Truncates `value` to it's lower `bits` bits, if it fits in them as unsigned
+				///
+				/// This is the `const` version of [`TruncateBits::checked_truncate_to`].
+				#[must_use]
+				#[inline]
+				pub const fn checked_truncate_to(value: $T, bits: u32) -> Option<$T> {
+					// Note: Negative values don't fit an unsigned field, even one as wide as the integer
+					let truncated = Const::<$T>::truncate_to(value, bits);
+					if truncated == value && !Const::<$T>::is_negative(value) {
+						Some(truncated)
+					} else {
+						None
+					}
+				}
+
+				/// Truncates `value` to it's lower `bits` bits, if it fits in them as signed
+				///
+				/// This is the `const` version of [`TruncateBits::checked_sign_truncate_to`].
+				#[must_use]
+				#[inline]
+				pub const fn checked_sign_truncate_to(value: $T, bits: u32) -> Option<$T> {
+					let truncated = Const::<$T>::sign_truncate_to(value, bits);
+					if truncated == value {
+						Some(truncated)
+					} else {
+						None
+					}
+				}
+
+				/// Truncates `value` to it's lower `bits` bits, clamping it to their unsigned range
+				///
+				/// This is the `const` version of [`TruncateBits::saturating_truncate_to`].
+				#[must_use]
+				#[inline]
+				#[allow(clippy::as_conversions)]
+				pub const fn saturating_truncate_to(value: $T, bits: u32) -> $T {
+					match bits {
+						0 => 0,
+						_ if Const::<$T>::is_negative(value) => 0,
+						_ if bits >= <$T>::BITS => value,
+						_ => {
+							// Note: Casting between signedness is a no-op.
+							let value = Const::<$T>::as_unsigned(value);
+							let max = <<$T as Signed>::Unsigned>::MAX >> (<$T>::BITS - bits);
+							if value > max {
+								max as $T
+							} else {
+								value as $T
+							}
+						},
+					}
+				}
+
+				/// Truncates `value` to it's lower `bits` bits, clamping it to their signed range
+				///
+				/// This is the `const` version of [`TruncateBits::saturating_sign_truncate_to`].
+				#[must_use]
+				#[inline]
+				#[allow(clippy::as_conversions)]
+				pub const fn saturating_sign_truncate_to(value: $T, bits: u32) -> $T {
+					match bits {
+						0 => 0,
+						_ if bits >= <$T>::BITS => value,
+						_ => {
+							// Note: Arithmetic shifting the signed range keeps it's sign.
+							// Note: Casting between signedness is a no-op.
+							let value = Const::<$T>::as_signed(value);
+							let shift = <$T>::BITS - bits;
+							let min = <<$T as Signed>::Signed>::MIN >> shift;
+							let max = <<$T as Signed>::Signed>::MAX >> shift;
+							if value < min {
+								min as $T
+							} else if value > max {
+								max as $T
+							} else {
+								value as $T
+							}
+						},
+					}
+				}
+			}
+
+			impl TruncateBits for $T {
+				#[inline]
+				fn truncate_to(self, bits: u32) -> Self {
+					Const::<$T>::truncate_to(self, bits)
+				}
+
+				#[inline]
+				fn sign_truncate_to(self, bits: u32) -> Self {
+					Const::<$T>::sign_truncate_to(self, bits)
+				}
+
+				#[inline]
+				fn checked_truncate_to(self, bits: u32) -> Option<Self> {
+					Const::<$T>::checked_truncate_to(self, bits)
+				}
+
+				#[inline]
+				fn checked_sign_truncate_to(self, bits: u32) -> Option<Self> {
+					Const::<$T>::checked_sign_truncate_to(self, bits)
+				}
+
+				#[inline]
+				fn saturating_truncate_to(self, bits: u32) -> Self {
+					Const::<$T>::saturating_truncate_to(self, bits)
+				}
+
+				#[inline]
+				fn saturating_sign_truncate_to(self, bits: u32) -> Self {
+					Const::<$T>::saturating_sign_truncate_to(self, bits)
+				}
+			}
+		)+
+	};
+}
+
+impl_truncate_bits! { u8, u16, u32, u64, u128, usize }
+impl_truncate_bits! { i8, i16, i32, i64, i128, isize }
+
+// Check that all `TruncateBits` impls exist
+static_assertions::assert_impl_all! { i8   : TruncateBits }
+static_assertions::assert_impl_all! { i16  : TruncateBits }
+static_assertions::assert_impl_all! { i32  : TruncateBits }
+static_assertions::assert_impl_all! { i64  : TruncateBits }
+static_assertions::assert_impl_all! { i128 : TruncateBits }
+static_assertions::assert_impl_all! { isize: TruncateBits }
+static_assertions::assert_impl_all! { u8   : TruncateBits }
+static_assertions::assert_impl_all! { u16  : TruncateBits }
+static_assertions::assert_impl_all! { u32  : TruncateBits }
+static_assertions::assert_impl_all! { u64  : TruncateBits }
+static_assertions::assert_impl_all! { u128 : TruncateBits }
+static_assertions::assert_impl_all! { usize: TruncateBits }
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use super::*;
+
+	#[test]
+	#[rustfmt::skip]
+	fn truncate_to() {
+		assert_eq!(0x1234_5678u32.truncate_to(20), 0x0004_5678);
+		assert_eq!(       (-1i32).truncate_to(11), 0x7ff);
+		assert_eq!(0x0000_0400i32.sign_truncate_to(11), -0x400);
+		assert_eq!(0x0000_0bffi32.sign_truncate_to(11), 0x3ff);
+		assert_eq!(       (-1i32).sign_truncate_to(11), -1);
+		assert_eq!(0x0000_0400u32.sign_truncate_to(11), 0xffff_fc00);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn truncate_to_edges() {
+		assert_eq!(u32::MAX.truncate_to( 0), 0);
+		assert_eq!(u32::MAX.sign_truncate_to( 0), 0);
+		assert_eq!(i32::MIN.truncate_to(32), i32::MIN);
+		assert_eq!(i32::MIN.sign_truncate_to(64), i32::MIN);
+
+		assert_eq!(1u32.checked_truncate_to(0), None);
+		assert_eq!(u32::MAX.checked_truncate_to(32), Some(u32::MAX));
+		assert_eq!(u32::MAX.saturating_truncate_to( 0), 0);
+		assert_eq!(i32::MIN.saturating_sign_truncate_to( 0), 0);
+		assert_eq!(i32::MIN.saturating_sign_truncate_to(32), i32::MIN);
+		assert_eq!(i32::MIN.saturating_sign_truncate_to(64), i32::MIN);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn checked_truncate_to() {
+		assert_eq!(0x0000_07ffu32.checked_truncate_to(11), Some(0x7ff));
+		assert_eq!(0x0000_0800u32.checked_truncate_to(11), None);
+		assert_eq!(       (-1i32).checked_truncate_to(11), None);
+		assert_eq!(       (-1i32).checked_truncate_to(32), None);
+		assert_eq!(       (-1i32).checked_truncate_to(40), None);
+		assert_eq!(      i32::MAX.checked_truncate_to(32), Some(i32::MAX));
+		assert_eq!(0x0000_03ffi32.checked_sign_truncate_to(11), Some(0x3ff));
+		assert_eq!(0x0000_0400i32.checked_sign_truncate_to(11), None);
+		assert_eq!(      (-0x400i32).checked_sign_truncate_to(11), Some(-0x400));
+		assert_eq!(      (-0x401i32).checked_sign_truncate_to(11), None);
+		assert_eq!(0xffff_fc00u32.checked_sign_truncate_to(11), Some(0xffff_fc00));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn saturating_truncate_to() {
+		assert_eq!(0x0000_07ffu32.saturating_truncate_to(11), 0x7ff);
+		assert_eq!(0x1234_5678u32.saturating_truncate_to(11), 0x7ff);
+		assert_eq!(       (-5i32).saturating_truncate_to(11), 0);
+		assert_eq!(     i128::MIN.saturating_truncate_to( 1), 0);
+		assert_eq!(       (-1i32).saturating_truncate_to(32), 0);
+		assert_eq!(       (-1i32).saturating_truncate_to(40), 0);
+		assert_eq!(      i32::MAX.saturating_truncate_to(32), i32::MAX);
+		assert_eq!(0x0000_07ffi32.saturating_truncate_to(11), 0x7ff);
+		assert_eq!(0x0000_0400i32.saturating_sign_truncate_to(11), 0x3ff);
+		assert_eq!(    (-0x1000i32).saturating_sign_truncate_to(11), -0x400);
+		assert_eq!(       (-5i32).saturating_sign_truncate_to(11), -5);
+		assert_eq!(     i128::MAX.saturating_sign_truncate_to( 1), 0);
+		assert_eq!(     i128::MIN.saturating_sign_truncate_to( 1), -1);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn truncate_bits() {
+		assert_eq!(0xfff0u16.truncate_bits::<12>(), 0x0ff0);
+		assert_eq!(0x0800u16.sign_truncate_bits::<12>(), 0xf800);
+		assert_eq!(0x0fffu16.checked_truncate_bits::<12>(), Some(0x0fff));
+		assert_eq!(0x1fffu16.checked_truncate_bits::<12>(), None);
+		assert_eq!(0xf800u16.checked_sign_truncate_bits::<12>(), Some(0xf800));
+		assert_eq!(0x0800u16.checked_sign_truncate_bits::<12>(), None);
+		assert_eq!(0xffffu16.saturating_truncate_bits::<12>(), 0x0fff);
+		assert_eq!(0x8000u16.saturating_sign_truncate_bits::<12>(), 0xf800);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn truncated_to() {
+		assert_eq!(0x1234_5678_9abc_def0u64.truncated_to::<u32>(20), 0x000c_def0);
+		assert_eq!(0x1234_5678_9abc_def0u64.sign_truncated_to::<u32>(20), 0xfffc_def0);
+		assert_eq!(0x1234_5678_9abc_def0u64.sign_truncated_to::<i32>(20), -0x3_2110);
+		assert_eq!(0x1234_5678_9abc_def0u64.truncated_to::<u16>(20), 0xdef0);
+	}
+}
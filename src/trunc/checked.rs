@@ -0,0 +1,409 @@
+//! Checked truncation
+
+// Imports
+use crate::{raw, Const};
+use core::fmt;
+
+/// Error for when a truncation would lose information
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct TruncateError;
+
+impl fmt::Display for TruncateError {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("value out of range of the truncated type")
+	}
+}
+
+/// Fallibly truncates this integer to a lower size
+///
+/// Unlike [`Truncate`](crate::Truncate), this trait checks if the value
+/// can be represented in the smaller integer.
+pub trait TryTruncate<T>: Sized {
+	/// Performs the truncation, if the value fits in `T`
+	///
+	/// # Errors
+	/// Returns [`TruncateError`] if the value doesn't fit in `T`.
+	fn try_truncate(self) -> Result<T, TruncateError>;
+
+	/// Performs the truncation, returning if any information was lost
+	fn overflowing_truncate(self) -> (T, bool);
+}
+
+/// Truncating to the same type always succeeds
+impl<T> TryTruncate<T> for T {
+	#[inline]
+	fn try_truncate(self) -> Result<T, TruncateError> {
+		Ok(self)
+	}
+
+	#[inline]
+	fn overflowing_truncate(self) -> (T, bool) {
+		(self, false)
+	}
+}
+
+/// Macro to help implement the `const` version of [`TryTruncate`] to the same type
+///
+/// Note: The trait itself is implemented by the blanket implementation above, so
+///       we only implement it for references here, by copying the underlying type.
+macro_rules! impl_try_truncate_identity {
+	($( $T:ty ),+ $(,)?) => {
+		$(
+			impl Const<$T> {
+				/// Truncates `value` into the same type, which always succeeds
+				///
+				/// This is the `const` version of [`TryTruncate::try_truncate`].
+				///
+				/// # Errors
+				/// Never returns an error.
+				#[inline]
+				pub const fn try_truncate(value: $T) -> Result<$T, TruncateError> {
+					Ok(value)
+				}
+
+				/// Truncates `value` into the same type, which never loses information
+				///
+				/// This is the `const` version of [`TryTruncate::overflowing_truncate`].
+				#[must_use]
+				#[inline]
+				pub const fn overflowing_truncate(value: $T) -> ($T, bool) {
+					(value, false)
+				}
+			}
+
+			impl<'a> TryTruncate<$T> for &'a $T {
+				#[inline]
+				fn try_truncate(self) -> Result<$T, TruncateError> {
+					<$T as TryTruncate<$T>>::try_truncate(*self)
+				}
+
+				#[inline]
+				fn overflowing_truncate(self) -> ($T, bool) {
+					<$T as TryTruncate<$T>>::overflowing_truncate(*self)
+				}
+			}
+		)+
+	};
+}
+
+impl_try_truncate_identity! { u8, u16, u32, u64, u128, usize }
+impl_try_truncate_identity! { i8, i16, i32, i64, i128, isize }
+
+/// Macro to help implement `TryTruncate`
+///
+/// Note: Just like `Truncate`, we don't implement `TryTruncate<&'b U> for &'a T`,
+///       but we do implement `TryTruncate<U> for &'a T` by copying the underlying type.
+macro_rules! impl_try_truncate {
+	($T:ty => $($U:ty),* $(,)?) => {
+		$(
+			impl Const<$T, $U> {
+				/// Truncates `value`, if it fits
+				///
+				/// This is the `const` version of [`TryTruncate::try_truncate`].
+				///
+				/// # Errors
+				/// Returns [`TruncateError`] if the value doesn't fit.
+				#[inline]
+				pub const fn try_truncate(value: $T) -> Result<$U, TruncateError> {
+					// Note: The truncation only loses information when the value doesn't fit in `U`
+					if raw::fits_raw::<$T, $U>(Const::<$T>::to_raw(value)) {
+						Ok(Const::<$T, $U>::truncate(value))
+					} else {
+						Err(TruncateError)
+					}
+				}
+
+				/// Truncates `value`, returning if any information was lost
+				///
+				/// This is the `const` version of [`TryTruncate::overflowing_truncate`].
+				#[must_use]
+				#[inline]
+				pub const fn overflowing_truncate(value: $T) -> ($U, bool) {
+					let truncated = Const::<$T, $U>::truncate(value);
+					let overflowed = !raw::fits_raw::<$T, $U>(Const::<$T>::to_raw(value));
+					(truncated, overflowed)
+				}
+			}
+
+			impl TryTruncate<$U> for $T {
+				#[inline]
+				fn try_truncate(self) -> Result<$U, TruncateError> {
+					Const::<$T, $U>::try_truncate(self)
+				}
+
+				#[inline]
+				fn overflowing_truncate(self) -> ($U, bool) {
+					Const::<$T, $U>::overflowing_truncate(self)
+				}
+			}
+
+			impl<'a> TryTruncate<$U> for &'a $T {
+				#[inline]
+				fn try_truncate(self) -> Result<$U, TruncateError> {
+					<$T as TryTruncate<$U>>::try_truncate(*self)
+				}
+
+				#[inline]
+				fn overflowing_truncate(self) -> ($U, bool) {
+					<$T as TryTruncate<$U>>::overflowing_truncate(*self)
+				}
+			}
+		)*
+	};
+}
+
+// Unsigned
+impl_try_truncate! { u128 => u64, u32, u16, u8 }
+impl_try_truncate! { u64  =>      u32, u16, u8 }
+impl_try_truncate! { u32  =>           u16, u8 }
+impl_try_truncate! { u16  =>                u8 }
+
+// Signed
+impl_try_truncate! { i128 => i64, i32, i16, i8 }
+impl_try_truncate! { i64  =>      i32, i16, i8 }
+impl_try_truncate! { i32  =>           i16, i8 }
+impl_try_truncate! { i16  =>                i8 }
+
+// Unsigned to signed
+impl_try_truncate! { u128 => i64, i32, i16, i8 }
+impl_try_truncate! { u64  =>      i32, i16, i8 }
+impl_try_truncate! { u32  =>           i16, i8 }
+impl_try_truncate! { u16  =>                i8 }
+
+// Signed to unsigned
+impl_try_truncate! { i128 => u64, u32, u16, u8 }
+impl_try_truncate! { i64  =>      u32, u16, u8 }
+impl_try_truncate! { i32  =>           u16, u8 }
+impl_try_truncate! { i16  =>                u8 }
+
+// Pointer-sized
+// Note: These only exist where `U` is smaller than `T`, or of the same size and signedness, for the current pointer width.
+impl_try_truncate! { u64   => usize }
+impl_try_truncate! { u128  => usize, isize }
+impl_try_truncate! { usize => u8, u16, i8 }
+impl_try_truncate! { i64   => isize }
+impl_try_truncate! { i128  => usize, isize }
+impl_try_truncate! { isize => u8, i8, i16 }
+#[cfg(any(target_pointer_width = "16", target_pointer_width = "32"))]
+impl_try_truncate! { u32   => usize }
+#[cfg(any(target_pointer_width = "16", target_pointer_width = "32"))]
+impl_try_truncate! { u64   => isize }
+#[cfg(any(target_pointer_width = "16", target_pointer_width = "32"))]
+impl_try_truncate! { i32   => isize }
+#[cfg(any(target_pointer_width = "16", target_pointer_width = "32"))]
+impl_try_truncate! { i64   => usize }
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl_try_truncate! { usize => u32, i16 }
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl_try_truncate! { isize => u16, i32 }
+#[cfg(target_pointer_width = "16")]
+impl_try_truncate! { u16   => usize }
+#[cfg(target_pointer_width = "16")]
+impl_try_truncate! { u32   => isize }
+#[cfg(target_pointer_width = "16")]
+impl_try_truncate! { i16   => isize }
+#[cfg(target_pointer_width = "16")]
+impl_try_truncate! { i32   => usize }
+#[cfg(target_pointer_width = "64")]
+impl_try_truncate! { usize => u64, i32 }
+#[cfg(target_pointer_width = "64")]
+impl_try_truncate! { isize => u32, i64 }
+
+/// Helper trait for [`TryTruncate`] to be used with turbofish syntax
+pub trait TryTruncated {
+	/// Tries to truncate this type
+	///
+	/// # Errors
+	/// Returns [`TruncateError`] if the value doesn't fit in `T`.
+	#[inline]
+	fn try_truncated<T>(self) -> Result<T, TruncateError>
+	where
+		Self: TryTruncate<T>,
+	{
+		self.try_truncate()
+	}
+
+	/// Truncates this type, returning if any information was lost
+	#[inline]
+	fn overflowing_truncated<T>(self) -> (T, bool)
+	where
+		Self: TryTruncate<T>,
+	{
+		self.overflowing_truncate()
+	}
+}
+impl<T> TryTruncated for T {}
+
+// Check that all `TryTruncate` impls exist
+static_assertions::assert_impl_all! { i128 : TryTruncate<i128>, TryTruncate<i64>, TryTruncate<i32>, TryTruncate<i16>, TryTruncate<i8> }
+static_assertions::assert_impl_all! { i64  :                    TryTruncate<i64>, TryTruncate<i32>, TryTruncate<i16>, TryTruncate<i8> }
+static_assertions::assert_impl_all! { i32  :                                      TryTruncate<i32>, TryTruncate<i16>, TryTruncate<i8> }
+static_assertions::assert_impl_all! { i16  :                                                        TryTruncate<i16>, TryTruncate<i8> }
+static_assertions::assert_impl_all! { i8   :                                                                          TryTruncate<i8> }
+static_assertions::assert_impl_all! { u128 : TryTruncate<u128>, TryTruncate<u64>, TryTruncate<u32>, TryTruncate<u16>, TryTruncate<u8> }
+static_assertions::assert_impl_all! { u64  :                    TryTruncate<u64>, TryTruncate<u32>, TryTruncate<u16>, TryTruncate<u8> }
+static_assertions::assert_impl_all! { u32  :                                      TryTruncate<u32>, TryTruncate<u16>, TryTruncate<u8> }
+static_assertions::assert_impl_all! { u16  :                                                        TryTruncate<u16>, TryTruncate<u8> }
+static_assertions::assert_impl_all! { u8   :                                                                          TryTruncate<u8> }
+
+// Check that all mixed-signedness `TryTruncate` impls exist
+static_assertions::assert_impl_all! { i128 :                    TryTruncate<u64>, TryTruncate<u32>, TryTruncate<u16>, TryTruncate<u8> }
+static_assertions::assert_impl_all! { i64  :                                      TryTruncate<u32>, TryTruncate<u16>, TryTruncate<u8> }
+static_assertions::assert_impl_all! { i32  :                                                        TryTruncate<u16>, TryTruncate<u8> }
+static_assertions::assert_impl_all! { i16  :                                                                          TryTruncate<u8> }
+static_assertions::assert_impl_all! { u128 :                    TryTruncate<i64>, TryTruncate<i32>, TryTruncate<i16>, TryTruncate<i8> }
+static_assertions::assert_impl_all! { u64  :                                      TryTruncate<i32>, TryTruncate<i16>, TryTruncate<i8> }
+static_assertions::assert_impl_all! { u32  :                                                        TryTruncate<i16>, TryTruncate<i8> }
+static_assertions::assert_impl_all! { u16  :                                                                          TryTruncate<i8> }
+
+// Check that integers of the same size, but different signedness, don't implement `TryTruncate`
+static_assertions::assert_not_impl_any! { i8   : TryTruncate<u8> }
+static_assertions::assert_not_impl_any! { i16  : TryTruncate<u16> }
+static_assertions::assert_not_impl_any! { i32  : TryTruncate<u32> }
+static_assertions::assert_not_impl_any! { i64  : TryTruncate<u64> }
+static_assertions::assert_not_impl_any! { i128 : TryTruncate<u128> }
+static_assertions::assert_not_impl_any! { u8   : TryTruncate<i8> }
+static_assertions::assert_not_impl_any! { u16  : TryTruncate<i16> }
+static_assertions::assert_not_impl_any! { u32  : TryTruncate<i32> }
+static_assertions::assert_not_impl_any! { u64  : TryTruncate<i64> }
+static_assertions::assert_not_impl_any! { u128 : TryTruncate<i128> }
+
+// Check that all pointer-sized `TryTruncate` impls exist for each pointer width
+#[cfg(target_pointer_width = "16")]
+static_assertions::assert_impl_all! { usize : TryTruncate<usize>, TryTruncate<u8>, TryTruncate<u16>, TryTruncate<i8> }
+#[cfg(target_pointer_width = "16")]
+static_assertions::assert_not_impl_any! { usize : TryTruncate<u32>, TryTruncate<u64>, TryTruncate<u128>, TryTruncate<i16>, TryTruncate<i32>, TryTruncate<i64>, TryTruncate<i128>, TryTruncate<isize> }
+#[cfg(target_pointer_width = "16")]
+static_assertions::assert_impl_all! { isize : TryTruncate<isize>, TryTruncate<u8>, TryTruncate<i8>, TryTruncate<i16> }
+#[cfg(target_pointer_width = "16")]
+static_assertions::assert_not_impl_any! { isize : TryTruncate<u16>, TryTruncate<u32>, TryTruncate<u64>, TryTruncate<u128>, TryTruncate<usize>, TryTruncate<i32>, TryTruncate<i64>, TryTruncate<i128> }
+#[cfg(target_pointer_width = "32")]
+static_assertions::assert_impl_all! { usize : TryTruncate<usize>, TryTruncate<u8>, TryTruncate<u16>, TryTruncate<u32>, TryTruncate<i8>, TryTruncate<i16> }
+#[cfg(target_pointer_width = "32")]
+static_assertions::assert_not_impl_any! { usize : TryTruncate<u64>, TryTruncate<u128>, TryTruncate<i32>, TryTruncate<i64>, TryTruncate<i128>, TryTruncate<isize> }
+#[cfg(target_pointer_width = "32")]
+static_assertions::assert_impl_all! { isize : TryTruncate<isize>, TryTruncate<u8>, TryTruncate<u16>, TryTruncate<i8>, TryTruncate<i16>, TryTruncate<i32> }
+#[cfg(target_pointer_width = "32")]
+static_assertions::assert_not_impl_any! { isize : TryTruncate<u32>, TryTruncate<u64>, TryTruncate<u128>, TryTruncate<usize>, TryTruncate<i64>, TryTruncate<i128> }
+#[cfg(target_pointer_width = "64")]
+static_assertions::assert_impl_all! { usize : TryTruncate<usize>, TryTruncate<u8>, TryTruncate<u16>, TryTruncate<u32>, TryTruncate<u64>, TryTruncate<i8>, TryTruncate<i16>, TryTruncate<i32> }
+#[cfg(target_pointer_width = "64")]
+static_assertions::assert_not_impl_any! { usize : TryTruncate<u128>, TryTruncate<i64>, TryTruncate<i128>, TryTruncate<isize> }
+#[cfg(target_pointer_width = "64")]
+static_assertions::assert_impl_all! { isize : TryTruncate<isize>, TryTruncate<u8>, TryTruncate<u16>, TryTruncate<u32>, TryTruncate<i8>, TryTruncate<i16>, TryTruncate<i32>, TryTruncate<i64> }
+#[cfg(target_pointer_width = "64")]
+static_assertions::assert_not_impl_any! { isize : TryTruncate<u64>, TryTruncate<u128>, TryTruncate<usize>, TryTruncate<i128> }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	#[rustfmt::skip]
+	fn try_truncate_unsigned() {
+		assert_eq!(u128::try_truncated::< u64>(u128::from(u64::MAX)), Ok(u64::MAX));
+		assert_eq!( u64::try_truncated::< u32>( u64::from(u32::MAX)), Ok(u32::MAX));
+		assert_eq!( u32::try_truncated::< u16>( u32::from(u16::MAX)), Ok(u16::MAX));
+		assert_eq!( u16::try_truncated::<  u8>( u16::from( u8::MAX)), Ok( u8::MAX));
+
+		assert_eq!(u128::try_truncated::< u64>(u128::from(u64::MAX) + 1), Err(TruncateError));
+		assert_eq!( u64::try_truncated::< u32>( u64::from(u32::MAX) + 1), Err(TruncateError));
+		assert_eq!( u32::try_truncated::< u16>( u32::from(u16::MAX) + 1), Err(TruncateError));
+		assert_eq!( u16::try_truncated::<  u8>( u16::from( u8::MAX) + 1), Err(TruncateError));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn try_truncate_signed() {
+		assert_eq!(i128::try_truncated::< i64>(i128::from(i64::MIN)), Ok(i64::MIN));
+		assert_eq!( i64::try_truncated::< i32>( i64::from(i32::MIN)), Ok(i32::MIN));
+		assert_eq!( i32::try_truncated::< i16>( i32::from(i16::MIN)), Ok(i16::MIN));
+		assert_eq!( i16::try_truncated::<  i8>( i16::from( i8::MIN)), Ok( i8::MIN));
+
+		assert_eq!(i128::try_truncated::< i64>(i128::from(i64::MIN) - 1), Err(TruncateError));
+		assert_eq!( i64::try_truncated::< i32>( i64::from(i32::MIN) - 1), Err(TruncateError));
+		assert_eq!( i32::try_truncated::< i16>( i32::from(i16::MIN) - 1), Err(TruncateError));
+		assert_eq!( i16::try_truncated::<  i8>( i16::from( i8::MIN) - 1), Err(TruncateError));
+
+		assert_eq!(i128::try_truncated::< i64>(i128::from(i64::MAX) + 1), Err(TruncateError));
+		assert_eq!( i64::try_truncated::< i32>( i64::from(i32::MAX) + 1), Err(TruncateError));
+		assert_eq!( i32::try_truncated::< i16>( i32::from(i16::MAX) + 1), Err(TruncateError));
+		assert_eq!( i16::try_truncated::<  i8>( i16::from( i8::MAX) + 1), Err(TruncateError));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn overflowing_truncate() {
+		assert_eq!(u128::overflowing_truncated::< u64>(1), (1, false));
+		assert_eq!( u64::overflowing_truncated::< u32>(1), (1, false));
+		assert_eq!( u32::overflowing_truncated::< u16>(1), (1, false));
+		assert_eq!( u16::overflowing_truncated::<  u8>(1), (1, false));
+		assert_eq!(i128::overflowing_truncated::< i64>(-1), (-1, false));
+		assert_eq!( i64::overflowing_truncated::< i32>(-1), (-1, false));
+		assert_eq!( i32::overflowing_truncated::< i16>(-1), (-1, false));
+		assert_eq!( i16::overflowing_truncated::<  i8>(-1), (-1, false));
+
+		assert_eq!(u128::overflowing_truncated::< u64>(u128::MAX), (u64::MAX, true));
+		assert_eq!( u64::overflowing_truncated::< u32>( u64::MAX), (u32::MAX, true));
+		assert_eq!( u32::overflowing_truncated::< u16>( u32::MAX), (u16::MAX, true));
+		assert_eq!( u16::overflowing_truncated::<  u8>( u16::MAX), ( u8::MAX, true));
+		assert_eq!(i128::overflowing_truncated::< i64>(i128::MAX), (-1, true));
+		assert_eq!( i64::overflowing_truncated::< i32>( i64::MAX), (-1, true));
+		assert_eq!( i32::overflowing_truncated::< i16>( i32::MAX), (-1, true));
+		assert_eq!( i16::overflowing_truncated::<  i8>( i16::MAX), (-1, true));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn try_truncate_mixed() {
+		assert_eq!(u128::try_truncated::< i64>(i64::MAX as u128), Ok(i64::MAX));
+		assert_eq!( u16::try_truncated::<  i8>( i8::MAX as u16), Ok( i8::MAX));
+		assert_eq!(u128::try_truncated::< i64>(i64::MAX as u128 + 1), Err(TruncateError));
+		assert_eq!( u16::try_truncated::<  i8>( i8::MAX as u16 + 1), Err(TruncateError));
+
+		assert_eq!(i128::try_truncated::< u64>(i128::from(u64::MAX)), Ok(u64::MAX));
+		assert_eq!( i16::try_truncated::<  u8>( i16::from( u8::MAX)), Ok( u8::MAX));
+		assert_eq!(i128::try_truncated::< u64>(-1), Err(TruncateError));
+		assert_eq!( i16::try_truncated::<  u8>(-1), Err(TruncateError));
+
+		assert_eq!(i128::overflowing_truncated::< u64>(-1), (u64::MAX, true));
+		assert_eq!( u16::overflowing_truncated::<  i8>(u16::MAX), (-1, true));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	#[cfg(target_pointer_width = "64")]
+	fn try_truncate_pointer_sized() {
+		assert_eq!(usize::try_truncated::<u32>(usize::MAX >> 32), Ok(u32::MAX));
+		assert_eq!(usize::try_truncated::<u32>(usize::MAX), Err(TruncateError));
+		assert_eq!(isize::try_truncated::<u32>(-1), Err(TruncateError));
+		assert_eq!(u64::try_truncated::<usize>(u64::MAX), Ok(usize::MAX));
+		assert_eq!(u128::try_truncated::<usize>(u128::MAX), Err(TruncateError));
+		assert_eq!(i128::try_truncated::<usize>(-1), Err(TruncateError));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	#[cfg(target_pointer_width = "32")]
+	fn try_truncate_pointer_sized() {
+		assert_eq!(usize::try_truncated::<u16>(usize::MAX >> 16), Ok(u16::MAX));
+		assert_eq!(usize::try_truncated::<u16>(usize::MAX), Err(TruncateError));
+		assert_eq!(isize::try_truncated::<u16>(-1), Err(TruncateError));
+		assert_eq!(u32::try_truncated::<usize>(u32::MAX), Ok(usize::MAX));
+		assert_eq!(u64::try_truncated::<usize>(u64::MAX), Err(TruncateError));
+		assert_eq!(i64::try_truncated::<usize>(-1), Err(TruncateError));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	#[cfg(target_pointer_width = "16")]
+	fn try_truncate_pointer_sized() {
+		assert_eq!(usize::try_truncated::<u8>(usize::MAX >> 8), Ok(u8::MAX));
+		assert_eq!(usize::try_truncated::<u8>(usize::MAX), Err(TruncateError));
+		assert_eq!(isize::try_truncated::<u8>(-1), Err(TruncateError));
+		assert_eq!(u16::try_truncated::<usize>(u16::MAX), Ok(usize::MAX));
+		assert_eq!(u32::try_truncated::<usize>(u32::MAX), Err(TruncateError));
+		assert_eq!(i32::try_truncated::<usize>(-1), Err(TruncateError));
+	}
+}
@@ -0,0 +1,330 @@
+//! Saturating truncation
+
+// Imports
+use crate::Const;
+
+/// Truncates this integer to a lower size, saturating at the bounds of `T`
+///
+/// Unlike [`Truncate`](crate::Truncate), values that don't fit in `T`
+/// are clamped to `T::MIN` / `T::MAX` instead of having their upper bits dropped.
+pub trait SaturatingTruncate<T>: Sized {
+	/// Performs the truncation
+	fn saturating_truncate(self) -> T;
+}
+
+/// Truncating to the same type simply returns it
+impl<T> SaturatingTruncate<T> for T {
+	#[inline]
+	fn saturating_truncate(self) -> T {
+		self
+	}
+}
+
+/// Macro to help implement the `const` version of [`SaturatingTruncate`] to the same type
+///
+/// Note: The trait itself is implemented by the blanket implementation above, so
+///       we only implement it for references here, by copying the underlying type.
+macro_rules! impl_saturating_truncate_identity {
+	($( $T:ty ),+ $(,)?) => {
+		$(
+			impl Const<$T> {
+				/// Truncates `value` into the same type, returning it
+				///
+				/// This is the `const` version of [`SaturatingTruncate::saturating_truncate`].
+				#[must_use]
+				#[inline]
+				pub const fn saturating_truncate(value: $T) -> $T {
+					value
+				}
+			}
+
+			impl<'a> SaturatingTruncate<$T> for &'a $T {
+				#[inline]
+				fn saturating_truncate(self) -> $T {
+					<$T as SaturatingTruncate<$T>>::saturating_truncate(*self)
+				}
+			}
+		)+
+	};
+}
+
+impl_saturating_truncate_identity! { u8, u16, u32, u64, u128, usize }
+impl_saturating_truncate_identity! { i8, i16, i32, i64, i128, isize }
+
+/// Macro to help implement `SaturatingTruncate`
+///
+/// Note: Just like `Truncate`, we don't implement `SaturatingTruncate<&'b U> for &'a T`,
+///       but we do implement `SaturatingTruncate<U> for &'a T` by copying the underlying type.
+macro_rules! impl_saturating_truncate {
+	(@impl $T:ty => $U:ty => |$value:ident| $is_negative:expr) => {
+		impl Const<$T, $U> {
+			/// Truncates `value`, clamping it to the range of the smaller integer
+			///
+			/// This is the `const` version of [`SaturatingTruncate::saturating_truncate`].
+			#[must_use]
+			#[inline]
+			pub const fn saturating_truncate(value: $T) -> $U {
+				// Note: Truncation only fails if we're outside of `U`'s range, so we just
+				//       need to check which side of it we're on.
+				match Const::<$T, $U>::try_truncate(value) {
+					Ok(value) => value,
+					Err(_) => {
+						let $value = value;
+						if $is_negative {
+							<$U>::MIN
+						} else {
+							<$U>::MAX
+						}
+					},
+				}
+			}
+		}
+
+		impl SaturatingTruncate<$U> for $T {
+			#[inline]
+			fn saturating_truncate(self) -> $U {
+				Const::<$T, $U>::saturating_truncate(self)
+			}
+		}
+
+		impl<'a> SaturatingTruncate<$U> for &'a $T {
+			#[inline]
+			fn saturating_truncate(self) -> $U {
+				<$T as SaturatingTruncate<$U>>::saturating_truncate(*self)
+			}
+		}
+	};
+
+	(+ $T:ty => $($U:ty),* $(,)?) => {
+		$(
+			impl_saturating_truncate! { @impl $T => $U => |_value| false }
+		)*
+	};
+
+	(- $T:ty => $($U:ty),* $(,)?) => {
+		$(
+			impl_saturating_truncate! { @impl $T => $U => |value| value < 0 }
+		)*
+	};
+}
+
+// Unsigned
+impl_saturating_truncate! { +u128 => u64, u32, u16, u8 }
+impl_saturating_truncate! { +u64  =>      u32, u16, u8 }
+impl_saturating_truncate! { +u32  =>           u16, u8 }
+impl_saturating_truncate! { +u16  =>                u8 }
+
+// Signed
+impl_saturating_truncate! { -i128 => i64, i32, i16, i8 }
+impl_saturating_truncate! { -i64  =>      i32, i16, i8 }
+impl_saturating_truncate! { -i32  =>           i16, i8 }
+impl_saturating_truncate! { -i16  =>                i8 }
+
+// Unsigned to signed
+impl_saturating_truncate! { +u128 => i64, i32, i16, i8 }
+impl_saturating_truncate! { +u64  =>      i32, i16, i8 }
+impl_saturating_truncate! { +u32  =>           i16, i8 }
+impl_saturating_truncate! { +u16  =>                i8 }
+
+// Signed to unsigned
+impl_saturating_truncate! { -i128 => u64, u32, u16, u8 }
+impl_saturating_truncate! { -i64  =>      u32, u16, u8 }
+impl_saturating_truncate! { -i32  =>           u16, u8 }
+impl_saturating_truncate! { -i16  =>                u8 }
+
+// Pointer-sized
+// Note: These only exist where `U` is smaller than `T`, or of the same size and signedness, for the current pointer width.
+impl_saturating_truncate! { +u64   => usize }
+impl_saturating_truncate! { +u128  => usize, isize }
+impl_saturating_truncate! { +usize => u8, u16, i8 }
+impl_saturating_truncate! { -i64   => isize }
+impl_saturating_truncate! { -i128  => usize, isize }
+impl_saturating_truncate! { -isize => u8, i8, i16 }
+#[cfg(any(target_pointer_width = "16", target_pointer_width = "32"))]
+impl_saturating_truncate! { +u32   => usize }
+#[cfg(any(target_pointer_width = "16", target_pointer_width = "32"))]
+impl_saturating_truncate! { +u64   => isize }
+#[cfg(any(target_pointer_width = "16", target_pointer_width = "32"))]
+impl_saturating_truncate! { -i32   => isize }
+#[cfg(any(target_pointer_width = "16", target_pointer_width = "32"))]
+impl_saturating_truncate! { -i64   => usize }
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl_saturating_truncate! { +usize => u32, i16 }
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl_saturating_truncate! { -isize => u16, i32 }
+#[cfg(target_pointer_width = "16")]
+impl_saturating_truncate! { +u16   => usize }
+#[cfg(target_pointer_width = "16")]
+impl_saturating_truncate! { +u32   => isize }
+#[cfg(target_pointer_width = "16")]
+impl_saturating_truncate! { -i16   => isize }
+#[cfg(target_pointer_width = "16")]
+impl_saturating_truncate! { -i32   => usize }
+#[cfg(target_pointer_width = "64")]
+impl_saturating_truncate! { +usize => u64, i32 }
+#[cfg(target_pointer_width = "64")]
+impl_saturating_truncate! { -isize => u32, i64 }
+
+/// Helper trait for [`SaturatingTruncate`] to be used with turbofish syntax
+pub trait SaturatingTruncated {
+	/// Truncates this type, saturating at the bounds of `T`
+	#[inline]
+	fn saturating_truncated<T>(self) -> T
+	where
+		Self: SaturatingTruncate<T>,
+	{
+		self.saturating_truncate()
+	}
+}
+impl<T> SaturatingTruncated for T {}
+
+// Check that all `SaturatingTruncate` impls exist
+static_assertions::assert_impl_all! { i128 : SaturatingTruncate<i128>, SaturatingTruncate<i64>, SaturatingTruncate<i32>, SaturatingTruncate<i16>, SaturatingTruncate<i8> }
+static_assertions::assert_impl_all! { i64  :                           SaturatingTruncate<i64>, SaturatingTruncate<i32>, SaturatingTruncate<i16>, SaturatingTruncate<i8> }
+static_assertions::assert_impl_all! { i32  :                                                    SaturatingTruncate<i32>, SaturatingTruncate<i16>, SaturatingTruncate<i8> }
+static_assertions::assert_impl_all! { i16  :                                                                             SaturatingTruncate<i16>, SaturatingTruncate<i8> }
+static_assertions::assert_impl_all! { i8   :                                                                                                      SaturatingTruncate<i8> }
+static_assertions::assert_impl_all! { u128 : SaturatingTruncate<u128>, SaturatingTruncate<u64>, SaturatingTruncate<u32>, SaturatingTruncate<u16>, SaturatingTruncate<u8> }
+static_assertions::assert_impl_all! { u64  :                           SaturatingTruncate<u64>, SaturatingTruncate<u32>, SaturatingTruncate<u16>, SaturatingTruncate<u8> }
+static_assertions::assert_impl_all! { u32  :                                                    SaturatingTruncate<u32>, SaturatingTruncate<u16>, SaturatingTruncate<u8> }
+static_assertions::assert_impl_all! { u16  :                                                                             SaturatingTruncate<u16>, SaturatingTruncate<u8> }
+static_assertions::assert_impl_all! { u8   :                                                                                                      SaturatingTruncate<u8> }
+
+// Check that all mixed-signedness `SaturatingTruncate` impls exist
+static_assertions::assert_impl_all! { i128 :                           SaturatingTruncate<u64>, SaturatingTruncate<u32>, SaturatingTruncate<u16>, SaturatingTruncate<u8> }
+static_assertions::assert_impl_all! { i64  :                                                    SaturatingTruncate<u32>, SaturatingTruncate<u16>, SaturatingTruncate<u8> }
+static_assertions::assert_impl_all! { i32  :                                                                             SaturatingTruncate<u16>, SaturatingTruncate<u8> }
+static_assertions::assert_impl_all! { i16  :                                                                                                      SaturatingTruncate<u8> }
+static_assertions::assert_impl_all! { u128 :                           SaturatingTruncate<i64>, SaturatingTruncate<i32>, SaturatingTruncate<i16>, SaturatingTruncate<i8> }
+static_assertions::assert_impl_all! { u64  :                                                    SaturatingTruncate<i32>, SaturatingTruncate<i16>, SaturatingTruncate<i8> }
+static_assertions::assert_impl_all! { u32  :                                                                             SaturatingTruncate<i16>, SaturatingTruncate<i8> }
+static_assertions::assert_impl_all! { u16  :                                                                                                      SaturatingTruncate<i8> }
+
+// Check that integers of the same size, but different signedness, don't implement `SaturatingTruncate`
+static_assertions::assert_not_impl_any! { i8   : SaturatingTruncate<u8> }
+static_assertions::assert_not_impl_any! { i16  : SaturatingTruncate<u16> }
+static_assertions::assert_not_impl_any! { i32  : SaturatingTruncate<u32> }
+static_assertions::assert_not_impl_any! { i64  : SaturatingTruncate<u64> }
+static_assertions::assert_not_impl_any! { i128 : SaturatingTruncate<u128> }
+static_assertions::assert_not_impl_any! { u8   : SaturatingTruncate<i8> }
+static_assertions::assert_not_impl_any! { u16  : SaturatingTruncate<i16> }
+static_assertions::assert_not_impl_any! { u32  : SaturatingTruncate<i32> }
+static_assertions::assert_not_impl_any! { u64  : SaturatingTruncate<i64> }
+static_assertions::assert_not_impl_any! { u128 : SaturatingTruncate<i128> }
+
+// Check that all pointer-sized `SaturatingTruncate` impls exist for each pointer width
+#[cfg(target_pointer_width = "16")]
+static_assertions::assert_impl_all! { usize : SaturatingTruncate<usize>, SaturatingTruncate<u8>, SaturatingTruncate<u16>, SaturatingTruncate<i8> }
+#[cfg(target_pointer_width = "16")]
+static_assertions::assert_not_impl_any! { usize : SaturatingTruncate<u32>, SaturatingTruncate<u64>, SaturatingTruncate<u128>, SaturatingTruncate<i16>, SaturatingTruncate<i32>, SaturatingTruncate<i64>, SaturatingTruncate<i128>, SaturatingTruncate<isize> }
+#[cfg(target_pointer_width = "16")]
+static_assertions::assert_impl_all! { isize : SaturatingTruncate<isize>, SaturatingTruncate<u8>, SaturatingTruncate<i8>, SaturatingTruncate<i16> }
+#[cfg(target_pointer_width = "16")]
+static_assertions::assert_not_impl_any! { isize : SaturatingTruncate<u16>, SaturatingTruncate<u32>, SaturatingTruncate<u64>, SaturatingTruncate<u128>, SaturatingTruncate<usize>, SaturatingTruncate<i32>, SaturatingTruncate<i64>, SaturatingTruncate<i128> }
+#[cfg(target_pointer_width = "32")]
+static_assertions::assert_impl_all! { usize : SaturatingTruncate<usize>, SaturatingTruncate<u8>, SaturatingTruncate<u16>, SaturatingTruncate<u32>, SaturatingTruncate<i8>, SaturatingTruncate<i16> }
+#[cfg(target_pointer_width = "32")]
+static_assertions::assert_not_impl_any! { usize : SaturatingTruncate<u64>, SaturatingTruncate<u128>, SaturatingTruncate<i32>, SaturatingTruncate<i64>, SaturatingTruncate<i128>, SaturatingTruncate<isize> }
+#[cfg(target_pointer_width = "32")]
+static_assertions::assert_impl_all! { isize : SaturatingTruncate<isize>, SaturatingTruncate<u8>, SaturatingTruncate<u16>, SaturatingTruncate<i8>, SaturatingTruncate<i16>, SaturatingTruncate<i32> }
+#[cfg(target_pointer_width = "32")]
+static_assertions::assert_not_impl_any! { isize : SaturatingTruncate<u32>, SaturatingTruncate<u64>, SaturatingTruncate<u128>, SaturatingTruncate<usize>, SaturatingTruncate<i64>, SaturatingTruncate<i128> }
+#[cfg(target_pointer_width = "64")]
+static_assertions::assert_impl_all! { usize : SaturatingTruncate<usize>, SaturatingTruncate<u8>, SaturatingTruncate<u16>, SaturatingTruncate<u32>, SaturatingTruncate<u64>, SaturatingTruncate<i8>, SaturatingTruncate<i16>, SaturatingTruncate<i32> }
+#[cfg(target_pointer_width = "64")]
+static_assertions::assert_not_impl_any! { usize : SaturatingTruncate<u128>, SaturatingTruncate<i64>, SaturatingTruncate<i128>, SaturatingTruncate<isize> }
+#[cfg(target_pointer_width = "64")]
+static_assertions::assert_impl_all! { isize : SaturatingTruncate<isize>, SaturatingTruncate<u8>, SaturatingTruncate<u16>, SaturatingTruncate<u32>, SaturatingTruncate<i8>, SaturatingTruncate<i16>, SaturatingTruncate<i32>, SaturatingTruncate<i64> }
+#[cfg(target_pointer_width = "64")]
+static_assertions::assert_not_impl_any! { isize : SaturatingTruncate<u64>, SaturatingTruncate<u128>, SaturatingTruncate<usize>, SaturatingTruncate<i128> }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	#[rustfmt::skip]
+	fn saturating_truncate_unsigned() {
+		assert_eq!(u128::saturating_truncated::< u64>(1), 1);
+		assert_eq!( u64::saturating_truncated::< u32>(1), 1);
+		assert_eq!( u32::saturating_truncated::< u16>(1), 1);
+		assert_eq!( u16::saturating_truncated::<  u8>(1), 1);
+
+		assert_eq!(u128::saturating_truncated::< u64>(u128::MAX), u64::MAX);
+		assert_eq!( u64::saturating_truncated::< u32>( u64::MAX), u32::MAX);
+		assert_eq!( u32::saturating_truncated::< u16>( u32::MAX), u16::MAX);
+		assert_eq!( u16::saturating_truncated::<  u8>( u16::MAX),  u8::MAX);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn saturating_truncate_signed() {
+		assert_eq!(i128::saturating_truncated::< i64>(-1), -1);
+		assert_eq!( i64::saturating_truncated::< i32>(-1), -1);
+		assert_eq!( i32::saturating_truncated::< i16>(-1), -1);
+		assert_eq!( i16::saturating_truncated::<  i8>(-1), -1);
+
+		assert_eq!(i128::saturating_truncated::< i64>(i128::MAX), i64::MAX);
+		assert_eq!( i64::saturating_truncated::< i32>( i64::MAX), i32::MAX);
+		assert_eq!( i32::saturating_truncated::< i16>( i32::MAX), i16::MAX);
+		assert_eq!( i16::saturating_truncated::<  i8>( i16::MAX),  i8::MAX);
+
+		assert_eq!(i128::saturating_truncated::< i64>(i128::MIN), i64::MIN);
+		assert_eq!( i64::saturating_truncated::< i32>( i64::MIN), i32::MIN);
+		assert_eq!( i32::saturating_truncated::< i16>( i32::MIN), i16::MIN);
+		assert_eq!( i16::saturating_truncated::<  i8>( i16::MIN),  i8::MIN);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn saturating_truncate_mixed() {
+		assert_eq!(u128::saturating_truncated::< i64>(u128::MAX), i64::MAX);
+		assert_eq!( u64::saturating_truncated::< i32>( u64::MAX), i32::MAX);
+		assert_eq!( u32::saturating_truncated::< i16>( u32::MAX), i16::MAX);
+		assert_eq!( u16::saturating_truncated::<  i8>( u16::MAX),  i8::MAX);
+
+		assert_eq!(i128::saturating_truncated::< u64>(i128::MIN), 0);
+		assert_eq!( i64::saturating_truncated::< u32>( i64::MIN), 0);
+		assert_eq!( i32::saturating_truncated::< u16>( i32::MIN), 0);
+		assert_eq!( i16::saturating_truncated::<  u8>( i16::MIN), 0);
+
+		assert_eq!(i128::saturating_truncated::< u64>(i128::MAX), u64::MAX);
+		assert_eq!( i16::saturating_truncated::<  u8>( i16::MAX),  u8::MAX);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	#[cfg(target_pointer_width = "64")]
+	fn saturating_truncate_pointer_sized() {
+		assert_eq!(usize::saturating_truncated::<u32>(usize::MAX), u32::MAX);
+		assert_eq!(isize::saturating_truncated::<i32>(isize::MIN), i32::MIN);
+		assert_eq!(isize::saturating_truncated::<u32>(isize::MIN), 0);
+		assert_eq!(u128::saturating_truncated::<usize>(u128::MAX), usize::MAX);
+		assert_eq!(i128::saturating_truncated::<isize>(i128::MIN), isize::MIN);
+		assert_eq!(i128::saturating_truncated::<usize>(i128::MIN), 0);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	#[cfg(target_pointer_width = "32")]
+	fn saturating_truncate_pointer_sized() {
+		assert_eq!(usize::saturating_truncated::<u16>(usize::MAX), u16::MAX);
+		assert_eq!(isize::saturating_truncated::<i16>(isize::MIN), i16::MIN);
+		assert_eq!(isize::saturating_truncated::<u16>(isize::MIN), 0);
+		assert_eq!(u64::saturating_truncated::<usize>(u64::MAX), usize::MAX);
+		assert_eq!(i64::saturating_truncated::<isize>(i64::MIN), isize::MIN);
+		assert_eq!(i64::saturating_truncated::<usize>(i64::MIN), 0);
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	#[cfg(target_pointer_width = "16")]
+	fn saturating_truncate_pointer_sized() {
+		assert_eq!(usize::saturating_truncated::<u8>(usize::MAX), u8::MAX);
+		assert_eq!(isize::saturating_truncated::<i8>(isize::MIN), i8::MIN);
+		assert_eq!(isize::saturating_truncated::<u8>(isize::MIN), 0);
+		assert_eq!(u32::saturating_truncated::<usize>(u32::MAX), usize::MAX);
+		assert_eq!(i32::saturating_truncated::<isize>(i32::MIN), isize::MIN);
+		assert_eq!(i32::saturating_truncated::<usize>(i32::MIN), 0);
+	}
+}
@@ -0,0 +1,50 @@
+//! Variable-length integer encodings
+//!
+//! This module provides encodings that store integers of small magnitude
+//! in fewer bytes:
+//!
+//! - [`Leb128`]: Unsigned and signed LEB128, as used by DWARF and WASM.
+//! - [`QuicVarint`]: QUIC variable-length integers, with a 2-bit length prefix.
+//! - [`SqliteVarint`]: `SQLite` variable-length integers, of up to 9 bytes.
+//!
+//! Integers are encoded into and decoded from byte slices, so no allocation is needed.
+//! Decoding rejects any integer that doesn't fit in the type it's decoded into and,
+//! except for QUIC where the shortest encoding is only required for frame types,
+//! any integer not encoded in it's shortest form.
+
+// Modules
+pub mod leb128;
+pub mod quic;
+pub mod sqlite;
+
+// Exports
+pub use leb128::Leb128;
+pub use quic::QuicVarint;
+pub use sqlite::SqliteVarint;
+
+// Imports
+use core::fmt;
+
+/// Error for when encoding or decoding a variable-length integer fails
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum VarintError {
+	/// The buffer ended before the end of the integer
+	End,
+
+	/// The integer isn't encoded in it's shortest form
+	Overlong,
+
+	/// The integer is out of range of the type it's decoded into, or of the encoding itself
+	Overflow,
+}
+
+impl fmt::Display for VarintError {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::End => f.write_str("buffer ended before the end of the integer"),
+			Self::Overlong => f.write_str("integer isn't encoded in it's shortest form"),
+			Self::Overflow => f.write_str("integer out of range"),
+		}
+	}
+}
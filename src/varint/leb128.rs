@@ -0,0 +1,238 @@
+//! LEB128 encoding
+//!
+//! Integers are stored in groups of 7 bits, least significant first, with the
+//! most significant bit of each byte set if more bytes follow.
+//!
+//! Unsigned integers use unsigned LEB128, while signed integers use signed LEB128,
+//! in which the last group is sign extended.
+
+// Imports
+use super::VarintError;
+use crate::{Extend, ExtendBits, Signed, Truncate, ZeroExtend};
+use core::mem;
+
+/// Integers that may be encoded with LEB128
+pub trait Leb128: Sized {
+	/// Maximum number of bytes of an encoded integer
+	const MAX_LEN: usize;
+
+	/// Encodes this integer at the start of `buf`, returning the number of bytes written
+	///
+	/// # Errors
+	/// Returns [`VarintError::End`] if `buf` is too small to hold the integer.
+	fn encode_leb128(self, buf: &mut [u8]) -> Result<usize, VarintError>;
+
+	/// Decodes an integer from the start of `bytes`, returning it and the number of bytes read
+	///
+	/// # Errors
+	/// Returns [`VarintError::End`] if `bytes` ends before the integer does,
+	/// [`VarintError::Overlong`] if the integer isn't encoded in it's shortest form
+	/// and [`VarintError::Overflow`] if it doesn't fit in this type.
+	fn decode_leb128(bytes: &[u8]) -> Result<(Self, usize), VarintError>;
+}
+
+/// Macro to help implement [`Leb128`]
+macro_rules! impl_leb128 {
+	(+ $( $T:ty ),+ $(,)?) => {
+		$(
+			impl Leb128 for $T {
+				const MAX_LEN: usize = (8 * mem::size_of::<$T>() + 6) / 7;
+
+				#[inline]
+				fn encode_leb128(self, buf: &mut [u8]) -> Result<usize, VarintError> {
+					let mut value = self;
+					for (idx, slot) in buf.iter_mut().enumerate() {
+						let byte = Truncate::<u8>::truncate(value) & 0x7f;
+						value >>= 7;
+						if value == 0 {
+							*slot = byte;
+							return Ok(idx + 1);
+						}
+						*slot = byte | 0x80;
+					}
+
+					Err(VarintError::End)
+				}
+
+				#[inline]
+				fn decode_leb128(bytes: &[u8]) -> Result<(Self, usize), VarintError> {
+					let mut value: $T = 0;
+					let mut shift: u32 = 0;
+					for (idx, &byte) in bytes.iter().enumerate().take(Self::MAX_LEN) {
+						let payload: $T = (byte & 0x7f).zero_extend();
+						value |= payload << shift;
+
+						if byte & 0x80 == 0 {
+							// Note: Any bits of the last byte that didn't fit were lost, so shifting back won't recover it
+							if value >> shift != payload {
+								return Err(VarintError::Overflow);
+							}
+
+							// Note: An empty last byte adds nothing to the previous ones
+							if idx != 0 && byte == 0 {
+								return Err(VarintError::Overlong);
+							}
+
+							return Ok((value, idx + 1));
+						}
+						shift += 7;
+					}
+
+					// Note: If we read the maximum length, the last byte we read wasn't the last one
+					match bytes.len() >= Self::MAX_LEN {
+						true => Err(VarintError::Overflow),
+						false => Err(VarintError::End),
+					}
+				}
+			}
+		)+
+	};
+
+	(- $( $T:ty ),+ $(,)?) => {
+		$(
+			impl Leb128 for $T {
+				const MAX_LEN: usize = (8 * mem::size_of::<$T>() + 6) / 7;
+
+				#[inline]
+				fn encode_leb128(self, buf: &mut [u8]) -> Result<usize, VarintError> {
+					let mut value = self;
+					for (idx, slot) in buf.iter_mut().enumerate() {
+						let byte = Truncate::<u8>::truncate(value.as_unsigned()) & 0x7f;
+
+						// Note: The shift is arithmetic, so we're done once the remaining
+						//       bits are all equal to the sign bit of this byte.
+						value >>= 7;
+						let is_negative = byte & 0x40 != 0;
+						if (value == 0 && !is_negative) || (value == -1 && is_negative) {
+							*slot = byte;
+							return Ok(idx + 1);
+						}
+						*slot = byte | 0x80;
+					}
+
+					Err(VarintError::End)
+				}
+
+				#[inline]
+				fn decode_leb128(bytes: &[u8]) -> Result<(Self, usize), VarintError> {
+					let mut value: <$T as Signed>::Unsigned = 0;
+					let mut shift: u32 = 0;
+					let mut prev_byte: u8 = 0;
+					for (idx, &byte) in bytes.iter().enumerate().take(Self::MAX_LEN) {
+						let payload: <$T as Signed>::Unsigned = (byte & 0x7f).zero_extend();
+						value |= payload << shift;
+
+						if byte & 0x80 == 0 {
+							let value = value.as_signed().sign_extend_from(shift + 7);
+							let payload = (byte << 1).as_signed() >> 1;
+
+							// Note: Any bits of the last byte that didn't fit were lost, so shifting back won't recover it
+							if value >> shift != Extend::<$T>::extend(payload) {
+								return Err(VarintError::Overflow);
+							}
+
+							// Note: A last byte that only holds the sign of the previous one adds nothing to them
+							if idx != 0 && payload == (prev_byte << 1).as_signed() >> 7 {
+								return Err(VarintError::Overlong);
+							}
+
+							return Ok((value, idx + 1));
+						}
+						shift += 7;
+						prev_byte = byte;
+					}
+
+					// Note: If we read the maximum length, the last byte we read wasn't the last one
+					match bytes.len() >= Self::MAX_LEN {
+						true => Err(VarintError::Overflow),
+						false => Err(VarintError::End),
+					}
+				}
+			}
+		)+
+	};
+}
+
+impl_leb128! { + u8, u16, u32, u64, u128, usize }
+impl_leb128! { - i8, i16, i32, i64, i128, isize }
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use super::*;
+
+	#[test]
+	#[rustfmt::skip]
+	fn encode_unsigned() {
+		let mut buf = [0; 19];
+		assert_eq!(0u8       .encode_leb128(&mut buf), Ok(1)); assert_eq!(buf[..1], [0x00]);
+		assert_eq!(0x7fu8    .encode_leb128(&mut buf), Ok(1)); assert_eq!(buf[..1], [0x7f]);
+		assert_eq!(0x80u8    .encode_leb128(&mut buf), Ok(2)); assert_eq!(buf[..2], [0x80, 0x01]);
+		assert_eq!(624_485u32.encode_leb128(&mut buf), Ok(3)); assert_eq!(buf[..3], [0xe5, 0x8e, 0x26]);
+		assert_eq!(u64::MAX  .encode_leb128(&mut buf), Ok(10)); assert_eq!(buf[..10], [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01]);
+		assert_eq!(u128::MAX .encode_leb128(&mut buf), Ok(19));
+		assert_eq!(usize::MAX.encode_leb128(&mut buf), Ok(usize::MAX_LEN));
+		assert_eq!(0x80u16   .encode_leb128(&mut buf[..1]), Err(VarintError::End));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn encode_signed() {
+		let mut buf = [0; 19];
+		assert_eq!(0i8        .encode_leb128(&mut buf), Ok(1)); assert_eq!(buf[..1], [0x00]);
+		assert_eq!((-1i8)     .encode_leb128(&mut buf), Ok(1)); assert_eq!(buf[..1], [0x7f]);
+		assert_eq!(63i8       .encode_leb128(&mut buf), Ok(1)); assert_eq!(buf[..1], [0x3f]);
+		assert_eq!(64i8       .encode_leb128(&mut buf), Ok(2)); assert_eq!(buf[..2], [0xc0, 0x00]);
+		assert_eq!((-64i8)    .encode_leb128(&mut buf), Ok(1)); assert_eq!(buf[..1], [0x40]);
+		assert_eq!((-65i8)    .encode_leb128(&mut buf), Ok(2)); assert_eq!(buf[..2], [0xbf, 0x7f]);
+		assert_eq!(i8::MIN    .encode_leb128(&mut buf), Ok(2)); assert_eq!(buf[..2], [0x80, 0x7f]);
+		assert_eq!((-123_456i32).encode_leb128(&mut buf), Ok(3)); assert_eq!(buf[..3], [0xc0, 0xbb, 0x78]);
+		assert_eq!(i64::MIN   .encode_leb128(&mut buf), Ok(10)); assert_eq!(buf[..10], [0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7f]);
+		assert_eq!(i128::MIN  .encode_leb128(&mut buf), Ok(19));
+		assert_eq!(isize::MIN .encode_leb128(&mut buf), Ok(isize::MAX_LEN));
+		assert_eq!(64i16      .encode_leb128(&mut buf[..1]), Err(VarintError::End));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn decode_unsigned() {
+		assert_eq!(u8   ::decode_leb128(&[0x00]), Ok((0, 1)));
+		assert_eq!(u8   ::decode_leb128(&[0xff, 0x01]), Ok((u8::MAX, 2)));
+		assert_eq!(u32  ::decode_leb128(&[0xe5, 0x8e, 0x26, 0xaa]), Ok((624_485, 3)));
+		assert_eq!(u64  ::decode_leb128(&[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01]), Ok((u64::MAX, 10)));
+		assert_eq!(u8   ::decode_leb128(&[0xff, 0x03]), Err(VarintError::Overflow));
+		assert_eq!(u8   ::decode_leb128(&[0xff, 0x81, 0x00]), Err(VarintError::Overflow));
+		assert_eq!(u16  ::decode_leb128(&[0x80, 0x00]), Err(VarintError::Overlong));
+		assert_eq!(u16  ::decode_leb128(&[0x80, 0x80]), Err(VarintError::End));
+		assert_eq!(usize::decode_leb128(&[]), Err(VarintError::End));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn decode_signed() {
+		assert_eq!(i8   ::decode_leb128(&[0x7f]), Ok((-1, 1)));
+		assert_eq!(i8   ::decode_leb128(&[0xc0, 0x00]), Ok((64, 2)));
+		assert_eq!(i8   ::decode_leb128(&[0x80, 0x7f]), Ok((i8::MIN, 2)));
+		assert_eq!(i32  ::decode_leb128(&[0xc0, 0xbb, 0x78]), Ok((-123_456, 3)));
+		assert_eq!(i64  ::decode_leb128(&[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7f]), Ok((i64::MIN, 10)));
+		assert_eq!(i8   ::decode_leb128(&[0x80, 0x01]), Err(VarintError::Overflow));
+		assert_eq!(i8   ::decode_leb128(&[0xff, 0x7e]), Err(VarintError::Overflow));
+		assert_eq!(i16  ::decode_leb128(&[0xff, 0x7f]), Err(VarintError::Overlong));
+		assert_eq!(i16  ::decode_leb128(&[0x80, 0x00]), Err(VarintError::Overlong));
+		assert_eq!(i16  ::decode_leb128(&[0xc0, 0x00]), Ok((64, 2)));
+		assert_eq!(isize::decode_leb128(&[0x80]), Err(VarintError::End));
+	}
+
+	#[test]
+	fn round_trip_exhaustive_16() {
+		let mut buf = [0; 3];
+		for value in u16::MIN..=u16::MAX {
+			let len = value.encode_leb128(&mut buf).unwrap();
+			assert_eq!(u16::decode_leb128(&buf[..len]), Ok((value, len)));
+
+			let value = value.as_signed();
+			let len = value.encode_leb128(&mut buf).unwrap();
+			assert_eq!(i16::decode_leb128(&buf[..len]), Ok((value, len)));
+		}
+	}
+}
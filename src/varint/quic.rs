@@ -0,0 +1,191 @@
+//! QUIC variable-length integers
+//!
+//! Integers are stored big endian in 1, 2, 4 or 8 bytes, with the 2 most significant
+//! bits of the first byte holding the base 2 logarithm of the length, which leaves
+//! up to 62 bits for the integer.
+//!
+//! The encoding is only defined for unsigned integers, so it's only implemented for them.
+//!
+//! RFC 9000, Section 16 doesn't require integers to be encoded in their shortest form,
+//! so [`QuicVarint::decode_quic`] accepts any length. Frame types must be encoded in their
+//! shortest form (RFC 9000, Section 12.4), which [`QuicVarint::decode_quic_strict`] checks.
+
+// Imports
+use super::VarintError;
+use crate::{raw, ZeroExtend};
+
+/// Integers that may be encoded as QUIC variable-length integers
+pub trait QuicVarint: Sized {
+	/// Maximum number of bytes of an encoded integer
+	const MAX_LEN: usize;
+
+	/// Encodes this integer at the start of `buf`, returning the number of bytes written
+	///
+	/// # Errors
+	/// Returns [`VarintError::End`] if `buf` is too small to hold the integer and
+	/// [`VarintError::Overflow`] if the integer doesn't fit in 62 bits.
+	fn encode_quic(self, buf: &mut [u8]) -> Result<usize, VarintError>;
+
+	/// Decodes an integer from the start of `bytes`, returning it and the number of bytes read
+	///
+	/// Integers don't need to be encoded in their shortest form.
+	///
+	/// # Errors
+	/// Returns [`VarintError::End`] if `bytes` ends before the integer does
+	/// and [`VarintError::Overflow`] if it doesn't fit in this type.
+	fn decode_quic(bytes: &[u8]) -> Result<(Self, usize), VarintError>;
+
+	/// Decodes an integer encoded in it's shortest form from the start of `bytes`, returning it and the number of bytes read
+	///
+	/// This should be used for frame types, which must be encoded in their shortest form.
+	///
+	/// # Errors
+	/// Returns [`VarintError::End`] if `bytes` ends before the integer does,
+	/// [`VarintError::Overlong`] if the integer isn't encoded in it's shortest form
+	/// and [`VarintError::Overflow`] if it doesn't fit in this type.
+	fn decode_quic_strict(bytes: &[u8]) -> Result<(Self, usize), VarintError>;
+}
+
+/// Largest integer that may be encoded
+pub const MAX: u64 = (1 << 62) - 1;
+
+/// Encodes `value` at the start of `buf`, returning the number of bytes written
+fn encode(value: u64, buf: &mut [u8]) -> Result<usize, VarintError> {
+	let (len, prefix): (usize, u64) = match value {
+		0..=0x3f => (1, 0b00),
+		0x40..=0x3fff => (2, 0b01),
+		0x4000..=0x3fff_ffff => (4, 0b10),
+		0x4000_0000..=MAX => (8, 0b11),
+		_ => return Err(VarintError::Overflow),
+	};
+
+	// Note: The length prefix goes in the 2 most significant bits of the `len` bytes we write
+	let bytes = (value | prefix << (8 * len - 2)).to_be_bytes();
+	let buf = buf.get_mut(..len).ok_or(VarintError::End)?;
+	buf.copy_from_slice(&bytes[bytes.len() - len..]);
+
+	Ok(len)
+}
+
+/// Decodes an integer from the start of `bytes`, returning it and the number of bytes read
+///
+/// If `strict`, integers not encoded in their shortest form are rejected.
+fn decode(bytes: &[u8], strict: bool) -> Result<(u64, usize), VarintError> {
+	let first = bytes.first().ok_or(VarintError::End)?;
+	let len = 1 << (first >> 6);
+	let bytes = bytes.get(1..len).ok_or(VarintError::End)?;
+
+	let value = bytes.iter().fold((first & 0x3f).zero_extend(), |value: u64, &byte| {
+		value << 8 | ZeroExtend::<u64>::zero_extend(byte)
+	});
+
+	// Note: Integers that would fit in half the length must use it
+	if strict && len != 1 && value >> (4 * len - 2) == 0 {
+		return Err(VarintError::Overlong);
+	}
+
+	Ok((value, len))
+}
+
+/// Macro to help implement [`QuicVarint`]
+macro_rules! impl_quic_varint {
+	($( $T:ty => $MAX_LEN:literal ),+ $(,)?) => {
+		$(
+			impl QuicVarint for $T {
+				const MAX_LEN: usize = $MAX_LEN;
+
+				#[inline]
+				fn encode_quic(self, buf: &mut [u8]) -> Result<usize, VarintError> {
+					let value = raw::try_cast::<$T, u64>(self).ok_or(VarintError::Overflow)?;
+					encode(value, buf)
+				}
+
+				#[inline]
+				fn decode_quic(bytes: &[u8]) -> Result<(Self, usize), VarintError> {
+					let (value, len) = decode(bytes, false)?;
+					let value = raw::try_cast::<u64, $T>(value).ok_or(VarintError::Overflow)?;
+					Ok((value, len))
+				}
+
+				#[inline]
+				fn decode_quic_strict(bytes: &[u8]) -> Result<(Self, usize), VarintError> {
+					let (value, len) = decode(bytes, true)?;
+					let value = raw::try_cast::<u64, $T>(value).ok_or(VarintError::Overflow)?;
+					Ok((value, len))
+				}
+			}
+		)+
+	};
+}
+
+impl_quic_varint! {
+	u8    => 2,
+	u16   => 4,
+	u32   => 8,
+	u64   => 8,
+	u128  => 8,
+	usize => 8,
+}
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use super::*;
+
+	#[test]
+	#[rustfmt::skip]
+	fn encode_quic() {
+		// Note: Examples from RFC 9000, Appendix A.1
+		let mut buf = [0; 8];
+		assert_eq!(37u8               .encode_quic(&mut buf), Ok(1)); assert_eq!(buf[..1], [0x25]);
+		assert_eq!(15_293u16          .encode_quic(&mut buf), Ok(2)); assert_eq!(buf[..2], [0x7b, 0xbd]);
+		assert_eq!(494_878_333u32     .encode_quic(&mut buf), Ok(4)); assert_eq!(buf[..4], [0x9d, 0x7f, 0x3e, 0x7d]);
+		assert_eq!(151_288_809_941_952_652u64.encode_quic(&mut buf), Ok(8)); assert_eq!(buf, [0xc2, 0x19, 0x7c, 0x5e, 0xff, 0x14, 0xe8, 0x8c]);
+		assert_eq!(u8::MAX            .encode_quic(&mut buf), Ok(2)); assert_eq!(buf[..2], [0x40, 0xff]);
+		assert_eq!(MAX                .encode_quic(&mut buf), Ok(8)); assert_eq!(buf, [0xff; 8]);
+		assert_eq!((MAX + 1)          .encode_quic(&mut buf), Err(VarintError::Overflow));
+		assert_eq!(u128::MAX          .encode_quic(&mut buf), Err(VarintError::Overflow));
+		assert_eq!(usize::MIN         .encode_quic(&mut buf), Ok(1));
+		assert_eq!(0x40u16            .encode_quic(&mut buf[..1]), Err(VarintError::End));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn decode_quic() {
+		assert_eq!(u8   ::decode_quic(&[0x25]), Ok((37, 1)));
+		assert_eq!(u16  ::decode_quic(&[0x7b, 0xbd]), Ok((15_293, 2)));
+		assert_eq!(u32  ::decode_quic(&[0x9d, 0x7f, 0x3e, 0x7d]), Ok((494_878_333, 4)));
+		assert_eq!(u64  ::decode_quic(&[0xc2, 0x19, 0x7c, 0x5e, 0xff, 0x14, 0xe8, 0x8c]), Ok((151_288_809_941_952_652, 8)));
+		assert_eq!(u128 ::decode_quic(&[0xff; 8]), Ok((u128::from(MAX), 8)));
+		assert_eq!(u8   ::decode_quic(&[0x41, 0x00]), Err(VarintError::Overflow));
+		// Note: Example from RFC 9000, Appendix A.1
+		assert_eq!(u16  ::decode_quic(&[0x40, 0x25]), Ok((37, 2)));
+		assert_eq!(u32  ::decode_quic(&[0x80, 0x00, 0x3f, 0xff]), Ok((0x3fff, 4)));
+		assert_eq!(u8   ::decode_quic(&[0xc0, 0, 0, 0, 0, 0, 0, 0xff]), Ok((u8::MAX, 8)));
+		assert_eq!(u32  ::decode_quic(&[0x80, 0x00]), Err(VarintError::End));
+		assert_eq!(usize::decode_quic(&[]), Err(VarintError::End));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn decode_quic_strict() {
+		assert_eq!(u8   ::decode_quic_strict(&[0x25]), Ok((37, 1)));
+		assert_eq!(u16  ::decode_quic_strict(&[0x7b, 0xbd]), Ok((15_293, 2)));
+		assert_eq!(u64  ::decode_quic_strict(&[0xff; 8]), Ok((MAX, 8)));
+		assert_eq!(u8   ::decode_quic_strict(&[0x41, 0x00]), Err(VarintError::Overflow));
+		assert_eq!(u16  ::decode_quic_strict(&[0x40, 0x25]), Err(VarintError::Overlong));
+		assert_eq!(u32  ::decode_quic_strict(&[0x80, 0x00, 0x3f, 0xff]), Err(VarintError::Overlong));
+		assert_eq!(u32  ::decode_quic_strict(&[0x80, 0x00]), Err(VarintError::End));
+		assert_eq!(usize::decode_quic_strict(&[]), Err(VarintError::End));
+	}
+
+	#[test]
+	fn round_trip_exhaustive_16() {
+		let mut buf = [0; 8];
+		for value in u16::MIN..=u16::MAX {
+			let len = value.encode_quic(&mut buf).unwrap();
+			assert_eq!(u16::decode_quic(&buf[..len]), Ok((value, len)));
+			assert_eq!(u16::decode_quic_strict(&buf[..len]), Ok((value, len)));
+		}
+	}
+}
@@ -0,0 +1,183 @@
+//! `SQLite` variable-length integers
+//!
+//! Integers are stored big endian in 1 to 9 bytes. The first 8 bytes hold 7 bits
+//! each, with the most significant bit set if more bytes follow, while the 9th
+//! byte holds all of it's 8 bits, for a total of 64 bits.
+//!
+//! Signed integers are stored as the bits of their 64-bit value, like `SQLite` does,
+//! so negative integers always take 9 bytes.
+
+// Imports
+use super::VarintError;
+use crate::{raw, Truncate, ZeroExtend};
+
+/// Integers that may be encoded as `SQLite` variable-length integers
+pub trait SqliteVarint: Sized {
+	/// Maximum number of bytes of an encoded integer
+	const MAX_LEN: usize;
+
+	/// Encodes this integer at the start of `buf`, returning the number of bytes written
+	///
+	/// # Errors
+	/// Returns [`VarintError::End`] if `buf` is too small to hold the integer and
+	/// [`VarintError::Overflow`] if the integer doesn't fit in 64 bits.
+	fn encode_sqlite(self, buf: &mut [u8]) -> Result<usize, VarintError>;
+
+	/// Decodes an integer from the start of `bytes`, returning it and the number of bytes read
+	///
+	/// # Errors
+	/// Returns [`VarintError::End`] if `bytes` ends before the integer does,
+	/// [`VarintError::Overlong`] if the integer isn't encoded in it's shortest form
+	/// and [`VarintError::Overflow`] if it doesn't fit in this type.
+	fn decode_sqlite(bytes: &[u8]) -> Result<(Self, usize), VarintError>;
+}
+
+/// Encodes `value` at the start of `buf`, returning the number of bytes written
+fn encode(value: u64, buf: &mut [u8]) -> Result<usize, VarintError> {
+	// Note: Integers that don't fit in 8 groups of 7 bits need the 9th byte, which holds 8 bits
+	if value >> 56 != 0 {
+		let buf = buf.get_mut(..9).ok_or(VarintError::End)?;
+		let mut value = value;
+		for (idx, slot) in buf.iter_mut().enumerate().rev() {
+			if idx == 8 {
+				*slot = value.truncate();
+				value >>= 8;
+			} else {
+				*slot = Truncate::<u8>::truncate(value) | 0x80;
+				value >>= 7;
+			}
+		}
+
+		return Ok(9);
+	}
+
+	let mut len = 1;
+	while value >> (7 * len) != 0 {
+		len += 1;
+	}
+
+	let buf = buf.get_mut(..len).ok_or(VarintError::End)?;
+	let mut value = value;
+	for (idx, slot) in buf.iter_mut().enumerate().rev() {
+		let byte = Truncate::<u8>::truncate(value) & 0x7f;
+		*slot = if idx == len - 1 { byte } else { byte | 0x80 };
+		value >>= 7;
+	}
+
+	Ok(len)
+}
+
+/// Decodes an integer from the start of `bytes`, returning it and the number of bytes read
+fn decode(bytes: &[u8]) -> Result<(u64, usize), VarintError> {
+	let mut value: u64 = 0;
+	for (idx, &byte) in bytes.iter().enumerate().take(9) {
+		if idx == 8 {
+			value = value << 8 | ZeroExtend::<u64>::zero_extend(byte);
+
+			// Note: Integers that fit in 8 bytes must not use the 9th
+			if value >> 56 == 0 {
+				return Err(VarintError::Overlong);
+			}
+
+			return Ok((value, 9));
+		}
+
+		value = value << 7 | ZeroExtend::<u64>::zero_extend(byte & 0x7f);
+		if byte & 0x80 == 0 {
+			// Note: An empty first byte adds nothing to the following ones
+			if idx != 0 && bytes.first() == Some(&0x80) {
+				return Err(VarintError::Overlong);
+			}
+
+			return Ok((value, idx + 1));
+		}
+	}
+
+	Err(VarintError::End)
+}
+
+/// Macro to help implement [`SqliteVarint`]
+///
+/// Integers are converted to and from `$Wide`, a 64-bit integer of the same signedness,
+/// whose bits are then encoded.
+macro_rules! impl_sqlite_varint {
+	($( $T:ty ),+ => $Wide:ty) => {
+		$(
+			impl SqliteVarint for $T {
+				const MAX_LEN: usize = 9;
+
+				#[inline]
+				fn encode_sqlite(self, buf: &mut [u8]) -> Result<usize, VarintError> {
+					let value = raw::try_cast::<$T, $Wide>(self).ok_or(VarintError::Overflow)?;
+					encode(raw::zero_cast(value), buf)
+				}
+
+				#[inline]
+				fn decode_sqlite(bytes: &[u8]) -> Result<(Self, usize), VarintError> {
+					let (value, len) = decode(bytes)?;
+					let value = raw::try_cast::<$Wide, $T>(raw::zero_cast(value)).ok_or(VarintError::Overflow)?;
+					Ok((value, len))
+				}
+			}
+		)+
+	};
+}
+
+impl_sqlite_varint! { u8, u16, u32, u64, u128, usize => u64 }
+impl_sqlite_varint! { i8, i16, i32, i64, i128, isize => i64 }
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use super::*;
+
+	#[test]
+	#[rustfmt::skip]
+	fn encode_sqlite() {
+		let mut buf = [0; 9];
+		assert_eq!(0u8          .encode_sqlite(&mut buf), Ok(1)); assert_eq!(buf[..1], [0x00]);
+		assert_eq!(0x7fu8       .encode_sqlite(&mut buf), Ok(1)); assert_eq!(buf[..1], [0x7f]);
+		assert_eq!(0x80u8       .encode_sqlite(&mut buf), Ok(2)); assert_eq!(buf[..2], [0x81, 0x00]);
+		assert_eq!(0x3fffu16    .encode_sqlite(&mut buf), Ok(2)); assert_eq!(buf[..2], [0xff, 0x7f]);
+		assert_eq!(0x4000u32    .encode_sqlite(&mut buf), Ok(3)); assert_eq!(buf[..3], [0x81, 0x80, 0x00]);
+		assert_eq!((1u64 << 56) .encode_sqlite(&mut buf), Ok(9)); assert_eq!(buf, [0x80, 0xc0, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x00]);
+		assert_eq!(u64::MAX     .encode_sqlite(&mut buf), Ok(9)); assert_eq!(buf, [0xff; 9]);
+		assert_eq!((-1i8)       .encode_sqlite(&mut buf), Ok(9)); assert_eq!(buf, [0xff; 9]);
+		assert_eq!(i64::MAX     .encode_sqlite(&mut buf), Ok(9)); assert_eq!(buf, [0xbf, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]);
+		assert_eq!(u128::MAX    .encode_sqlite(&mut buf), Err(VarintError::Overflow));
+		assert_eq!(i128::MIN    .encode_sqlite(&mut buf), Err(VarintError::Overflow));
+		assert_eq!(usize::MAX   .encode_sqlite(&mut buf), Ok(9));
+		assert_eq!(isize::MIN   .encode_sqlite(&mut buf), Ok(9));
+		assert_eq!(0x80u16      .encode_sqlite(&mut buf[..1]), Err(VarintError::End));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn decode_sqlite() {
+		assert_eq!(u8   ::decode_sqlite(&[0x7f]), Ok((0x7f, 1)));
+		assert_eq!(u8   ::decode_sqlite(&[0x81, 0x7f, 0x00]), Ok((u8::MAX, 2)));
+		assert_eq!(u64  ::decode_sqlite(&[0xff; 9]), Ok((u64::MAX, 9)));
+		assert_eq!(i8   ::decode_sqlite(&[0xff; 9]), Ok((-1, 9)));
+		assert_eq!(i128 ::decode_sqlite(&[0x80, 0xc0, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x00]), Ok((1 << 56, 9)));
+		assert_eq!(u8   ::decode_sqlite(&[0x82, 0x00]), Err(VarintError::Overflow));
+		assert_eq!(i8   ::decode_sqlite(&[0x81, 0x00]), Err(VarintError::Overflow));
+		assert_eq!(u8   ::decode_sqlite(&[0xff; 9]), Err(VarintError::Overflow));
+		assert_eq!(u16  ::decode_sqlite(&[0x80, 0x7f]), Err(VarintError::Overlong));
+		assert_eq!(u64  ::decode_sqlite(&[0x80, 0x80, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]), Err(VarintError::Overlong));
+		assert_eq!(u16  ::decode_sqlite(&[0x81]), Err(VarintError::End));
+		assert_eq!(usize::decode_sqlite(&[]), Err(VarintError::End));
+	}
+
+	#[test]
+	fn round_trip_exhaustive_16() {
+		let mut buf = [0; 9];
+		for value in u16::MIN..=u16::MAX {
+			let len = value.encode_sqlite(&mut buf).unwrap();
+			assert_eq!(u16::decode_sqlite(&buf[..len]), Ok((value, len)));
+
+			let value = crate::Signed::as_signed(value);
+			let len = value.encode_sqlite(&mut buf).unwrap();
+			assert_eq!(i16::decode_sqlite(&buf[..len]), Ok((value, len)));
+		}
+	}
+}
@@ -0,0 +1,237 @@
+//! Wrapper types
+//!
+//! This module implements the crate's traits for the [`Wrapping`] and `Saturating`
+//! wrappers, keeping the wrapper on the output:
+//!
+//! - [`ZeroExtend`] / [`SignExtend`] / [`Extend`] / [`Split`] / [`Join`]: Implemented for both, as they're lossless.
+//! - [`Truncate`] / [`Signed`] / [`Raw`]: Implemented only for [`Wrapping`], as they wrap around.
+//! - `SaturatingTruncate`: Implemented only for `Saturating`, as it saturates.
+//!
+//! The conversions are implemented for wrappers of primitive, odd-width and arbitrary-width integers.
+//!
+//! Note: `Saturating` requires Rust 1.74, so it's implementations are only
+//!       available with the `saturating` feature.
+
+// Imports
+use crate::{
+	i24, i40, i48, i56, lift::Distinct, u24, u40, u48, u56, Backing, Bits, Extend, Int, Join, Raw, SignExtend, Signed, SignedExt, Split, Truncate,
+	UInt, ZeroExtend,
+};
+use core::num::Wrapping;
+#[cfg(feature = "saturating")]
+use {crate::SaturatingTruncate, core::num::Saturating};
+
+/// Macro to help implement [`Split`] and [`Join`] for a wrapper
+macro_rules! impl_split {
+	($( $W:ident ),+ $(,)?) => {
+		$(
+			impl<T: Split> Split for $W<T> {
+				type Hi = $W<T::Hi>;
+				type Lo = $W<T::Lo>;
+
+				#[inline]
+				fn hi(self) -> Self::Hi {
+					$W(self.0.hi())
+				}
+
+				#[inline]
+				fn lo(self) -> Self::Lo {
+					$W(self.0.lo())
+				}
+
+				#[inline]
+				fn lo_hi(self) -> (Self::Lo, Self::Hi) {
+					let (lo, hi) = self.0.lo_hi();
+					($W(lo), $W(hi))
+				}
+			}
+
+			impl<T: Join> Join for $W<T> {
+				#[inline]
+				fn join(lo: Self::Lo, hi: Self::Hi) -> Self {
+					$W(T::join(lo.0, hi.0))
+				}
+			}
+		)+
+	};
+}
+
+impl_split! { Wrapping }
+#[cfg(feature = "saturating")]
+impl_split! { Saturating }
+
+/// Macro to help implement the conversions for the wrappers of an integer
+///
+/// Note: The conversions are implemented for each type into itself by their blanket implementations,
+///       which implementations generic over the integers would overlap with, so they're instead
+///       implemented for each integer, into any [`Distinct`] integer, like in [`lift`](crate::lift).
+macro_rules! impl_wrapper {
+	(@impl [$( $N:ident )?] $W:ident < $T:ty > : $Trait:ident :: $method:ident where $( $Bound:tt )+) => {
+		impl<U $(, const $N: u32 )?> $Trait<$W<U>> for $W<$T>
+		where
+			$( Bits<$N>: Backing, )?
+			$( $Bound )+
+		{
+			#[inline]
+			fn $method(self) -> $W<U> {
+				$W(self.0.$method())
+			}
+		}
+
+		impl<'a, U $(, const $N: u32 )?> $Trait<$W<U>> for &'a $W<$T>
+		where
+			$( Bits<$N>: Backing, )?
+			$( $Bound )+
+		{
+			#[inline]
+			fn $method(self) -> $W<U> {
+				$W(self.0.$method())
+			}
+		}
+	};
+
+	(@impl [$( $N:ident )?] $T:ty) => {
+		impl_wrapper! { @impl [$( $N )?] Wrapping<$T> : ZeroExtend::zero_extend where $T: ZeroExtend<U> + Distinct<U> }
+		impl_wrapper! { @impl [$( $N )?] Wrapping<$T> : SignExtend::sign_extend where $T: SignExtend<U> + Distinct<U> }
+		impl_wrapper! { @impl [$( $N )?] Wrapping<$T> : Extend::extend where $T: Extend<U> + Distinct<U> }
+		impl_wrapper! { @impl [$( $N )?] Wrapping<$T> : Truncate::truncate where $T: Truncate<U>, U: Distinct<$T> }
+		#[cfg(feature = "saturating")]
+		impl_wrapper! { @impl [$( $N )?] Saturating<$T> : ZeroExtend::zero_extend where $T: ZeroExtend<U> + Distinct<U> }
+		#[cfg(feature = "saturating")]
+		impl_wrapper! { @impl [$( $N )?] Saturating<$T> : SignExtend::sign_extend where $T: SignExtend<U> + Distinct<U> }
+		#[cfg(feature = "saturating")]
+		impl_wrapper! { @impl [$( $N )?] Saturating<$T> : Extend::extend where $T: Extend<U> + Distinct<U> }
+		#[cfg(feature = "saturating")]
+		impl_wrapper! { @impl [$( $N )?] Saturating<$T> : SaturatingTruncate::saturating_truncate where $T: SaturatingTruncate<U>, U: Distinct<$T> }
+	};
+
+	(impl<$N:ident> $( $T:ty ),+ $(,)?) => {
+		$( impl_wrapper! { @impl [$N] $T } )+
+	};
+
+	($( $T:ty ),+ $(,)?) => {
+		$( impl_wrapper! { @impl [] $T } )+
+	};
+}
+
+// Primitives
+impl_wrapper! { u8, u16, u32, u64, u128, usize }
+impl_wrapper! { i8, i16, i32, i64, i128, isize }
+
+// Odd-width
+impl_wrapper! { u24, u40, u48, u56 }
+impl_wrapper! { i24, i40, i48, i56 }
+
+// Arbitrary-width
+impl_wrapper! { impl<N> UInt<N>, Int<N> }
+
+impl<T: Signed> Signed for Wrapping<T> {
+	type Signed = Wrapping<T::Signed>;
+	type Unsigned = Wrapping<T::Unsigned>;
+
+	#[inline]
+	fn as_unsigned(self) -> Self::Unsigned {
+		Wrapping(self.0.as_unsigned())
+	}
+
+	#[inline]
+	fn as_signed(self) -> Self::Signed {
+		Wrapping(self.0.as_signed())
+	}
+
+	#[inline]
+	fn abs_unsigned(self) -> Self::Unsigned {
+		Wrapping(self.0.abs_unsigned())
+	}
+}
+
+impl<T: Raw> Raw for Wrapping<T> {
+	const BITS: u32 = T::BITS;
+	const SIGNED: bool = T::SIGNED;
+
+	#[inline]
+	fn to_raw(self) -> u128 {
+		self.0.to_raw()
+	}
+
+	#[inline]
+	fn from_raw(raw: u128) -> Self {
+		Wrapping(T::from_raw(raw))
+	}
+}
+
+// Check that the wrappers keep the impls of the integers they wrap
+static_assertions::assert_impl_all! { Wrapping<u16>   : Extend<Wrapping<u32>>, Truncate<Wrapping<u8>>, Split, Join, Signed, SignedExt }
+static_assertions::assert_impl_all! { Wrapping<i32>   : SignExtend<Wrapping<i64>>, Truncate<Wrapping<i16>>, Split, Join, Signed }
+#[cfg(feature = "saturating")]
+static_assertions::assert_impl_all! { Saturating<u16> : Extend<Saturating<u32>>, SaturatingTruncate<Saturating<u8>>, Split, Join }
+static_assertions::assert_impl_all! { Wrapping<UInt<5>>: Extend<Wrapping<UInt<7>>>, Truncate<Wrapping<UInt<3>>>, Extend<Wrapping<u8>> }
+
+// Check that `Saturating` doesn't wrap around
+#[cfg(feature = "saturating")]
+static_assertions::assert_not_impl_any! { Saturating<u16> : Truncate<Saturating<u8>>, Signed }
+
+#[cfg(test)]
+mod tests {
+	// Imports
+	use super::*;
+	#[cfg(feature = "saturating")]
+	use crate::SaturatingTruncated;
+	use crate::{i24, Extended, Sign, SignError, SignExtended, Truncated, ZeroExtended};
+
+	#[test]
+	#[rustfmt::skip]
+	fn extend() {
+		assert_eq!(Wrapping(0xffu8)     .zero_extended::<Wrapping<u16>>(), Wrapping(0x00ff));
+		assert_eq!(Wrapping(0xffu8)     .sign_extended::<Wrapping<u16>>(), Wrapping(0xffff));
+		assert_eq!(Wrapping(-1i8)       .extended::<Wrapping<i32>>(), Wrapping(-1));
+		assert_eq!(Wrapping(-1i16)      .extended::<Wrapping<i24>>(), Wrapping(i24::new(-1).unwrap()));
+		assert_eq!((&Wrapping(1u32))    .extended::<Wrapping<u64>>(), Wrapping(1));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn truncate() {
+		assert_eq!(Wrapping(0x1234u16)     .truncated::<Wrapping<u8>>(), Wrapping(0x34));
+		assert_eq!(Wrapping(-1i64)         .truncated::<Wrapping<i8>>(), Wrapping(-1));
+		assert_eq!((&Wrapping(0x1ffu16))   .truncated::<Wrapping<u8>>(), Wrapping(0xff));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn split_join() {
+		assert_eq!(Wrapping(0x1234u16)  .lo_hi(), (Wrapping(0x34), Wrapping(0x12)));
+		assert_eq!(Wrapping(-2i32)      .hi(), Wrapping(-1));
+		assert_eq!(Wrapping::<u16>::join(Wrapping(0x34), Wrapping(0x12)), Wrapping(0x1234));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	#[cfg(feature = "saturating")]
+	fn saturating() {
+		assert_eq!(Saturating(0xffu8)      .zero_extended::<Saturating<u16>>(), Saturating(0x00ff));
+		assert_eq!(Saturating(-1i8)        .extended::<Saturating<i64>>(), Saturating(-1));
+		assert_eq!(Saturating(0x1234u16)   .saturating_truncated::<Saturating<u8>>(), Saturating(0xff));
+		assert_eq!(Saturating(-300i16)     .saturating_truncated::<Saturating<i8>>(), Saturating(i8::MIN));
+		assert_eq!((&Saturating(0x12u16))  .saturating_truncated::<Saturating<u8>>(), Saturating(0x12));
+		assert_eq!(Saturating(0x1234u16)   .lo(), Saturating(0x34));
+		assert_eq!(Saturating::<i32>::join(Saturating(0xfffe), Saturating(-1)), Saturating(-2));
+	}
+
+	#[test]
+	#[rustfmt::skip]
+	fn signed() {
+		assert_eq!(Wrapping(-1i8)        .as_unsigned(), Wrapping(0xff));
+		assert_eq!(Wrapping(0xffu8)      .as_signed(), Wrapping(-1));
+		assert_eq!(Wrapping(i16::MIN)    .abs_unsigned(), Wrapping(0x8000));
+		assert_eq!(Wrapping(-5i32)       .sign(), Sign::Negative);
+		assert_eq!(SignedExt::signum(Wrapping(-5i32)), Wrapping(-1));
+		assert_eq!(Wrapping(u8::MAX)     .try_to_signed(), Err(SignError));
+		assert_eq!(Wrapping(-1i8)        .saturating_to_unsigned(), Wrapping(0));
+		assert_eq!(Wrapping(u8::MAX)     .overflowing_to_signed(), (Wrapping(-1), true));
+		assert_eq!(Wrapping::<i8>::try_from_sign_magnitude(true, Wrapping(0x80)), Ok(Wrapping(i8::MIN)));
+		assert_eq!(Wrapping::<i8>::wrapping_from_sign_magnitude(false, Wrapping(0xff)), Wrapping(-1));
+		assert_eq!(Wrapping(-1i32)       .zigzag_encode(), Wrapping(1));
+		assert_eq!(Wrapping::<i32>::zigzag_decode(Wrapping(1)), Wrapping(-1));
+	}
+}